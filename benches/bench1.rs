@@ -101,6 +101,32 @@ fn zipdot_f32_default_zip(b: &mut test::Bencher)
     })
 }
 
+#[bench]
+fn zipdot_f32_itertools_dot(b: &mut test::Bencher)
+{
+    let xs = vec![2f32; 1024];
+    let ys = vec![2f32; 768];
+    let xs = black_box(xs);
+    let ys = black_box(ys);
+
+    b.iter(|| {
+        xs.iter().dot(&ys)
+    })
+}
+
+#[bench]
+fn zipdot_i32_itertools_dot(b: &mut test::Bencher)
+{
+    let xs = vec![2; 1024];
+    let ys = vec![2; 768];
+    let xs = black_box(xs);
+    let ys = black_box(ys);
+
+    b.iter(|| {
+        xs.iter().dot(&ys)
+    })
+}
+
 #[bench]
 fn zip_default_zip3(b: &mut test::Bencher)
 {
@@ -549,6 +575,32 @@ fn chunks_lazy_1(b: &mut test::Bencher) {
     })
 }
 
+#[bench]
+fn chunks_lazy_collect_bytes(b: &mut test::Bencher) {
+    let data = vec![7u8; 1 << 16];
+    let sz = test::black_box(64);
+
+    b.iter(|| {
+        for group in &data.iter().cloned().chunks_lazy(sz) {
+            test::black_box(group.collect::<Vec<u8>>());
+        }
+    })
+}
+
+#[cfg(feature = "bytes")]
+#[bench]
+fn chunk_bytes(b: &mut test::Bencher) {
+    use itertools::ByteItertools;
+    let data = vec![7u8; 1 << 16];
+    let sz = test::black_box(64);
+
+    b.iter(|| {
+        for chunk in data.iter().cloned().chunk_bytes(sz) {
+            test::black_box(chunk);
+        }
+    })
+}
+
 #[bench]
 fn equal(b: &mut test::Bencher) {
     let data = vec![7; 1024];
@@ -691,9 +743,247 @@ fn kmerge_tenway(b: &mut test::Bencher) {
         rest = tail;
     }
 
-    // println!("Chunk lengths: {}", chunks.iter().format(", ", |elt, f| f(&elt.len())));
+    // println!("Chunk lengths: {}", chunks.iter().format_with(", ", |elt, f| f(&elt.len())));
 
     b.iter(|| {
         chunks.iter().cloned().kmerge().count()
     })
 }
+
+/// Make `k` sorted runs of pseudo random numbers, each `len` long.
+fn kmerge_runs(k: usize, len: usize) -> Vec<Vec<u16>> {
+    let mut state = 1729u16;
+    let mut rng = || {
+        state = state.wrapping_mul(31421).wrapping_add(6927);
+        state
+    };
+    (0..k).map(|_| {
+        let mut run = (0..len).map(|_| rng()).collect_vec();
+        run.sort();
+        run
+    }).collect()
+}
+
+#[bench]
+fn kmerge_hundredsway(b: &mut test::Bencher) {
+    let runs = kmerge_runs(256, 64);
+    b.iter(|| {
+        runs.iter().kmerge().count()
+    })
+}
+
+#[bench]
+fn kmerge_by_hundredsway(b: &mut test::Bencher) {
+    let runs = kmerge_runs(256, 64);
+    b.iter(|| {
+        runs.iter().kmerge_by(|a, b| a < b).count()
+    })
+}
+
+/// For comparison with `kmerge_hundredsway`: pick the least head of all
+/// runs with a linear scan, which is O(k) per element.
+#[bench]
+fn kmerge_linear_scan_hundredsway(b: &mut test::Bencher) {
+    let runs = kmerge_runs(256, 64);
+    b.iter(|| {
+        let mut its = runs.iter().map(|run| run.iter().peekable()).collect_vec();
+        let mut count = 0;
+        loop {
+            let mut least = None;
+            for (i, it) in its.iter_mut().enumerate() {
+                if let Some(&x) = it.peek() {
+                    match least {
+                        Some((_, y)) if y <= x => {}
+                        _ => least = Some((i, x)),
+                    }
+                }
+            }
+            match least {
+                None => break,
+                Some((i, _)) => {
+                    black_box(its[i].next());
+                    count += 1;
+                }
+            }
+        }
+        count
+    })
+}
+
+fn k_largest_data(len: usize) -> Vec<u32> {
+    let mut state = 1729u32;
+    (0..len).map(|_| {
+        state = state.wrapping_mul(1103515245).wrapping_add(12345);
+        state
+    }).collect()
+}
+
+#[bench]
+fn k_largest_hundred(b: &mut test::Bencher) {
+    let data = k_largest_data(1 << 17);
+    b.iter(|| {
+        data.iter().k_largest(100).count()
+    })
+}
+
+/// For comparison with `k_largest_hundred`: sort everything, then take.
+#[bench]
+fn k_largest_hundred_sorted(b: &mut test::Bencher) {
+    let data = k_largest_data(1 << 17);
+    b.iter(|| {
+        data.iter().sorted_by(|a, b| b.cmp(a)).take(100).count()
+    })
+}
+
+/// Sum `it` one element at a time, bypassing any specialized `fold`.
+fn sum_by_next<I: Iterator<Item = u32>>(mut it: I) -> u32 {
+    let mut sum = 0u32;
+    while let Some(elt) = it.next() {
+        sum = sum.wrapping_add(elt);
+    }
+    sum
+}
+
+/// Sum `it` through its `fold`.
+fn sum_by_fold<I: Iterator<Item = u32>>(it: I) -> u32 {
+    it.fold(0u32, |sum, elt| sum.wrapping_add(elt))
+}
+
+fn fold_data() -> (Vec<u32>, Vec<u32>) {
+    let data1 = (0..1024).map(|x| x * 2).collect::<Vec<u32>>();
+    let data2 = (0..800).map(|x| x * 3 / 4).collect::<Vec<u32>>();
+    (test::black_box(data1), test::black_box(data2))
+}
+
+#[bench]
+fn interleave_next(b: &mut test::Bencher) {
+    let (data1, data2) = fold_data();
+    b.iter(|| sum_by_next(data1.iter().cloned().interleave(data2.iter().cloned())))
+}
+
+#[bench]
+fn interleave_fold(b: &mut test::Bencher) {
+    let (data1, data2) = fold_data();
+    b.iter(|| sum_by_fold(data1.iter().cloned().interleave(data2.iter().cloned())))
+}
+
+#[bench]
+fn merge_next(b: &mut test::Bencher) {
+    let (data1, data2) = fold_data();
+    b.iter(|| sum_by_next(data1.iter().cloned().merge(data2.iter().cloned())))
+}
+
+#[bench]
+fn merge_fold(b: &mut test::Bencher) {
+    let (data1, data2) = fold_data();
+    b.iter(|| sum_by_fold(data1.iter().cloned().merge(data2.iter().cloned())))
+}
+
+#[bench]
+fn kmerge_next(b: &mut test::Bencher) {
+    let runs = kmerge_runs(100, 1000);
+    b.iter(|| sum_by_next(runs.iter().map(|v| v.iter().map(|&x| x as u32)).kmerge()))
+}
+
+#[bench]
+fn kmerge_fold(b: &mut test::Bencher) {
+    let runs = kmerge_runs(100, 1000);
+    b.iter(|| sum_by_fold(runs.iter().map(|v| v.iter().map(|&x| x as u32)).kmerge()))
+}
+
+#[bench]
+fn dedup_next(b: &mut test::Bencher) {
+    let (_, data) = fold_data();
+    b.iter(|| sum_by_next(data.iter().cloned().dedup()))
+}
+
+#[bench]
+fn dedup_fold(b: &mut test::Bencher) {
+    let (_, data) = fold_data();
+    b.iter(|| sum_by_fold(data.iter().cloned().dedup()))
+}
+
+#[bench]
+fn coalesce_next(b: &mut test::Bencher) {
+    let (_, data) = fold_data();
+    b.iter(|| sum_by_next(data.iter().cloned().coalesce(|x, y| {
+        if x / 8 == y / 8 { Ok(x + y) } else { Err((x, y)) }
+    })))
+}
+
+#[bench]
+fn coalesce_fold(b: &mut test::Bencher) {
+    let (_, data) = fold_data();
+    b.iter(|| sum_by_fold(data.iter().cloned().coalesce(|x, y| {
+        if x / 8 == y / 8 { Ok(x + y) } else { Err((x, y)) }
+    })))
+}
+
+#[bench]
+fn with_position_next(b: &mut test::Bencher) {
+    let (data, _) = fold_data();
+    b.iter(|| sum_by_next(data.iter().with_position().map(|p| p.into_inner() + 1)))
+}
+
+#[bench]
+fn with_position_fold(b: &mut test::Bencher) {
+    let (data, _) = fold_data();
+    b.iter(|| sum_by_fold(data.iter().with_position().map(|p| p.into_inner() + 1)))
+}
+
+#[bench]
+fn intersperse_with_next(b: &mut test::Bencher) {
+    let (data, _) = fold_data();
+    b.iter(|| sum_by_next(data.iter().cloned().intersperse_with(|| 1)))
+}
+
+#[bench]
+fn intersperse_with_fold(b: &mut test::Bencher) {
+    let (data, _) = fold_data();
+    b.iter(|| sum_by_fold(data.iter().cloned().intersperse_with(|| 1)))
+}
+
+fn dot_data() -> (Vec<f32>, Vec<f32>) {
+    let xs = (0..1024).map(|x| x as f32 * 0.5).collect::<Vec<_>>();
+    let ys = (0..1024).map(|x| 1. / (x as f32 + 1.)).collect::<Vec<_>>();
+    (test::black_box(xs), test::black_box(ys))
+}
+
+/// For comparison with `dot_f32`: the plain zip, map and sum chain.
+#[bench]
+fn dot_f32_chain(b: &mut test::Bencher) {
+    let (xs, ys) = dot_data();
+    b.iter(|| xs.iter().zip(&ys).map(|(x, y)| x * y).sum::<f32>())
+}
+
+#[bench]
+fn dot_f32(b: &mut test::Bencher) {
+    let (xs, ys) = dot_data();
+    b.iter(|| xs.iter().dot(&ys))
+}
+
+/// For comparison with `norm_squared_f32`.
+#[bench]
+fn norm_squared_f32_chain(b: &mut test::Bencher) {
+    let (xs, _) = dot_data();
+    b.iter(|| xs.iter().map(|x| x * x).sum::<f32>())
+}
+
+#[bench]
+fn norm_squared_f32(b: &mut test::Bencher) {
+    let (xs, _) = dot_data();
+    b.iter(|| xs.iter().norm_squared())
+}
+
+/// For comparison with `dot_i32`.
+#[bench]
+fn dot_i32_chain(b: &mut test::Bencher) {
+    let xs = test::black_box((0..1024).collect::<Vec<i32>>());
+    b.iter(|| xs.iter().zip(&xs).map(|(x, y)| x * y).sum::<i32>())
+}
+
+#[bench]
+fn dot_i32(b: &mut test::Bencher) {
+    let xs = test::black_box((0..1024).collect::<Vec<i32>>());
+    b.iter(|| xs.iter().dot(&xs))
+}
@@ -88,9 +88,7 @@ fn main() {
 
         for iris in species_group {
             // using Itertools::format for lazy formatting
-            println!("{}",
-                     iris.data.iter()
-                     .format(", ", |elt, f| f(&format_args!("{:>3.1}", elt))));
+            println!("{:>3.1}", iris.data.iter().format(", "));
         }
 
     }
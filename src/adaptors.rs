@@ -5,16 +5,22 @@
 //! except according to those terms.
 
 use std::cmp;
+use std::marker::PhantomData;
+#[cfg(feature = "use_std")]
 use std::mem;
 #[cfg(feature = "unstable")]
 use std::num::One;
 #[cfg(feature = "unstable")]
 use std::ops::Add;
+#[cfg(feature = "use_std")]
 use std::ops::Index;
-use std::iter::{Fuse, Peekable, FlatMap};
-use std::collections::HashSet;
+use std::iter::{Fuse, FusedIterator, Peekable, FlatMap};
+#[cfg(feature = "use_std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "use_std")]
+use std::collections::hash_map::Entry;
+#[cfg(feature = "use_std")]
 use std::hash::Hash;
-use Itertools;
 use size_hint;
 use misc::MendSlice;
 
@@ -76,6 +82,63 @@ impl<I, J> Iterator for Interleave<I, J>
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::add(self.a.size_hint(), self.b.size_hint())
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+        where F: FnMut(B, Self::Item) -> B
+    {
+        let Interleave { mut a, mut b, flag } = self;
+        let mut accum = init;
+        if flag {
+            match b.next() {
+                Some(x) => accum = f(accum, x),
+                None => return a.fold(accum, f),
+            }
+        }
+        // once either side runs out, the rest of the other is folded in one go
+        loop {
+            match a.next() {
+                Some(x) => accum = f(accum, x),
+                None => return b.fold(accum, f),
+            }
+            match b.next() {
+                Some(x) => accum = f(accum, x),
+                None => return a.fold(accum, f),
+            }
+        }
+    }
+
+    fn count(self) -> usize {
+        self.a.count() + self.b.count()
+    }
+}
+
+impl<I, J> FusedIterator for Interleave<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>
+{}
+
+impl<I, J> DoubleEndedIterator for Interleave<I, J>
+    where I: DoubleEndedIterator + ExactSizeIterator,
+          J: DoubleEndedIterator<Item = I::Item> + ExactSizeIterator
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        // whichever iterator is longer supplies the tail; with equal
+        // lengths, the last element is from the one not next in turn
+        match self.a.len().cmp(&self.b.len()) {
+            cmp::Ordering::Greater => self.a.next_back(),
+            cmp::Ordering::Less => self.b.next_back(),
+            cmp::Ordering::Equal => if self.flag {
+                self.a.next_back()
+            } else {
+                self.b.next_back()
+            },
+        }
+    }
 }
 
 /// An iterator adaptor that alternates elements from the two iterators until
@@ -93,6 +156,7 @@ pub struct InterleaveShortest<I, J>
     it0: I,
     it1: J,
     phase: bool, // false ==> it0, true ==> it1
+    done: bool,
 }
 
 impl<I, J> InterleaveShortest<I, J>
@@ -105,6 +169,7 @@ impl<I, J> InterleaveShortest<I, J>
             it0: a,
             it1: b,
             phase: false,
+            done: false,
         }
     }
 }
@@ -117,47 +182,127 @@ impl<I, J> Iterator for InterleaveShortest<I, J>
 
     #[inline]
     fn next(&mut self) -> Option<I::Item> {
-        match self.phase {
-            false => match self.it0.next() {
-                None => None,
-                e => {
-                    self.phase = true;
-                    e
-                }
-            },
-            true => match self.it1.next() {
-                None => None,
-                e => {
-                    self.phase = false;
-                    e
-                }
-            },
+        if self.done {
+            return None;
+        }
+        let elt = match self.phase {
+            false => self.it0.next(),
+            true => self.it1.next(),
+        };
+        match elt {
+            // the sources are not fused, so never poll them again
+            None => self.done = true,
+            Some(_) => self.phase = !self.phase,
         }
+        elt
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let bound = |a: usize, b: usize| -> Option<usize> {
-            use std::cmp::min;
-            2usize.checked_mul(min(a, b))
-                .and_then(|lhs| lhs.checked_add(if !self.phase && a > b { 1 } else { 0 }))
-        };
-
+        if self.done {
+            return (0, Some(0));
+        }
+        // twice the shorter length, plus one when the iterator whose turn
+        // it is has an element more
         let (l0, u0) = self.it0.size_hint();
         let (l1, u1) = self.it1.size_hint();
-        let lb = bound(l0, l1).unwrap_or(usize::max_value());
-        let ub = match (u0, u1) {
-            (None, None) => None,
-            (Some(u0), None) => Some(u0 * 2 + self.phase as usize),
-            (None, Some(u1)) => Some(u1 * 2 + !self.phase as usize),
-            (Some(u0), Some(u1)) => Some(cmp::min(u0, u1) * 2 +
-                                         (u0 > u1 && !self.phase ||
-                                          (u0 < u1 && self.phase)) as usize),
+        let low_extra = !self.phase && l0 > l1;
+        let (hi, hi_extra) = match (u0, u1) {
+            (None, None) => (None, false),
+            (Some(u0), None) => (Some(u0), self.phase),
+            (None, Some(u1)) => (Some(u1), !self.phase),
+            (Some(u0), Some(u1)) => (Some(cmp::min(u0, u1)),
+                                     u0 > u1 && !self.phase || (u0 < u1 && self.phase)),
         };
+        let (lb, _) = size_hint::add_scalar(size_hint::mul_scalar((cmp::min(l0, l1), None), 2),
+                                            low_extra as usize);
+        let (_, ub) = size_hint::add_scalar(size_hint::mul_scalar((0, hi), 2),
+                                            hi_extra as usize);
         (lb, ub)
     }
 }
 
+impl<I, J> FusedIterator for InterleaveShortest<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>
+{}
+
+/// An iterator adaptor that alternates runs of elements from two iterators,
+/// taking a fixed number of elements from each in turn, until both run out.
+///
+/// This iterator is *fused*.
+///
+/// See [*.interleave_weighted()*](trait.Itertools.html#method.interleave_weighted)
+/// for more information.
+#[derive(Clone)]
+pub struct InterleaveWeighted<I, J> {
+    a: Fuse<I>,
+    b: Fuse<J>,
+    weight_a: usize,
+    weight_b: usize,
+    // number of elements taken in the current run
+    taken: usize,
+    on_b: bool,
+}
+
+impl<I, J> InterleaveWeighted<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>
+{
+    /// Create a new `InterleaveWeighted` iterator.
+    ///
+    /// **Panics** if either weight is zero.
+    pub fn new(a: I, b: J, weight_a: usize, weight_b: usize) -> InterleaveWeighted<I, J> {
+        assert!(weight_a != 0 && weight_b != 0,
+                "InterleaveWeighted: weights must be non-zero");
+        InterleaveWeighted {
+            a: a.fuse(),
+            b: b.fuse(),
+            weight_a: weight_a,
+            weight_b: weight_b,
+            taken: 0,
+            on_b: false,
+        }
+    }
+}
+
+impl<I, J> Iterator for InterleaveWeighted<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let weight = if self.on_b { self.weight_b } else { self.weight_a };
+        if self.taken == weight {
+            self.on_b = !self.on_b;
+            self.taken = 0;
+        }
+        let elt = if self.on_b {
+            match self.b.next() {
+                None => return self.a.next(),
+                elt => elt,
+            }
+        } else {
+            match self.a.next() {
+                None => return self.b.next(),
+                elt => elt,
+            }
+        };
+        self.taken += 1;
+        elt
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::add(self.a.size_hint(), self.b.size_hint())
+    }
+}
+
+impl<I, J> FusedIterator for InterleaveWeighted<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>
+{}
+
 #[derive(Clone)]
 /// An iterator adaptor that allows putting back a single
 /// item to the front of the iterator.
@@ -229,11 +374,13 @@ impl<I> Iterator for PutBack<I>
 /// items in front of the iterator.
 ///
 /// Iterator element type is `I::Item`.
+#[cfg(feature = "use_std")]
 pub struct PutBackN<I: Iterator> {
     top: Vec<I::Item>,
     iter: I,
 }
 
+#[cfg(feature = "use_std")]
 impl<I: Iterator> PutBackN<I> {
     /// Iterator element type is `A`
     #[inline]
@@ -263,6 +410,7 @@ impl<I: Iterator> PutBackN<I> {
     }
 }
 
+#[cfg(feature = "use_std")]
 impl<I: Iterator> Iterator for PutBackN<I> {
     type Item = I::Item;
     #[inline]
@@ -280,6 +428,7 @@ impl<I: Iterator> Iterator for PutBackN<I> {
     }
 }
 
+#[cfg(feature = "use_std")]
 impl<I: Iterator> Clone for PutBackN<I>
     where I: Clone,
           I::Item: Clone
@@ -303,6 +452,9 @@ pub struct Product<I, J>
     a_cur: Option<I::Item>,
     b: J,
     b_orig: J,
+    /// The last row, when iterating from the back: its element of `I`
+    /// and the part of `J` that is left for it
+    back: Option<(I::Item, J)>,
 }
 
 impl<I, J> Product<I, J>
@@ -320,6 +472,7 @@ impl<I, J> Product<I, J>
             a: i,
             b: j.clone(),
             b_orig: j,
+            back: None,
         }
     }
 }
@@ -334,13 +487,35 @@ impl<I, J> Iterator for Product<I, J>
     fn next(&mut self) -> Option<(I::Item, J::Item)> {
         let elt_b = match self.b.next() {
             None => {
-                self.b = self.b_orig.clone();
-                match self.b.next() {
-                    None => return None,
-                    Some(x) => {
-                        self.a_cur = self.a.next();
-                        x
+                match self.a.next() {
+                    Some(a) => {
+                        self.b = self.b_orig.clone();
+                        match self.b.next() {
+                            None => return None,
+                            Some(x) => {
+                                self.a_cur = Some(a);
+                                x
+                            }
+                        }
                     }
+                    // continue with the row started from the back, if any
+                    None => match self.back.take() {
+                        Some((a, mut b)) => match b.next() {
+                            None => {
+                                self.a_cur = None;
+                                return None;
+                            }
+                            Some(x) => {
+                                self.a_cur = Some(a);
+                                self.b = b;
+                                x
+                            }
+                        },
+                        None => {
+                            self.a_cur = None;
+                            return None;
+                        }
+                    },
                 }
             }
             Some(x) => x
@@ -357,11 +532,43 @@ impl<I, J> Iterator for Product<I, J>
         let has_cur = self.a_cur.is_some() as usize;
         // Not ExactSizeIterator because size may be larger than usize
         let (b, _) = self.b.size_hint();
+        let back = match self.back {
+            Some((_, ref b)) => b.size_hint(),
+            None => (0, Some(0)),
+        };
+
+        // Compute a * b_orig + b + back for both lower and upper bound
+        size_hint::add(
+            size_hint::add_scalar(
+                size_hint::mul(self.a.size_hint(), self.b_orig.size_hint()),
+                b * has_cur),
+            back)
+    }
+}
 
-        // Compute a * b_orig + b for both lower and upper bound
-        size_hint::add_scalar(
-            size_hint::mul(self.a.size_hint(), self.b_orig.size_hint()),
-            b * has_cur)
+impl<I, J> DoubleEndedIterator for Product<I, J>
+    where I: DoubleEndedIterator,
+          J: Clone + DoubleEndedIterator,
+          I::Item: Clone
+{
+    fn next_back(&mut self) -> Option<(I::Item, J::Item)> {
+        loop {
+            if let Some((ref a, ref mut b)) = self.back {
+                if let Some(x) = b.next_back() {
+                    return Some((a.clone(), x));
+                }
+            }
+            match self.a.next_back() {
+                Some(a) => self.back = Some((a, self.b_orig.clone())),
+                None => break,
+            }
+        }
+        // the rows in between are used up: finish the current front row
+        self.back = None;
+        match self.a_cur {
+            None => None,
+            Some(ref a) => self.b.next_back().map(|x| (a.clone(), x)),
+        }
     }
 }
 
@@ -405,16 +612,20 @@ impl<B, F, I> Iterator for Batching<I, F>
 /// An iterator adaptor that groups iterator elements. Consecutive elements
 /// that map to the same key (“runs”), are returned as the iterator elements.
 ///
+/// This iterator is *fused*.
+///
 /// See [*.group_by()*](trait.Itertools.html#method.group_by) for more information.
+#[cfg(feature = "use_std")]
 pub struct GroupBy<K, I, F>
     where I: Iterator
 {
     key: F,
-    iter: I,
+    iter: Fuse<I>,
     current_key: Option<K>,
     elts: Vec<I::Item>,
 }
 
+#[cfg(feature = "use_std")]
 impl<K, F, I> GroupBy<K, I, F>
     where I: Iterator
 {
@@ -422,13 +633,14 @@ impl<K, F, I> GroupBy<K, I, F>
     pub fn new(iter: I, key: F) -> Self {
         GroupBy {
             key: key,
-            iter: iter,
+            iter: iter.fuse(),
             current_key: None,
             elts: Vec::new(),
         }
     }
 }
 
+#[cfg(feature = "use_std")]
 impl<K, I, F> Iterator for GroupBy<K, I, F>
     where K: PartialEq,
           I: Iterator,
@@ -470,6 +682,13 @@ impl<K, I, F> Iterator for GroupBy<K, I, F>
     }
 }
 
+#[cfg(feature = "use_std")]
+impl<K, I, F> FusedIterator for GroupBy<K, I, F>
+    where K: PartialEq,
+          I: Iterator,
+          F: FnMut(&I::Item) -> K
+{}
+
 /// An iterator adaptor that steps a number elements in the base iterator
 /// for each iteration.
 ///
@@ -505,7 +724,10 @@ impl<I> Iterator for Step<I>
     #[inline]
     fn next(&mut self) -> Option<I::Item> {
         let elt = self.iter.next();
-        self.iter.dropn(self.skip);
+        if self.skip > 0 {
+            // .nth() lets the base iterator skip ahead without yielding
+            self.iter.nth(self.skip - 1);
+        }
         elt
     }
 
@@ -522,217 +744,240 @@ impl<I> Iterator for Step<I>
     }
 }
 
+impl<I> FusedIterator for Step<I>
+    where I: Iterator
+{}
+
 // known size
 impl<I> ExactSizeIterator for Step<I>
     where I: ExactSizeIterator
 {}
 
-
-struct MergeCore<I, J>
-    where I: Iterator,
-          J: Iterator<Item = I::Item>
-{
-    a: Peekable<I>,
-    b: Peekable<J>,
-    fused: Option<bool>,
+/// An iterator adaptor that yields every `n`-th element counted from the
+/// end of the base iterator, starting with the last one.
+///
+/// See [*.step_back()*](trait.Itertools.html#method.step_back) for more information.
+#[derive(Clone)]
+pub struct StepBack<I> {
+    iter: I,
+    step: usize,
 }
 
-
-impl<I, J> Clone for MergeCore<I, J>
-    where I: Iterator,
-          J: Iterator<Item = I::Item>,
-          Peekable<I>: Clone,
-          Peekable<J>: Clone
+impl<I> StepBack<I>
+    where I: DoubleEndedIterator + ExactSizeIterator
 {
-    fn clone(&self) -> Self {
-        clone_fields!(MergeCore, self, a, b, fused)
+    /// Create a `StepBack` iterator.
+    ///
+    /// **Panics** if the step is 0.
+    pub fn new(iter: I, step: usize) -> Self {
+        assert!(step != 0);
+        StepBack {
+            iter: iter,
+            step: step,
+        }
     }
 }
 
-impl<I, J> MergeCore<I, J>
-    where I: Iterator,
-          J: Iterator<Item = I::Item>
+impl<I> Iterator for StepBack<I>
+    where I: DoubleEndedIterator + ExactSizeIterator
 {
-    fn next_with<F>(&mut self, mut less_than: F) -> Option<I::Item>
-        where F: FnMut(&I::Item, &I::Item) -> bool
-    {
-        let less_than = match self.fused {
-            Some(lt) => lt,
-            None => match (self.a.peek(), self.b.peek()) {
-                (Some(a), Some(b)) => less_than(a, b),
-                (Some(_), None) => {
-                    self.fused = Some(true);
-                    true
-                }
-                (None, Some(_)) => {
-                    self.fused = Some(false);
-                    false
-                }
-                (None, None) => return None,
-            }
-        };
-
-        if less_than {
-            self.a.next()
-        } else {
-            self.b.next()
+    type Item = I::Item;
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        let elt = self.iter.next_back();
+        if elt.is_some() && self.step > 1 {
+            // keep the back of the base iterator on a selected element
+            self.iter.nth_back(self.step - 2);
         }
+        elt
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        // Not ExactSizeIterator because size may be larger than usize
-        size_hint::add(self.a.size_hint(), self.b.size_hint())
+        let len = self.iter.len();
+        let n = if len == 0 { 0 } else { 1 + (len - 1) / self.step };
+        (n, Some(n))
     }
 }
 
-/// An iterator adaptor that merges the two base iterators in ascending order.
-/// If both base iterators are sorted (ascending), the result is sorted.
-///
-/// Iterator element type is `I::Item`.
-///
-/// See [*.merge()*](trait.Itertools.html#method.merge_by) for more information.
-pub struct Merge<I, J>
-    where I: Iterator,
-          J: Iterator<Item = I::Item>
+impl<I> DoubleEndedIterator for StepBack<I>
+    where I: DoubleEndedIterator + ExactSizeIterator
 {
-    merge: MergeCore<I, J>,
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        let len = self.iter.len();
+        if len == 0 {
+            return None;
+        }
+        // the first selected element is aligned to the back
+        self.iter.nth((len - 1) % self.step)
+    }
 }
 
-impl<I, J> Clone for Merge<I, J>
-    where I: Iterator,
-          J: Iterator<Item = I::Item>,
-          Peekable<I>: Clone,
-          Peekable<J>: Clone
-{
-    fn clone(&self) -> Self {
-        clone_fields!(Merge, self, merge)
-    }
+impl<I> ExactSizeIterator for StepBack<I>
+    where I: DoubleEndedIterator + ExactSizeIterator
+{}
+
+
+/// An iterator adaptor that repeats a clonable iterator until exactly
+/// a given number of elements have been produced.
+///
+/// See [*.cycled_to_len()*](trait.Itertools.html#method.cycled_to_len) for more information.
+#[derive(Clone)]
+pub struct CycledToLen<I> {
+    orig: I,
+    iter: I,
+    remaining: usize,
 }
 
-/// Create a `Merge` iterator.
-pub fn merge_new<I, J>(a: I, b: J) -> Merge<I, J>
-    where I: Iterator,
-          J: Iterator<Item = I::Item>
+impl<I> CycledToLen<I>
+    where I: Iterator + Clone
 {
-    Merge {
-        merge: MergeCore {
-            a: a.peekable(),
-            b: b.peekable(),
-            fused: None,
-        },
+    /// Create a `CycledToLen` iterator.
+    pub fn new(iter: I, total: usize) -> Self {
+        // an empty iterator can't be cycled to any length
+        let remaining = if total > 0 && iter.clone().next().is_none() {
+            0
+        } else {
+            total
+        };
+        CycledToLen {
+            orig: iter.clone(),
+            iter: iter,
+            remaining: remaining,
+        }
     }
 }
 
-impl<I, J> Iterator for Merge<I, J>
-    where I: Iterator,
-          J: Iterator<Item = I::Item>,
-          I::Item: PartialOrd
+impl<I> Iterator for CycledToLen<I>
+    where I: Iterator + Clone
 {
     type Item = I::Item;
-
+    #[inline]
     fn next(&mut self) -> Option<I::Item> {
-        self.merge.next_with(|a, b| a <= b)
+        if self.remaining == 0 {
+            return None;
+        }
+        let elt = match self.iter.next() {
+            None => {
+                self.iter = self.orig.clone();
+                self.iter.next()
+            }
+            elt => elt,
+        };
+        match elt {
+            None => self.remaining = 0,
+            Some(_) => self.remaining -= 1,
+        }
+        elt
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.merge.size_hint()
+        (self.remaining, Some(self.remaining))
     }
 }
 
-/// An iterator adaptor that merges the two base iterators in ascending order.
-/// If both base iterators are sorted (ascending), the result is sorted.
-///
-/// Iterator element type is `I::Item`.
+// known size
+impl<I> ExactSizeIterator for CycledToLen<I>
+    where I: Iterator + Clone
+{}
+
+/// An iterator adaptor that calls a closure on every *n*-th element,
+/// and then passes the element on.
 ///
-/// See [*.merge_by()*](trait.Itertools.html#method.merge_by) for more information.
-pub struct MergeBy<I, J, F>
-    where I: Iterator,
-          J: Iterator<Item = I::Item>
-{
-    merge: MergeCore<I, J>,
-    cmp: F,
+/// See [*.inspect_every()*](trait.Itertools.html#method.inspect_every) for more information.
+#[derive(Clone)]
+pub struct InspectEvery<I, F> {
+    iter: I,
+    n: usize,
+    index: usize,
+    f: F,
 }
 
-/// Create a `MergeBy` iterator.
-pub fn merge_by_new<I, J, F>(a: I, b: J, cmp: F) -> MergeBy<I, J, F>
+impl<I, F> InspectEvery<I, F>
     where I: Iterator,
-          J: Iterator<Item = I::Item>
+          F: FnMut(usize, &I::Item)
 {
-    MergeBy {
-        merge: MergeCore {
-            a: a.peekable(),
-            b: b.peekable(),
-            fused: None,
-        },
-        cmp: cmp,
+    /// Create an `InspectEvery` iterator.
+    ///
+    /// **Panics** if `n` is 0.
+    pub fn new(iter: I, n: usize, f: F) -> Self {
+        assert!(n != 0);
+        InspectEvery {
+            iter: iter,
+            n: n,
+            index: 0,
+            f: f,
+        }
     }
 }
 
-impl<I, J, F> Clone for MergeBy<I, J, F>
+impl<I, F> Iterator for InspectEvery<I, F>
     where I: Iterator,
-          J: Iterator<Item = I::Item>,
-          Peekable<I>: Clone,
-          Peekable<J>: Clone,
-          F: Clone
-{
-    fn clone(&self) -> Self {
-        clone_fields!(MergeBy, self, merge, cmp)
-    }
-}
-
-impl<I, J, F> Iterator for MergeBy<I, J, F>
-    where I: Iterator,
-          J: Iterator<Item = I::Item>,
-          F: FnMut(&I::Item, &I::Item) -> bool
+          F: FnMut(usize, &I::Item)
 {
     type Item = I::Item;
-
+    #[inline]
     fn next(&mut self) -> Option<I::Item> {
-        self.merge.next_with(&mut self.cmp)
+        let elt = self.iter.next();
+        if let Some(ref elt) = elt {
+            if self.index % self.n == 0 {
+                (self.f)(self.index, elt);
+            }
+            self.index += 1;
+        }
+        elt
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.merge.size_hint()
+        self.iter.size_hint()
     }
 }
 
-#[cfg(feature = "unstable")]
-/// An iterator adaptor that enumerates the iterator elements,
-/// with a custom starting value and integer type.
+// Same size
+impl<I, F> ExactSizeIterator for InspectEvery<I, F>
+    where I: ExactSizeIterator,
+          F: FnMut(usize, &I::Item)
+{}
+
+/// An iterator adaptor that pairs each element with the index of the
+/// chunk of *n* elements it falls in.
 ///
-/// See [*.enumerate_from()*](trait.Itertools.html#method.enumerate_from) for more information.
-pub struct EnumerateFrom<I, K> {
-    index: K,
+/// See [*.with_chunk_index()*](trait.Itertools.html#method.with_chunk_index)
+/// for more information.
+#[derive(Clone)]
+pub struct WithChunkIndex<I> {
     iter: I,
+    n: usize,
+    count: usize,
 }
 
-#[cfg(feature = "unstable")]
-impl<K, I> EnumerateFrom<I, K>
+impl<I> WithChunkIndex<I>
     where I: Iterator
 {
-    /// Create a new `EnumerateFrom`.
-    pub fn new(iter: I, start: K) -> Self {
-        EnumerateFrom {
-            index: start,
+    /// Create a `WithChunkIndex` iterator.
+    ///
+    /// **Panics** if `n` is 0.
+    pub fn new(iter: I, n: usize) -> Self {
+        assert!(n != 0);
+        WithChunkIndex {
             iter: iter,
+            n: n,
+            count: 0,
         }
     }
 }
 
-#[cfg(feature = "unstable")]
-impl<K, I> Iterator for EnumerateFrom<I, K>
-    where K: Copy + One + Add<Output = K>,
-          I: Iterator
+impl<I> Iterator for WithChunkIndex<I>
+    where I: Iterator
 {
-    type Item = (K, I::Item);
-    fn next(&mut self) -> Option<(K, I::Item)> {
+    type Item = (usize, I::Item);
+    #[inline]
+    fn next(&mut self) -> Option<(usize, I::Item)> {
         match self.iter.next() {
             None => None,
             Some(elt) => {
-                let index = self.index.clone();
-                // FIXME: Arithmetic needs to be wrapping here to be sane,
-                // imagine i8 counter to enumerate a sequence 0 to 127 inclusive.
-                self.index = self.index + K::one();
+                let index = self.count / self.n;
+                self.count += 1;
                 Some((index, elt))
             }
         }
@@ -744,257 +989,2171 @@ impl<K, I> Iterator for EnumerateFrom<I, K>
 }
 
 // Same size
-#[cfg(feature = "unstable")]
-impl<K, I> ExactSizeIterator for EnumerateFrom<I, K>
-    where K: Copy + One + Add<Output = K>,
-          I: ExactSizeIterator
+impl<I> ExactSizeIterator for WithChunkIndex<I>
+    where I: ExactSizeIterator
 {}
 
-#[derive(Clone)]
-/// An iterator adaptor that allows the user to peek at multiple *.next()*
-/// values without advancing itself.
+/// An iterator adaptor that yields exactly *n* elements of the base
+/// iterator, and panics if it runs out before that.
 ///
-/// See [*.multipeek()*](trait.Itertools.html#method.multipeek) for more information.
-pub struct MultiPeek<I>
-    where I: Iterator
-{
-    iter: Fuse<I>,
-    buf: Vec<I::Item>,
-    index: usize,
+/// See [*.take_exact()*](trait.Itertools.html#method.take_exact) for more information.
+#[derive(Clone)]
+pub struct TakeExact<I> {
+    iter: I,
+    n: usize,
+    remaining: usize,
 }
 
-impl<I: Iterator> MultiPeek<I> {
-    /// Create a `MultiPeek` iterator.
-    pub fn new(iter: I) -> MultiPeek<I> {
-        MultiPeek {
-            iter: iter.fuse(),
-            buf: Vec::new(),
-            index: 0,
+impl<I> TakeExact<I>
+    where I: Iterator
+{
+    /// Create a `TakeExact` iterator.
+    pub fn new(iter: I, n: usize) -> Self {
+        TakeExact {
+            iter: iter,
+            n: n,
+            remaining: n,
         }
     }
-
-    /// Works exactly like *.next()* with the only difference that it doesn't
-    /// advance itself. *.peek()* can be called multiple times, to peek
-    /// further ahead.
-    pub fn peek(&mut self) -> Option<&I::Item> {
-        let ret = if self.index < self.buf.len() {
-            Some(&self.buf[self.index])
-        } else {
-            match self.iter.next() {
-                Some(x) => {
-                    self.buf.push(x);
-                    Some(&self.buf[self.index])
-                }
-                None => return None,
-            }
-        };
-
-        self.index += 1;
-        ret
-    }
 }
 
-impl<I> Iterator for MultiPeek<I>
+impl<I> Iterator for TakeExact<I>
     where I: Iterator
 {
     type Item = I::Item;
-
+    #[inline]
     fn next(&mut self) -> Option<I::Item> {
-        self.index = 0;
-        if self.buf.is_empty() {
-            self.iter.next()
-        } else {
-            Some(self.buf.remove(0))
+        if self.remaining == 0 {
+            return None;
+        }
+        match self.iter.next() {
+            Some(elt) => {
+                self.remaining -= 1;
+                Some(elt)
+            }
+            None => panic!("TakeExact: iterator ended after {} of {} elements",
+                           self.n - self.remaining, self.n),
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        size_hint::add_scalar(self.iter.size_hint(), self.buf.len())
+        (self.remaining, Some(self.remaining))
     }
 }
 
-// Same size
-impl<I> ExactSizeIterator for MultiPeek<I>
-    where I: ExactSizeIterator
+// known size
+impl<I> ExactSizeIterator for TakeExact<I>
+    where I: Iterator
 {}
 
+/// An iterator adaptor that folds each complete chunk of *n* elements
+/// into one value, leaving any incomplete chunk at the end untouched.
+///
+/// See [*.fold_chunks_exact()*](trait.Itertools.html#method.fold_chunks_exact)
+/// for more information.
+#[cfg(feature = "use_std")]
 #[derive(Clone)]
-pub struct CoalesceCore<I>
+pub struct FoldChunksExact<I, G, F>
     where I: Iterator
 {
-    iter: I,
-    last: Option<I::Item>,
+    iter: Fuse<I>,
+    partial: Vec<I::Item>,
+    n: usize,
+    init: G,
+    f: F,
 }
 
-impl<I> CoalesceCore<I>
+#[cfg(feature = "use_std")]
+impl<I, G, F> FoldChunksExact<I, G, F>
     where I: Iterator
 {
-    fn next_with<F>(&mut self, mut f: F) -> Option<I::Item>
-        where F: FnMut(I::Item, I::Item) -> Result<I::Item, (I::Item, I::Item)>
-    {
-        // this fuses the iterator
-        let mut last = match self.last.take() {
-            None => return None,
-            Some(x) => x,
-        };
-        for next in &mut self.iter {
-            match f(last, next) {
-                Ok(joined) => last = joined,
-                Err((last_, next_)) => {
-                    self.last = Some(next_);
-                    return Some(last_);
-                }
-            }
+    /// Create a `FoldChunksExact` iterator.
+    ///
+    /// **Panics** if `n` is 0.
+    pub fn new(iter: I, n: usize, init: G, f: F) -> Self {
+        assert!(n != 0);
+        FoldChunksExact {
+            iter: iter.fuse(),
+            partial: Vec::new(),
+            n: n,
+            init: init,
+            f: f,
         }
-
-        Some(last)
     }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let (low, hi) = size_hint::add_scalar(self.iter.size_hint(),
-                                              self.last.is_some() as usize);
-        ((low > 0) as usize, hi)
+    /// Return the fewer than *n* elements of the incomplete last chunk.
+    ///
+    /// The iterator is empty until this adaptor has been exhausted.
+    pub fn into_remainder(self) -> ::std::vec::IntoIter<I::Item> {
+        self.partial.into_iter()
     }
 }
 
-/// An iterator adaptor that may join together adjacent elements.
-///
-/// See [*.coalesce()*](trait.Itertools.html#method.coalesce) for more information.
-pub struct Coalesce<I, F>
-    where I: Iterator
-{
-    iter: CoalesceCore<I>,
-    f: F,
-}
-
-impl<I: Clone, F: Clone> Clone for Coalesce<I, F>
+#[cfg(feature = "use_std")]
+impl<B, I, G, F> Iterator for FoldChunksExact<I, G, F>
     where I: Iterator,
-          I::Item: Clone
+          G: FnMut() -> B,
+          F: FnMut(B, I::Item) -> B
 {
-    fn clone(&self) -> Self {
-        clone_fields!(Coalesce, self, iter, f)
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        if !self.partial.is_empty() {
+            return None;
+        }
+        // when the lower bound covers the chunk, fold straight from the
+        // iterator; otherwise hold the elements until the chunk is complete
+        if self.iter.size_hint().0 >= self.n {
+            let mut acc = (self.init)();
+            for elt in self.iter.by_ref().take(self.n) {
+                acc = (self.f)(acc, elt);
+            }
+            return Some(acc);
+        }
+        while self.partial.len() < self.n {
+            match self.iter.next() {
+                Some(elt) => self.partial.push(elt),
+                None => return None,
+            }
+        }
+        let f = &mut self.f;
+        Some(self.partial.drain(..).fold((self.init)(), |acc, elt| f(acc, elt)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if !self.partial.is_empty() {
+            return (0, Some(0));
+        }
+        let (low, hi) = self.iter.size_hint();
+        (low / self.n, hi.map(|hi| hi / self.n))
     }
 }
 
-impl<I, F> Coalesce<I, F>
-    where I: Iterator
+#[cfg(feature = "use_std")]
+impl<B, I, G, F> ExactSizeIterator for FoldChunksExact<I, G, F>
+    where I: ExactSizeIterator,
+          G: FnMut() -> B,
+          F: FnMut(B, I::Item) -> B
+{}
+
+/// An iterator adaptor that pairs each element with the element `lag`
+/// places after it in the same sequence.
+///
+/// See [*.zip_offset()*](trait.Itertools.html#method.zip_offset) for more information.
+#[derive(Clone)]
+pub struct ZipOffset<I> {
+    front: I,
+    back: I,
+    lag: usize,
+}
+
+impl<I> ZipOffset<I>
+    where I: Iterator + Clone
 {
-    /// Create a new `Coalesce`.
-    pub fn new(mut iter: I, f: F) -> Self {
-        Coalesce {
-            iter: CoalesceCore {
-                last: iter.next(),
-                iter: iter,
-            },
-            f: f,
+    /// Create a `ZipOffset` iterator.
+    pub fn new(iter: I, lag: usize) -> Self {
+        ZipOffset {
+            back: iter.clone(),
+            front: iter,
+            lag: lag,
         }
     }
 }
 
-impl<I, F> Iterator for Coalesce<I, F>
-    where I: Iterator,
-          F: FnMut(I::Item, I::Item) -> Result<I::Item, (I::Item, I::Item)>
+impl<I> Iterator for ZipOffset<I>
+    where I: Iterator + Clone
 {
-    type Item = I::Item;
-
-    fn next(&mut self) -> Option<I::Item> {
-        self.iter.next_with(&mut self.f)
+    type Item = (I::Item, I::Item);
+    #[inline]
+    fn next(&mut self) -> Option<(I::Item, I::Item)> {
+        // skip ahead lazily, on the first call
+        while self.lag > 0 {
+            self.lag -= 1;
+            if self.back.next().is_none() {
+                self.lag = 0;
+                return None;
+            }
+        }
+        match self.back.next() {
+            None => None,
+            Some(b) => self.front.next().map(|a| (a, b)),
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        size_hint::sub_scalar(self.back.size_hint(), self.lag)
     }
 }
 
-/// An iterator adaptor that removes repeated duplicates.
-///
-/// See [*.dedup()*](trait.Itertools.html#method.dedup) for more information.
-pub struct Dedup<I>
-    where I: Iterator
+impl<I> ExactSizeIterator for ZipOffset<I>
+    where I: ExactSizeIterator + Clone
+{}
+
+struct MergeCore<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>
 {
-    iter: CoalesceCore<I>,
+    a: Peekable<I>,
+    b: Peekable<J>,
+    /// Elements taken from the back of `a` and `b` but not yielded yet
+    a_back: Option<I::Item>,
+    b_back: Option<I::Item>,
+    fused: Option<bool>,
 }
 
-impl<I: Clone> Clone for Dedup<I>
+
+impl<I, J> Clone for MergeCore<I, J>
     where I: Iterator,
-          I::Item: Clone
+          J: Iterator<Item = I::Item>,
+          I::Item: Clone,
+          Peekable<I>: Clone,
+          Peekable<J>: Clone
 {
     fn clone(&self) -> Self {
-        clone_fields!(Dedup, self, iter)
+        clone_fields!(MergeCore, self, a, b, a_back, b_back, fused)
     }
 }
 
-impl<I> Dedup<I>
-    where I: Iterator
+impl<I, J> MergeCore<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>
 {
-    /// Create a new `Dedup`.
-    pub fn new(mut iter: I) -> Self {
-        Dedup {
-            iter: CoalesceCore {
-                last: iter.next(),
-                iter: iter,
-            },
+    fn new(a: I, b: J) -> Self {
+        MergeCore {
+            a: a.peekable(),
+            b: b.peekable(),
+            a_back: None,
+            b_back: None,
+            fused: None,
         }
     }
+
+    fn next_with<F>(&mut self, mut less_than: F) -> Option<I::Item>
+        where F: FnMut(&I::Item, &I::Item) -> bool
+    {
+        let less_than = match self.fused {
+            Some(lt) => lt,
+            None => {
+                let a = match self.a.peek() {
+                    None => self.a_back.as_ref(),
+                    a => a,
+                };
+                let b = match self.b.peek() {
+                    None => self.b_back.as_ref(),
+                    b => b,
+                };
+                match (a, b) {
+                    (Some(a), Some(b)) => less_than(a, b),
+                    (Some(_), None) => {
+                        self.fused = Some(true);
+                        true
+                    }
+                    (None, Some(_)) => {
+                        self.fused = Some(false);
+                        false
+                    }
+                    (None, None) => return None,
+                }
+            }
+        };
+
+        if less_than {
+            self.a.next().or_else(|| self.a_back.take())
+        } else {
+            self.b.next().or_else(|| self.b_back.take())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Not ExactSizeIterator because size may be larger than usize
+        let backs = self.a_back.is_some() as usize + self.b_back.is_some() as usize;
+        size_hint::add_scalar(size_hint::add(self.a.size_hint(), self.b.size_hint()), backs)
+    }
+
+    fn fold_with<B, F, G>(mut self, init: B, mut less_than: F, mut g: G) -> B
+        where F: FnMut(&I::Item, &I::Item) -> bool,
+              G: FnMut(B, I::Item) -> B
+    {
+        let mut accum = init;
+        while self.fused.is_none() {
+            match self.next_with(&mut less_than) {
+                Some(x) => accum = g(accum, x),
+                None => return accum,
+            }
+        }
+        // only one side is left: no more comparisons are needed
+        let MergeCore { a, b, a_back, b_back, fused } = self;
+        if fused == Some(true) {
+            accum = a.fold(accum, &mut g);
+            a_back.into_iter().fold(accum, g)
+        } else {
+            accum = b.fold(accum, &mut g);
+            b_back.into_iter().fold(accum, g)
+        }
+    }
+
+    fn count(self) -> usize {
+        let backs = self.a_back.is_some() as usize + self.b_back.is_some() as usize;
+        self.a.count() + self.b.count() + backs
+    }
 }
 
-impl<I> Iterator for Dedup<I>
+impl<I, J> MergeCore<I, J>
+    where I: DoubleEndedIterator,
+          J: DoubleEndedIterator<Item = I::Item>
+{
+    fn next_back_with<F>(&mut self, mut less_than: F) -> Option<I::Item>
+        where F: FnMut(&I::Item, &I::Item) -> bool
+    {
+        if self.a_back.is_none() {
+            self.a_back = self.a.next_back();
+        }
+        if self.b_back.is_none() {
+            self.b_back = self.b.next_back();
+        }
+        // the element that the front would pick last is the one that does
+        // not go before the other
+        let take_a = match (&self.a_back, &self.b_back) {
+            (&Some(ref a), &Some(ref b)) => !less_than(a, b),
+            (&Some(_), &None) => true,
+            (&None, _) => false,
+        };
+        if take_a {
+            self.a_back.take()
+        } else {
+            self.b_back.take()
+        }
+    }
+}
+
+/// An iterator adaptor that merges the two base iterators in ascending order.
+/// If both base iterators are sorted (ascending), the result is sorted.
+///
+/// Iterator element type is `I::Item`.
+///
+/// See [*.merge()*](trait.Itertools.html#method.merge_by) for more information.
+pub struct Merge<I, J>
     where I: Iterator,
-          I::Item: PartialEq
+          J: Iterator<Item = I::Item>
+{
+    merge: MergeCore<I, J>,
+}
+
+impl<I, J> Clone for Merge<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Clone,
+          Peekable<I>: Clone,
+          Peekable<J>: Clone
+{
+    fn clone(&self) -> Self {
+        clone_fields!(Merge, self, merge)
+    }
+}
+
+/// Create a `Merge` iterator.
+pub fn merge_new<I, J>(a: I, b: J) -> Merge<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>
+{
+    Merge {
+        merge: MergeCore::new(a, b),
+    }
+}
+
+impl<I, J> Iterator for Merge<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: PartialOrd
 {
     type Item = I::Item;
 
     fn next(&mut self) -> Option<I::Item> {
-        self.iter.next_with(|x, y| {
-            if x == y { Ok(x) } else { Err((x, y)) }
-        })
+        self.merge.next_with(|a, b| a <= b)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        self.merge.size_hint()
+    }
+
+    fn fold<B, G>(self, init: B, g: G) -> B
+        where G: FnMut(B, Self::Item) -> B
+    {
+        self.merge.fold_with(init, |a, b| a <= b, g)
+    }
+
+    fn count(self) -> usize {
+        self.merge.count()
     }
 }
 
-/// An iterator adaptor that glues together adjacent contiguous slices.
+impl<I, J> DoubleEndedIterator for Merge<I, J>
+    where I: DoubleEndedIterator,
+          J: DoubleEndedIterator<Item = I::Item>,
+          I::Item: PartialOrd
+{
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.merge.next_back_with(|a, b| a <= b)
+    }
+}
+
+/// An iterator adaptor that merges the two base iterators in ascending order.
+/// If both base iterators are sorted (ascending), the result is sorted.
 ///
-/// See [*.mend_slices()*](trait.Itertools.html#method.mend_slices) for more information.
-pub struct MendSlices<I>
-    where I: Iterator
+/// Iterator element type is `I::Item`.
+///
+/// See [*.merge_by()*](trait.Itertools.html#method.merge_by) for more information.
+pub struct MergeBy<I, J, F>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>
 {
-    iter: CoalesceCore<I>,
+    merge: MergeCore<I, J>,
+    cmp: F,
 }
 
-impl<I: Clone> Clone for MendSlices<I>
+/// Create a `MergeBy` iterator.
+pub fn merge_by_new<I, J, F>(a: I, b: J, cmp: F) -> MergeBy<I, J, F>
     where I: Iterator,
-          I::Item: Clone
+          J: Iterator<Item = I::Item>
+{
+    MergeBy {
+        merge: MergeCore::new(a, b),
+        cmp: cmp,
+    }
+}
+
+impl<I, J, F> Clone for MergeBy<I, J, F>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Clone,
+          Peekable<I>: Clone,
+          Peekable<J>: Clone,
+          F: Clone
 {
     fn clone(&self) -> Self {
-        clone_fields!(MendSlices, self, iter)
+        clone_fields!(MergeBy, self, merge, cmp)
     }
 }
 
-impl<I> MendSlices<I>
+impl<I, J, F> Iterator for MergeBy<I, J, F>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          F: FnMut(&I::Item, &I::Item) -> bool
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.merge.next_with(&mut self.cmp)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.merge.size_hint()
+    }
+
+    fn fold<B, G>(self, init: B, g: G) -> B
+        where G: FnMut(B, Self::Item) -> B
+    {
+        self.merge.fold_with(init, self.cmp, g)
+    }
+
+    fn count(self) -> usize {
+        self.merge.count()
+    }
+}
+
+impl<I, J, F> DoubleEndedIterator for MergeBy<I, J, F>
+    where I: DoubleEndedIterator,
+          J: DoubleEndedIterator<Item = I::Item>,
+          F: FnMut(&I::Item, &I::Item) -> bool
+{
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.merge.next_back_with(&mut self.cmp)
+    }
+}
+
+/// An iterator adaptor that merges the two base iterators in ascending order
+/// of a key. If both base iterators are sorted by that key, the result is
+/// sorted by it too.
+///
+/// Iterator element type is `I::Item`.
+///
+/// See [*.merge_by_key()*](trait.Itertools.html#method.merge_by_key) for more information.
+pub struct MergeByKey<I, J, F>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>
+{
+    merge: MergeCore<I, J>,
+    key: F,
+}
+
+/// Create a `MergeByKey` iterator.
+pub fn merge_by_key_new<I, J, F>(a: I, b: J, key: F) -> MergeByKey<I, J, F>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>
+{
+    MergeByKey {
+        merge: MergeCore::new(a, b),
+        key: key,
+    }
+}
+
+impl<I, J, F> Clone for MergeByKey<I, J, F>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Clone,
+          Peekable<I>: Clone,
+          Peekable<J>: Clone,
+          F: Clone
+{
+    fn clone(&self) -> Self {
+        clone_fields!(MergeByKey, self, merge, key)
+    }
+}
+
+impl<I, J, F, K> Iterator for MergeByKey<I, J, F>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          F: FnMut(&I::Item) -> K,
+          K: PartialOrd
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let key = &mut self.key;
+        // on equal keys, take from the first iterator, like .merge()
+        self.merge.next_with(|a, b| key(a) <= key(b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.merge.size_hint()
+    }
+
+    fn fold<B, G>(self, init: B, g: G) -> B
+        where G: FnMut(B, Self::Item) -> B
+    {
+        let mut key = self.key;
+        self.merge.fold_with(init, |a, b| key(a) <= key(b), g)
+    }
+
+    fn count(self) -> usize {
+        self.merge.count()
+    }
+}
+
+impl<I, J, F, K> DoubleEndedIterator for MergeByKey<I, J, F>
+    where I: DoubleEndedIterator,
+          J: DoubleEndedIterator<Item = I::Item>,
+          F: FnMut(&I::Item) -> K,
+          K: PartialOrd
+{
+    fn next_back(&mut self) -> Option<I::Item> {
+        let key = &mut self.key;
+        self.merge.next_back_with(|a, b| key(a) <= key(b))
+    }
+}
+
+#[cfg(feature = "unstable")]
+/// An iterator adaptor that enumerates the iterator elements,
+/// with a custom starting value and integer type.
+///
+/// See [*.enumerate_from()*](trait.Itertools.html#method.enumerate_from) for more information.
+pub struct EnumerateFrom<I, K> {
+    index: K,
+    iter: I,
+}
+
+#[cfg(feature = "unstable")]
+impl<K, I> EnumerateFrom<I, K>
     where I: Iterator
 {
-    /// Create a new `MendSlices`.
-    pub fn new(mut iter: I) -> Self {
-        MendSlices {
-            iter: CoalesceCore {
-                last: iter.next(),
-                iter: iter,
-            },
+    /// Create a new `EnumerateFrom`.
+    pub fn new(iter: I, start: K) -> Self {
+        EnumerateFrom {
+            index: start,
+            iter: iter,
+        }
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl<K, I> Iterator for EnumerateFrom<I, K>
+    where K: Copy + One + Add<Output = K>,
+          I: Iterator
+{
+    type Item = (K, I::Item);
+    fn next(&mut self) -> Option<(K, I::Item)> {
+        match self.iter.next() {
+            None => None,
+            Some(elt) => {
+                let index = self.index.clone();
+                // FIXME: Arithmetic needs to be wrapping here to be sane,
+                // imagine i8 counter to enumerate a sequence 0 to 127 inclusive.
+                self.index = self.index + K::one();
+                Some((index, elt))
+            }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+// Same size
+#[cfg(feature = "unstable")]
+impl<K, I> ExactSizeIterator for EnumerateFrom<I, K>
+    where K: Copy + One + Add<Output = K>,
+          I: ExactSizeIterator
+{}
+
+/// An integer type that can be used as the index of `.enumerate_as()`.
+pub trait EnumerateIndex: Copy {
+    /// The first index.
+    fn zero() -> Self;
+    /// The index after `self`, or `None` if it is not representable.
+    fn checked_next(self) -> Option<Self>;
+}
+
+macro_rules! impl_enumerate_index {
+    ($($t:ty)*) => {
+        $(
+            impl EnumerateIndex for $t {
+                #[inline]
+                fn zero() -> Self { 0 }
+                #[inline]
+                fn checked_next(self) -> Option<Self> { self.checked_add(1) }
+            }
+        )*
+    }
+}
+
+impl_enumerate_index!{u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize}
+
+#[derive(Clone, Debug)]
+/// An iterator adaptor that enumerates the iterator elements with indices
+/// of the integer type `T`.
+///
+/// See [*.enumerate_as()*](trait.Itertools.html#method.enumerate_as) for more information.
+pub struct EnumerateAs<I, T> {
+    iter: I,
+    /// The next index, `None` if it has overflowed
+    index: Option<T>,
+}
+
+/// Create a new `EnumerateAs`.
+pub fn enumerate_as<I, T>(iter: I) -> EnumerateAs<I, T>
+    where T: EnumerateIndex
+{
+    EnumerateAs {
+        iter: iter,
+        index: Some(T::zero()),
+    }
+}
+
+impl<I, T> Iterator for EnumerateAs<I, T>
+    where I: Iterator,
+          T: EnumerateIndex
+{
+    type Item = (T, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            None => None,
+            Some(elt) => {
+                let index = match self.index {
+                    None => panic!("EnumerateAs: index overflowed its integer type"),
+                    Some(index) => index,
+                };
+                self.index = index.checked_next();
+                Some((index, elt))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> ExactSizeIterator for EnumerateAs<I, T>
+    where I: ExactSizeIterator,
+          T: EnumerateIndex
+{}
+
+#[derive(Clone)]
+/// An iterator adaptor that allows the user to peek at multiple *.next()*
+/// values without advancing itself.
+///
+/// See [*.multipeek()*](trait.Itertools.html#method.multipeek) for more information.
+#[cfg(feature = "use_std")]
+pub struct MultiPeek<I>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    buf: Vec<I::Item>,
+    index: usize,
+}
+
+#[cfg(feature = "use_std")]
+impl<I: Iterator> MultiPeek<I> {
+    /// Create a `MultiPeek` iterator.
+    pub fn new(iter: I) -> MultiPeek<I> {
+        MultiPeek {
+            iter: iter.fuse(),
+            buf: Vec::new(),
+            index: 0,
+        }
+    }
+
+    /// Works exactly like *.next()* with the only difference that it doesn't
+    /// advance itself. *.peek()* can be called multiple times, to peek
+    /// further ahead.
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        let ret = if self.index < self.buf.len() {
+            Some(&self.buf[self.index])
+        } else {
+            match self.iter.next() {
+                Some(x) => {
+                    self.buf.push(x);
+                    Some(&self.buf[self.index])
+                }
+                None => return None,
+            }
+        };
+
+        self.index += 1;
+        ret
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<I> Iterator for MultiPeek<I>
+    where I: Iterator
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.index = 0;
+        if self.buf.is_empty() {
+            self.iter.next()
+        } else {
+            Some(self.buf.remove(0))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::add_scalar(self.iter.size_hint(), self.buf.len())
+    }
+}
+
+// Same size
+#[cfg(feature = "use_std")]
+impl<I> ExactSizeIterator for MultiPeek<I>
+    where I: ExactSizeIterator
+{}
+
+#[derive(Clone)]
+pub struct CoalesceCore<I>
+    where I: Iterator
+{
+    iter: I,
+    last: Option<I::Item>,
+}
+
+impl<I> CoalesceCore<I>
+    where I: Iterator
+{
+    fn next_with<F>(&mut self, mut f: F) -> Option<I::Item>
+        where F: FnMut(I::Item, I::Item) -> Result<I::Item, (I::Item, I::Item)>
+    {
+        // this fuses the iterator
+        let mut last = match self.last.take() {
+            None => return None,
+            Some(x) => x,
+        };
+        for next in &mut self.iter {
+            match f(last, next) {
+                Ok(joined) => last = joined,
+                Err((last_, next_)) => {
+                    self.last = Some(next_);
+                    return Some(last_);
+                }
+            }
+        }
+
+        Some(last)
+    }
+
+    fn fold_with<B, F, G>(self, init: B, mut f: F, mut g: G) -> B
+        where F: FnMut(I::Item, I::Item) -> Result<I::Item, (I::Item, I::Item)>,
+              G: FnMut(B, I::Item) -> B
+    {
+        let CoalesceCore { iter, last } = self;
+        let last = match last {
+            None => return init,
+            Some(x) => x,
+        };
+        let (accum, last) = iter.fold((init, last), |(accum, last), next| {
+            match f(last, next) {
+                Ok(joined) => (accum, joined),
+                Err((last_, next_)) => (g(accum, last_), next_),
+            }
+        });
+        g(accum, last)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, hi) = size_hint::add_scalar(self.iter.size_hint(),
+                                              self.last.is_some() as usize);
+        ((low > 0) as usize, hi)
+    }
+}
+
+/// An iterator adaptor that may join together adjacent elements.
+///
+/// See [*.coalesce()*](trait.Itertools.html#method.coalesce) for more information.
+pub struct Coalesce<I, F>
+    where I: Iterator
+{
+    iter: CoalesceCore<I>,
+    f: F,
+}
+
+impl<I: Clone, F: Clone> Clone for Coalesce<I, F>
+    where I: Iterator,
+          I::Item: Clone
+{
+    fn clone(&self) -> Self {
+        clone_fields!(Coalesce, self, iter, f)
+    }
+}
+
+impl<I, F> Coalesce<I, F>
+    where I: Iterator
+{
+    /// Create a new `Coalesce`.
+    pub fn new(mut iter: I, f: F) -> Self {
+        Coalesce {
+            iter: CoalesceCore {
+                last: iter.next(),
+                iter: iter,
+            },
+            f: f,
+        }
+    }
+}
+
+impl<I, F> Iterator for Coalesce<I, F>
+    where I: Iterator,
+          F: FnMut(I::Item, I::Item) -> Result<I::Item, (I::Item, I::Item)>
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.next_with(&mut self.f)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn fold<B, G>(self, init: B, g: G) -> B
+        where G: FnMut(B, Self::Item) -> B
+    {
+        self.iter.fold_with(init, self.f, g)
+    }
+}
+
+/// An iterator adaptor that removes repeated duplicates.
+///
+/// See [*.dedup()*](trait.Itertools.html#method.dedup) for more information.
+pub struct Dedup<I>
+    where I: Iterator
+{
+    iter: CoalesceCore<I>,
+}
+
+impl<I: Clone> Clone for Dedup<I>
+    where I: Iterator,
+          I::Item: Clone
+{
+    fn clone(&self) -> Self {
+        clone_fields!(Dedup, self, iter)
+    }
+}
+
+impl<I> Dedup<I>
+    where I: Iterator
+{
+    /// Create a new `Dedup`.
+    pub fn new(mut iter: I) -> Self {
+        Dedup {
+            iter: CoalesceCore {
+                last: iter.next(),
+                iter: iter,
+            },
+        }
+    }
+}
+
+impl<I> Iterator for Dedup<I>
+    where I: Iterator,
+          I::Item: PartialEq
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.next_with(|x, y| {
+            if x == y { Ok(x) } else { Err((x, y)) }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn fold<B, G>(self, init: B, g: G) -> B
+        where G: FnMut(B, Self::Item) -> B
+    {
+        self.iter.fold_with(init, |x, y| {
+            if x == y { Ok(x) } else { Err((x, y)) }
+        }, g)
+    }
+}
+
+/// An iterator adaptor that removes repeated duplicates, comparing
+/// elements by a key.
+///
+/// See [*.dedup_by_key()*](trait.Itertools.html#method.dedup_by_key) for more information.
+pub struct DedupByKey<I, F>
+    where I: Iterator
+{
+    iter: CoalesceCore<I>,
+    key: F,
+}
+
+impl<I: Clone, F: Clone> Clone for DedupByKey<I, F>
+    where I: Iterator,
+          I::Item: Clone
+{
+    fn clone(&self) -> Self {
+        clone_fields!(DedupByKey, self, iter, key)
+    }
+}
+
+impl<I, F> DedupByKey<I, F>
+    where I: Iterator
+{
+    /// Create a new `DedupByKey`.
+    pub fn new(mut iter: I, key: F) -> Self {
+        DedupByKey {
+            iter: CoalesceCore {
+                last: iter.next(),
+                iter: iter,
+            },
+            key: key,
+        }
+    }
+}
+
+impl<I, K, F> Iterator for DedupByKey<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let key = &mut self.key;
+        self.iter.next_with(|x, y| {
+            if key(&x) == key(&y) { Ok(x) } else { Err((x, y)) }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn fold<B, G>(self, init: B, g: G) -> B
+        where G: FnMut(B, Self::Item) -> B
+    {
+        let mut key = self.key;
+        self.iter.fold_with(init, |x, y| {
+            if key(&x) == key(&y) { Ok(x) } else { Err((x, y)) }
+        }, g)
+    }
+}
+
+/// An iterator adaptor that yields each run of equal consecutive elements
+/// as an element and the length of its run.
+///
+/// See [*.run_length_encode()*](trait.Itertools.html#method.run_length_encode)
+/// for more information.
+pub struct RunLengthEncode<I>
+    where I: Iterator
+{
+    iter: I,
+    last: Option<I::Item>,
+}
+
+impl<I: Clone> Clone for RunLengthEncode<I>
+    where I: Iterator,
+          I::Item: Clone
+{
+    fn clone(&self) -> Self {
+        clone_fields!(RunLengthEncode, self, iter, last)
+    }
+}
+
+impl<I> RunLengthEncode<I>
+    where I: Iterator
+{
+    /// Create a new `RunLengthEncode`.
+    pub fn new(mut iter: I) -> Self {
+        RunLengthEncode {
+            last: iter.next(),
+            iter: iter,
+        }
+    }
+}
+
+impl<I> Iterator for RunLengthEncode<I>
+    where I: Iterator,
+          I::Item: PartialEq
+{
+    type Item = (I::Item, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // this fuses the iterator
+        let last = match self.last.take() {
+            None => return None,
+            Some(x) => x,
+        };
+        let mut count = 1;
+        for next in &mut self.iter {
+            if next == last {
+                count += 1;
+            } else {
+                self.last = Some(next);
+                break;
+            }
+        }
+        Some((last, count))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, hi) = size_hint::add_scalar(self.iter.size_hint(),
+                                              self.last.is_some() as usize);
+        ((low > 0) as usize, hi)
+    }
+}
+
+impl<I> FusedIterator for RunLengthEncode<I>
+    where I: Iterator,
+          I::Item: PartialEq
+{}
+
+/// An iterator adaptor that expands `(element, count)` pairs into `count`
+/// copies of each element.
+///
+/// See [*.run_length_decode()*](trait.Itertools.html#method.run_length_decode)
+/// for more information.
+#[derive(Clone)]
+pub struct RunLengthDecode<I, T> {
+    iter: I,
+    run: Option<(T, usize)>,
+}
+
+impl<I, T> RunLengthDecode<I, T>
+    where I: Iterator<Item = (T, usize)>
+{
+    /// Create a new `RunLengthDecode`.
+    pub fn new(iter: I) -> Self {
+        RunLengthDecode {
+            iter: iter,
+            run: None,
+        }
+    }
+}
+
+impl<I, T> Iterator for RunLengthDecode<I, T>
+    where I: Iterator<Item = (T, usize)>,
+          T: Clone
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            match self.run.take() {
+                Some((x, n)) => {
+                    if n > 1 {
+                        self.run = Some((x.clone(), n - 1));
+                        return Some(x);
+                    } else if n == 1 {
+                        return Some(x);
+                    }
+                }
+                None => match self.iter.next() {
+                    None => return None,
+                    run => self.run = run,
+                },
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.run.as_ref().map_or(0, |run| run.1);
+        match self.iter.size_hint() {
+            (_, Some(0)) => (n, Some(n)),
+            // the remaining runs may have any length
+            _ => (n, None),
+        }
+    }
+}
+
+/// An iterator adaptor that collapses each run of a sentinel value into
+/// a single occurrence.
+///
+/// See [*.collapse_runs()*](trait.Itertools.html#method.collapse_runs) for more information.
+pub struct CollapseRuns<I>
+    where I: Iterator
+{
+    iter: CoalesceCore<I>,
+    value: I::Item,
+}
+
+impl<I: Clone> Clone for CollapseRuns<I>
+    where I: Iterator,
+          I::Item: Clone
+{
+    fn clone(&self) -> Self {
+        clone_fields!(CollapseRuns, self, iter, value)
+    }
+}
+
+impl<I> CollapseRuns<I>
+    where I: Iterator
+{
+    /// Create a new `CollapseRuns`.
+    pub fn new(mut iter: I, value: I::Item) -> Self {
+        CollapseRuns {
+            iter: CoalesceCore {
+                last: iter.next(),
+                iter: iter,
+            },
+            value: value,
+        }
+    }
+}
+
+impl<I> Iterator for CollapseRuns<I>
+    where I: Iterator,
+          I::Item: PartialEq
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let value = &self.value;
+        self.iter.next_with(|x, y| {
+            if x == *value && y == *value { Ok(x) } else { Err((x, y)) }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An iterator adaptor that collapses each run of elements matching a
+/// predicate into its first element.
+///
+/// See [*.collapse_runs_by()*](trait.Itertools.html#method.collapse_runs_by)
+/// for more information.
+pub struct CollapseRunsBy<I, F>
+    where I: Iterator
+{
+    iter: CoalesceCore<I>,
+    f: F,
+}
+
+impl<I: Clone, F: Clone> Clone for CollapseRunsBy<I, F>
+    where I: Iterator,
+          I::Item: Clone
+{
+    fn clone(&self) -> Self {
+        clone_fields!(CollapseRunsBy, self, iter, f)
+    }
+}
+
+impl<I, F> CollapseRunsBy<I, F>
+    where I: Iterator
+{
+    /// Create a new `CollapseRunsBy`.
+    pub fn new(mut iter: I, f: F) -> Self {
+        CollapseRunsBy {
+            iter: CoalesceCore {
+                last: iter.next(),
+                iter: iter,
+            },
+            f: f,
+        }
+    }
+}
+
+impl<I, F> Iterator for CollapseRunsBy<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> bool
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let f = &mut self.f;
+        self.iter.next_with(|x, y| {
+            if f(&x) && f(&y) { Ok(x) } else { Err((x, y)) }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An iterator adaptor that glues together adjacent contiguous slices.
+///
+/// See [*.mend_slices()*](trait.Itertools.html#method.mend_slices) for more information.
+pub struct MendSlices<I>
+    where I: Iterator
+{
+    iter: CoalesceCore<I>,
+}
+
+impl<I: Clone> Clone for MendSlices<I>
+    where I: Iterator,
+          I::Item: Clone
+{
+    fn clone(&self) -> Self {
+        clone_fields!(MendSlices, self, iter)
+    }
+}
+
+impl<I> MendSlices<I>
+    where I: Iterator
+{
+    /// Create a new `MendSlices`.
+    pub fn new(mut iter: I) -> Self {
+        MendSlices {
+            iter: CoalesceCore {
+                last: iter.next(),
+                iter: iter,
+            },
+        }
+    }
+}
+
+impl<I> Iterator for MendSlices<I>
+    where I: Iterator,
+          I::Item: MendSlice
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.next_with(MendSlice::mend)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An iterator adaptor that borrows from a `Clone`-able iterator
+/// to only pick off elements while the predicate returns `true`.
+///
+/// See [*.take_while_ref()*](trait.Itertools.html#method.take_while_ref) for more information.
+pub struct TakeWhileRef<'a, I: 'a, F> {
+    iter: &'a mut I,
+    f: F,
+}
+
+impl<'a, I, F> TakeWhileRef<'a, I, F>
+    where I: Iterator + Clone
+{
+    /// Create a new `TakeWhileRef` from a reference to clonable iterator.
+    pub fn new(iter: &'a mut I, f: F) -> Self {
+        TakeWhileRef { iter: iter, f: f }
+    }
+}
+
+impl<'a, I, F> Iterator for TakeWhileRef<'a, I, F>
+    where I: Iterator + Clone,
+          F: FnMut(&I::Item) -> bool
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let old = self.iter.clone();
+        match self.iter.next() {
+            None => None,
+            Some(elt) => {
+                if (self.f)(&elt) {
+                    Some(elt)
+                } else {
+                    *self.iter = old;
+                    None
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, hi) = self.iter.size_hint();
+        (0, hi)
+    }
+}
+
+/// An iterator adaptor that filters `Option<A>` iterator elements
+/// and produces `A`. Stops on the first `None` encountered.
+///
+/// This iterator is *fused*.
+///
+/// See [*.while_some()*](trait.Itertools.html#method.while_some) for more information.
+#[derive(Clone)]
+pub struct WhileSome<I> {
+    iter: I,
+    done: bool,
+}
+
+impl<I> WhileSome<I> {
+    /// Create a new `WhileSome<I>`.
+    pub fn new(iter: I) -> Self {
+        WhileSome { iter: iter, done: false }
+    }
+}
+
+impl<I, A> Iterator for WhileSome<I>
+    where I: Iterator<Item = Option<A>>
+{
+    type Item = A;
+
+    fn next(&mut self) -> Option<A> {
+        if self.done {
+            return None;
+        }
+        match self.iter.next() {
+            None | Some(None) => {
+                self.done = true;
+                None
+            }
+            Some(elt) => elt,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let sh = self.iter.size_hint();
+            (0, sh.1)
+        }
+    }
+}
+
+impl<I, A> FusedIterator for WhileSome<I>
+    where I: Iterator<Item = Option<A>>
+{}
+
+/// The result of the closure passed to
+/// [*.filter_map_while()*](trait.Itertools.html#method.filter_map_while).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WhileStep<T> {
+    /// Produce the value and continue.
+    Yield(T),
+    /// Produce nothing for this element and continue.
+    Skip,
+    /// Stop the iteration.
+    Stop,
+}
+
+/// An iterator adaptor that maps and filters elements with a closure
+/// until the closure asks it to stop.
+///
+/// This iterator is *fused*.
+///
+/// See [*.filter_map_while()*](trait.Itertools.html#method.filter_map_while) for more information.
+#[derive(Clone)]
+pub struct FilterMapWhile<I, F> {
+    iter: I,
+    f: F,
+    done: bool,
+}
+
+/// Create a new `FilterMapWhile` iterator.
+pub fn filter_map_while<I, F>(iter: I, f: F) -> FilterMapWhile<I, F> {
+    FilterMapWhile {
+        iter: iter,
+        f: f,
+        done: false,
+    }
+}
+
+impl<I, F, B> Iterator for FilterMapWhile<I, F>
+    where I: Iterator,
+          F: FnMut(I::Item) -> WhileStep<B>
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        if self.done {
+            return None;
+        }
+        while let Some(elt) = self.iter.next() {
+            match (self.f)(elt) {
+                WhileStep::Yield(x) => return Some(x),
+                WhileStep::Skip => {}
+                WhileStep::Stop => break,
+            }
+        }
+        self.done = true;
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<I, F, B> FusedIterator for FilterMapWhile<I, F>
+    where I: Iterator,
+          F: FnMut(I::Item) -> WhileStep<B>
+{}
+
+/// An iterator to iterate through all the combinations of pairs in a `Clone`-able iterator.
+///
+/// See [*.combinations()*](trait.Itertools.html#method.combinations) for more information.
+#[derive(Clone)]
+#[cfg(feature = "use_std")]
+pub struct Combinations<I: Iterator> {
+    iter: I,
+    next_iter: I,
+    val: Option<I::Item>,
+}
+#[cfg(feature = "use_std")]
+impl<I> Combinations<I>
+    where I: Iterator + Clone
+{
+    /// Create a new `Combinations` from a clonable iterator.
+    pub fn new(iter: I) -> Combinations<I> {
+        Combinations {
+            next_iter: iter.clone(),
+            iter: iter,
+            val: None,
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<I> Iterator for Combinations<I>
+    where I: Iterator + Clone,
+          I::Item: Clone
+{
+    type Item = (I::Item, I::Item);
+    fn next(&mut self) -> Option<Self::Item> {
+        // not having a value means we iterate once more through the first iterator
+        if self.val.is_none() {
+            self.val = self.iter.next();
+            self.next_iter = self.iter.clone();
+        }
+
+        // if its still none, we're out of values
+        let elt = match self.val {
+            Some(ref x) => x.clone(),
+            None => return None,
+        };
+
+        match self.next_iter.next() {
+            Some(ref x) => {
+                return Some((elt, x.clone()));
+            },
+            None => {
+                self.val = None;
+            }
+        }
+        // try again if we ran out of values in the second iterator
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut extra = (0, Some(0));
+        if self.val.is_some() {
+            extra = self.next_iter.size_hint();
+        }
+        size_hint::add(size_hint::pairs(self.iter.size_hint()), extra)
+    }
+}
+
+#[cfg(feature = "use_std")]
+pub struct LazyBuffer<I: Iterator> {
+    it: I,
+    done: bool,
+    buffer: Vec<I::Item>,
+}
+
+#[cfg(feature = "use_std")]
+impl<I> LazyBuffer<I>
+    where I: Iterator
+{
+    pub fn new(it: I) -> LazyBuffer<I> {
+        let mut it = it;
+        let mut buffer = Vec::new();
+        let done;
+        if let Some(first) = it.next() {
+            buffer.push(first);
+            done = false;
+        } else {
+            done = true;
+        }
+        LazyBuffer {
+            it: it,
+            done: done,
+            buffer: buffer,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    pub fn get_next(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+        let next_item = self.it.next();
+        match next_item {
+            Some(x) => {
+                self.buffer.push(x);
+                true
+            }
+            None => {
+                self.done = true;
+                false
+            }
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<I> Index<usize> for LazyBuffer<I>
+    where I: Iterator,
+          I::Item: Sized
+{
+    type Output = I::Item;
+
+    fn index<'b>(&'b self, _index: usize) -> &'b I::Item {
+        self.buffer.index(_index)
+    }
+}
+
+/// Advance `indices` to the next combination of elements of `pool`,
+/// pulling more elements into it as needed.
+///
+/// Return `false` if `indices` already was the last combination.
+#[cfg(feature = "use_std")]
+pub fn next_combination_indices<I>(indices: &mut [usize], pool: &mut LazyBuffer<I>) -> bool
+    where I: Iterator
+{
+    let n = indices.len();
+    if n == 0 {
+        return false;
+    }
+    let mut pool_len = pool.len();
+
+    // Scan from the end, looking for an index to increment
+    let mut i: usize = n - 1;
+
+    // Check if we need to consume more from the iterator
+    if indices[i] == pool_len - 1 && !pool.is_done() {
+        if pool.get_next() {
+            pool_len += 1;
+        }
+    }
+
+    while indices[i] == i + pool_len - n {
+        if i > 0 {
+            i -= 1;
+        } else {
+            // Reached the last combination
+            return false;
+        }
+    }
+
+    // Increment index, and reset the ones to its right
+    indices[i] += 1;
+    let mut j = i + 1;
+    while j < n {
+        indices[j] = indices[j - 1] + 1;
+        j += 1;
+    }
+    true
+}
+
+/// An iterator to iterate through all the `n`-length combinations in an iterator.
+///
+/// See [*.combinations_n()*](trait.Itertools.html#method.combinations_n) for more information.
+#[cfg(feature = "use_std")]
+pub struct CombinationsN<I: Iterator> {
+    n: usize,
+    indices: Vec<usize>,
+    pool: LazyBuffer<I>,
+    first: bool,
+}
+#[cfg(feature = "use_std")]
+impl<I> CombinationsN<I>
+    where I: Iterator
+{
+    /// Create a new `CombinationsN` from a clonable iterator.
+    pub fn new(iter: I, n: usize) -> CombinationsN<I> {
+        let mut indices: Vec<usize> = Vec::with_capacity(n);
+        for i in 0..n {
+            indices.push(i);
+        }
+        let mut pool: LazyBuffer<I> = LazyBuffer::new(iter);
+
+        for _ in 0..n {
+            if !pool.get_next() {
+                break;
+            }
+        }
+
+        CombinationsN {
+            n: n,
+            indices: indices,
+            pool: pool,
+            first: true,
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<I> Iterator for CombinationsN<I>
+    where I: Iterator,
+          I::Item: Clone
+{
+    type Item = Vec<I::Item>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let pool_len = self.pool.len();
+        if self.pool.is_done() {
+            if pool_len == 0 || self.n > pool_len {
+                return None;
+            }
+        }
+
+        if self.first {
+            self.first = false;
+        } else if !next_combination_indices(&mut self.indices, &mut self.pool) {
+            return None;
+        }
+
+        // Create result vector based on the indices
+        let mut result = Vec::with_capacity(self.n);
+        for i in self.indices.iter() {
+            result.push(self.pool[*i].clone());
+        }
+        Some(result)
+    }
+}
+
+/// An iterator adapter to filter out duplicate elements.
+///
+/// See [*.unique_by()*](trait.Itertools.html#method.unique) for more information.
+#[derive(Clone)]
+#[cfg(feature = "use_std")]
+pub struct UniqueBy<I: Iterator, V, F> {
+    iter: I,
+    used: HashSet<V>,
+    f: F,
+}
+
+#[cfg(feature = "use_std")]
+impl<I: Iterator, V, F> UniqueBy<I, V, F>
+    where V: Eq + Hash,
+          F: FnMut(&I::Item) -> V
+{
+    /// Create a new `UniqueBy` iterator.
+    pub fn new(iter: I, f: F) -> UniqueBy<I, V, F> {
+        UniqueBy {
+            iter: iter,
+            used: HashSet::new(),
+            f: f,
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<I, V, F> Iterator for UniqueBy<I, V, F>
+    where I: Iterator,
+          V: Eq + Hash,
+          F: FnMut(&I::Item) -> V
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        loop {
+            match self.iter.next() {
+                None => return None,
+                Some(v) => {
+                    let key = (self.f)(&v);
+                    if self.used.insert(key) {
+                        return Some(v);
+                    }
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, hi) = self.iter.size_hint();
+        ((low > 0 && self.used.is_empty()) as usize, hi)
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<I> Iterator for Unique<I>
+    where I: Iterator,
+          I::Item: Eq + Hash + Clone
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        loop {
+            match self.iter.iter.next() {
+                None => return None,
+                Some(v) => {
+                    if !self.iter.used.contains(&v) {
+                        // FIXME: Avoid this double lookup when the entry api allows
+                        self.iter.used.insert(v.clone());
+                        return Some(v);
+                    }
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, hi) = self.iter.iter.size_hint();
+        ((low > 0 && self.iter.used.is_empty()) as usize, hi)
+    }
+}
+
+/// An iterator adapter to filter out duplicate elements.
+///
+/// See [*.unique()*](trait.Itertools.html#method.unique) for more information.
+#[derive(Clone)]
+#[cfg(feature = "use_std")]
+pub struct Unique<I: Iterator> {
+    iter: UniqueBy<I, I::Item, ()>,
+}
+
+#[cfg(feature = "use_std")]
+pub fn unique<I>(iter: I) -> Unique<I>
+    where I: Iterator,
+          I::Item: Eq + Hash,
+{
+    Unique {
+        iter: UniqueBy {
+            iter: iter,
+            used: HashSet::new(),
+            f: (),
+        }
+    }
+}
+
+/// An iterator adapter to filter out elements that are seen only once.
+///
+/// See [*.duplicates_by()*](trait.Itertools.html#method.duplicates_by) for more information.
+#[derive(Clone)]
+#[cfg(feature = "use_std")]
+pub struct DuplicatesBy<I: Iterator, V, F> {
+    iter: I,
+    /// Keys seen so far, mapped to whether they were already produced
+    used: HashMap<V, bool>,
+    f: F,
+}
+
+#[cfg(feature = "use_std")]
+impl<I: Iterator, V, F> DuplicatesBy<I, V, F>
+    where V: Eq + Hash,
+          F: FnMut(&I::Item) -> V
+{
+    /// Create a new `DuplicatesBy` iterator.
+    pub fn new(iter: I, f: F) -> DuplicatesBy<I, V, F> {
+        DuplicatesBy {
+            iter: iter,
+            used: HashMap::new(),
+            f: f,
+        }
+    }
+}
+
+/// Record `key` as seen; return `true` if this is its second occurrence.
+#[cfg(feature = "use_std")]
+fn duplicate_seen<V: Eq + Hash>(used: &mut HashMap<V, bool>, key: V) -> bool {
+    match used.entry(key) {
+        Entry::Occupied(mut e) => !e.insert(true),
+        Entry::Vacant(e) => {
+            e.insert(false);
+            false
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<I, V, F> Iterator for DuplicatesBy<I, V, F>
+    where I: Iterator,
+          V: Eq + Hash,
+          F: FnMut(&I::Item) -> V
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        loop {
+            match self.iter.next() {
+                None => return None,
+                Some(v) => {
+                    let key = (self.f)(&v);
+                    if duplicate_seen(&mut self.used, key) {
+                        return Some(v);
+                    }
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, hi) = self.iter.size_hint();
+        (0, hi)
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<I> Iterator for Duplicates<I>
+    where I: Iterator,
+          I::Item: Eq + Hash + Clone
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        loop {
+            match self.iter.iter.next() {
+                None => return None,
+                Some(v) => {
+                    if duplicate_seen(&mut self.iter.used, v.clone()) {
+                        return Some(v);
+                    }
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, hi) = self.iter.iter.size_hint();
+        (0, hi)
+    }
+}
+
+/// An iterator adapter to filter out elements that are seen only once.
+///
+/// See [*.duplicates()*](trait.Itertools.html#method.duplicates) for more information.
+#[derive(Clone)]
+#[cfg(feature = "use_std")]
+pub struct Duplicates<I: Iterator> {
+    iter: DuplicatesBy<I, I::Item, ()>,
+}
+
+#[cfg(feature = "use_std")]
+pub fn duplicates<I>(iter: I) -> Duplicates<I>
+    where I: Iterator,
+          I::Item: Eq + Hash,
+{
+    Duplicates {
+        iter: DuplicatesBy {
+            iter: iter,
+            used: HashMap::new(),
+            f: (),
+        }
+    }
+}
+
+/// An iterator adapter to simply flatten a structure.
+///
+/// See [*.flatten()*](trait.Itertools.html#method.flatten) for more information.
+pub struct Flatten<I>
+    where I: Iterator,
+          I::Item: IntoIterator
+{
+    iter: FlatMap<I, I::Item, fn(I::Item) -> I::Item>,
+}
+
+impl<I> Flatten<I>
+    where I: Iterator,
+          I::Item: IntoIterator
+{
+    /// Create a new `Flatten` iterator.
+    pub fn new(iter: I) -> Flatten<I> {
+        fn identity<T>(t: T) -> T {
+            t
+        }
+        Flatten { iter: iter.flat_map(identity) }
+    }
+}
+
+impl<I> Iterator for Flatten<I>
+    where I: Iterator,
+          I::Item: IntoIterator
+{
+    type Item = <I::Item as IntoIterator>::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<I> DoubleEndedIterator for Flatten<I>
+    where I: DoubleEndedIterator,
+          I::Item: DoubleEndedIterator
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<I> Clone for Flatten<I> where
+    I: Iterator + Clone,
+    I::Item: IntoIterator + Clone,
+    <<I as Iterator>::Item as IntoIterator>::IntoIter: Clone
+{
+    fn clone(&self) -> Self {
+        Flatten {
+            iter: self.iter.clone()
+        }
+    }
+}
+
+/// An iterator adapter to get the positions of each element that matches a predicate.
+///
+/// See [*.positions()*](trait.Itertools.html#method.positions) for more information.
+#[derive(Clone)]
+pub struct Positions<I, F> {
+    iter: I,
+    f: F,
+    count: usize,
+}
+
+impl<I, F> Positions<I, F>
+    where I: Iterator,
+          F: FnMut(I::Item) -> bool
+{
+    /// Create a new `Positions` iterator.
+    pub fn new(iter: I, f: F) -> Self {
+        Positions {
+            iter: iter,
+            f: f,
+            count: 0,
+        }
+    }
+}
+
+impl<I, F> Iterator for Positions<I, F>
+    where I: Iterator,
+          F: FnMut(I::Item) -> bool
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while let Some(v) = self.iter.next() {
+            let i = self.count;
+            self.count = i + 1;
+            if (self.f)(v) {
+                return Some(i);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, F> DoubleEndedIterator for Positions<I, F>
+    where I: DoubleEndedIterator + ExactSizeIterator,
+          F: FnMut(I::Item) -> bool
+{
+    fn next_back(&mut self) -> Option<usize> {
+        while let Some(v) = self.iter.next_back() {
+            if (self.f)(v) {
+                return Some(self.count + self.iter.len());
+            }
+        }
+        None
+    }
+}
+
+/// An iterator adapter to apply a mutating function to each element before
+/// yielding it.
+///
+/// See [*.update()*](trait.Itertools.html#method.update) for more information.
+#[derive(Clone)]
+pub struct Update<I, F> {
+    iter: I,
+    f: F,
+}
+
+/// Create a new `Update` iterator.
+pub fn update<I, F>(iter: I, f: F) -> Update<I, F>
+    where I: Iterator,
+          F: FnMut(&mut I::Item),
+{
+    Update {
+        iter: iter,
+        f: f,
+    }
+}
+
+impl<I, F> Iterator for Update<I, F>
+    where I: Iterator,
+          F: FnMut(&mut I::Item),
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        match self.iter.next() {
+            Some(mut v) => {
+                (self.f)(&mut v);
+                Some(v)
+            }
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> DoubleEndedIterator for Update<I, F>
+    where I: DoubleEndedIterator,
+          F: FnMut(&mut I::Item),
+{
+    fn next_back(&mut self) -> Option<I::Item> {
+        match self.iter.next_back() {
+            Some(mut v) => {
+                (self.f)(&mut v);
+                Some(v)
+            }
+            None => None,
+        }
+    }
+}
+
+impl<I, F> ExactSizeIterator for Update<I, F>
+    where I: ExactSizeIterator,
+          F: FnMut(&mut I::Item),
+{}
+
+/// An iterator adapter to convert each element into `U` with `Into`.
+///
+/// See [*.map_into()*](trait.Itertools.html#method.map_into) for more information.
+pub struct MapInto<I, U> {
+    iter: I,
+    _target: PhantomData<U>,
+}
+
+impl<I: Clone, U> Clone for MapInto<I, U> {
+    fn clone(&self) -> Self {
+        map_into(self.iter.clone())
+    }
+}
+
+/// Create a new `MapInto` iterator.
+pub fn map_into<I, U>(iter: I) -> MapInto<I, U> {
+    MapInto {
+        iter: iter,
+        _target: PhantomData,
+    }
+}
+
+impl<I, U> Iterator for MapInto<I, U>
+    where I: Iterator,
+          I::Item: Into<U>,
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<U> {
+        self.iter.next().map(Into::into)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, U> DoubleEndedIterator for MapInto<I, U>
+    where I: DoubleEndedIterator,
+          I::Item: Into<U>,
+{
+    fn next_back(&mut self) -> Option<U> {
+        self.iter.next_back().map(Into::into)
+    }
+}
+
+impl<I, U> ExactSizeIterator for MapInto<I, U>
+    where I: ExactSizeIterator,
+          I::Item: Into<U>,
+{}
+
+/// An iterator adapter to apply a transformation within a nested `Result::Ok`.
+///
+/// See [*.map_ok()*](trait.Itertools.html#method.map_ok) for more information.
+#[derive(Clone)]
+pub struct MapOk<I, F> {
+    iter: I,
+    f: F,
+}
+
+/// Create a new `MapOk` iterator.
+pub fn map_ok<I, F, T, U, E>(iter: I, f: F) -> MapOk<I, F>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(T) -> U,
+{
+    MapOk {
+        iter: iter,
+        f: f,
+    }
 }
 
-impl<I> Iterator for MendSlices<I>
-    where I: Iterator,
-          I::Item: MendSlice
+impl<I, F, T, U, E> Iterator for MapOk<I, F>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(T) -> U,
 {
-    type Item = I::Item;
+    type Item = Result<U, E>;
 
-    fn next(&mut self) -> Option<I::Item> {
-        self.iter.next_with(MendSlice::mend)
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(Ok(v)) => Some(Ok((self.f)(v))),
+            Some(Err(e)) => Some(Err(e)),
+            None => None,
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -1002,451 +3161,760 @@ impl<I> Iterator for MendSlices<I>
     }
 }
 
-/// An iterator adaptor that borrows from a `Clone`-able iterator
-/// to only pick off elements while the predicate returns `true`.
+impl<I, F, T, U, E> DoubleEndedIterator for MapOk<I, F>
+    where I: DoubleEndedIterator<Item = Result<T, E>>,
+          F: FnMut(T) -> U,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.iter.next_back() {
+            Some(Ok(v)) => Some(Ok((self.f)(v))),
+            Some(Err(e)) => Some(Err(e)),
+            None => None,
+        }
+    }
+}
+
+impl<I, F, T, U, E> ExactSizeIterator for MapOk<I, F>
+    where I: ExactSizeIterator<Item = Result<T, E>>,
+          F: FnMut(T) -> U,
+{}
+
+/// An iterator adapter to inspect values within a nested `Result::Ok`.
 ///
-/// See [*.take_while_ref()*](trait.Itertools.html#method.take_while_ref) for more information.
-pub struct TakeWhileRef<'a, I: 'a, F> {
-    iter: &'a mut I,
+/// See [*.inspect_ok()*](trait.Itertools.html#method.inspect_ok) for more information.
+#[derive(Clone)]
+pub struct InspectOk<I, F> {
+    iter: I,
     f: F,
 }
 
-impl<'a, I, F> TakeWhileRef<'a, I, F>
-    where I: Iterator + Clone
+/// Create a new `InspectOk` iterator.
+pub fn inspect_ok<I, F, T, E>(iter: I, f: F) -> InspectOk<I, F>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(&T),
 {
-    /// Create a new `TakeWhileRef` from a reference to clonable iterator.
-    pub fn new(iter: &'a mut I, f: F) -> Self {
-        TakeWhileRef { iter: iter, f: f }
+    InspectOk {
+        iter: iter,
+        f: f,
     }
 }
 
-impl<'a, I, F> Iterator for TakeWhileRef<'a, I, F>
-    where I: Iterator + Clone,
-          F: FnMut(&I::Item) -> bool
+impl<I, F, T, E> Iterator for InspectOk<I, F>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(&T),
 {
-    type Item = I::Item;
+    type Item = Result<T, E>;
 
-    fn next(&mut self) -> Option<I::Item> {
-        let old = self.iter.clone();
-        match self.iter.next() {
-            None => None,
-            Some(elt) => {
-                if (self.f)(&elt) {
-                    Some(elt)
-                } else {
-                    *self.iter = old;
-                    None
-                }
-            }
+    fn next(&mut self) -> Option<Self::Item> {
+        let elt = self.iter.next();
+        if let Some(Ok(ref v)) = elt {
+            (self.f)(v);
         }
+        elt
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let (_, hi) = self.iter.size_hint();
-        (0, hi)
+        self.iter.size_hint()
     }
 }
 
-/// An iterator adaptor that filters `Option<A>` iterator elements
-/// and produces `A`. Stops on the first `None` encountered.
+impl<I, F, T, E> DoubleEndedIterator for InspectOk<I, F>
+    where I: DoubleEndedIterator<Item = Result<T, E>>,
+          F: FnMut(&T),
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let elt = self.iter.next_back();
+        if let Some(Ok(ref v)) = elt {
+            (self.f)(v);
+        }
+        elt
+    }
+}
+
+impl<I, F, T, E> ExactSizeIterator for InspectOk<I, F>
+    where I: ExactSizeIterator<Item = Result<T, E>>,
+          F: FnMut(&T),
+{}
+
+/// An iterator adapter to filter values within a nested `Result::Ok`.
 ///
-/// See [*.while_some()*](trait.Itertools.html#method.while_some) for more information.
+/// See [*.filter_ok()*](trait.Itertools.html#method.filter_ok) for more information.
 #[derive(Clone)]
-pub struct WhileSome<I> {
+pub struct FilterOk<I, F> {
     iter: I,
+    f: F,
 }
 
-impl<I> WhileSome<I> {
-    /// Create a new `WhileSome<I>`.
-    pub fn new(iter: I) -> Self {
-        WhileSome { iter: iter }
+/// Create a new `FilterOk` iterator.
+pub fn filter_ok<I, F, T, E>(iter: I, f: F) -> FilterOk<I, F>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(&T) -> bool,
+{
+    FilterOk {
+        iter: iter,
+        f: f,
     }
 }
 
-impl<I, A> Iterator for WhileSome<I>
-    where I: Iterator<Item = Option<A>>
+impl<I, F, T, E> Iterator for FilterOk<I, F>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(&T) -> bool,
 {
-    type Item = A;
+    type Item = Result<T, E>;
 
-    fn next(&mut self) -> Option<A> {
-        match self.iter.next() {
-            None | Some(None) => None,
-            Some(elt) => elt,
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next() {
+                Some(Ok(v)) => {
+                    if (self.f)(&v) {
+                        return Some(Ok(v));
+                    }
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => return None,
+            }
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let sh = self.iter.size_hint();
-        (0, sh.1)
+        (0, self.iter.size_hint().1)
     }
 }
 
-/// An iterator to iterate through all the combinations of pairs in a `Clone`-able iterator.
+impl<I, F, T, E> DoubleEndedIterator for FilterOk<I, F>
+    where I: DoubleEndedIterator<Item = Result<T, E>>,
+          F: FnMut(&T) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next_back() {
+                Some(Ok(v)) => {
+                    if (self.f)(&v) {
+                        return Some(Ok(v));
+                    }
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => return None,
+            }
+        }
+    }
+}
+
+/// An iterator adapter to filter and apply a transformation on values
+/// within a nested `Result::Ok`.
 ///
-/// See [*.combinations()*](trait.Itertools.html#method.combinations) for more information.
+/// See [*.filter_map_ok()*](trait.Itertools.html#method.filter_map_ok) for more information.
 #[derive(Clone)]
-pub struct Combinations<I: Iterator> {
+pub struct FilterMapOk<I, F> {
     iter: I,
-    next_iter: I,
-    val: Option<I::Item>,
+    f: F,
 }
-impl<I> Combinations<I>
-    where I: Iterator + Clone
+
+/// Create a new `FilterMapOk` iterator.
+pub fn filter_map_ok<I, F, T, U, E>(iter: I, f: F) -> FilterMapOk<I, F>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(T) -> Option<U>,
 {
-    /// Create a new `Combinations` from a clonable iterator.
-    pub fn new(iter: I) -> Combinations<I> {
-        Combinations {
-            next_iter: iter.clone(),
-            iter: iter,
-            val: None,
-        }
+    FilterMapOk {
+        iter: iter,
+        f: f,
     }
 }
 
-impl<I> Iterator for Combinations<I>
-    where I: Iterator + Clone,
-          I::Item: Clone
+impl<I, F, T, U, E> Iterator for FilterMapOk<I, F>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(T) -> Option<U>,
 {
-    type Item = (I::Item, I::Item);
-    fn next(&mut self) -> Option<Self::Item> {
-        // not having a value means we iterate once more through the first iterator
-        if self.val.is_none() {
-            self.val = self.iter.next();
-            self.next_iter = self.iter.clone();
-        }
-
-        // if its still none, we're out of values
-        let elt = match self.val {
-            Some(ref x) => x.clone(),
-            None => return None,
-        };
+    type Item = Result<U, E>;
 
-        match self.next_iter.next() {
-            Some(ref x) => {
-                return Some((elt, x.clone()));
-            },
-            None => {
-                self.val = None;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next() {
+                Some(Ok(v)) => {
+                    if let Some(v) = (self.f)(v) {
+                        return Some(Ok(v));
+                    }
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => return None,
             }
         }
-        // try again if we ran out of values in the second iterator
-        self.next()
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let sh = self.iter.size_hint();
-        let (lo, hi) = size_hint::mul(sh, size_hint::sub_scalar(sh, 1));
-        let mut extra = (0, Some(0));
-        if self.val.is_some() {
-            extra = self.next_iter.size_hint();
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, F, T, U, E> DoubleEndedIterator for FilterMapOk<I, F>
+    where I: DoubleEndedIterator<Item = Result<T, E>>,
+          F: FnMut(T) -> Option<U>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next_back() {
+                Some(Ok(v)) => {
+                    if let Some(v) = (self.f)(v) {
+                        return Some(Ok(v));
+                    }
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => return None,
+            }
         }
-        // won't truncate because x * (x - 1) is guarenteed to be even
-        size_hint::add((lo / 2, hi.map(|hi| hi / 2)), extra)
     }
 }
 
-struct LazyBuffer<I: Iterator> {
-    it: I,
-    done: bool,
-    buffer: Vec<I::Item>,
+/// An iterator adapter to apply a fallible transformation within a
+/// nested `Result::Ok`.
+///
+/// See [*.and_then_ok()*](trait.Itertools.html#method.and_then_ok) for more information.
+#[derive(Clone)]
+pub struct AndThenOk<I, F> {
+    iter: I,
+    f: F,
 }
 
-impl<I> LazyBuffer<I>
-    where I: Iterator
+/// Create a new `AndThenOk` iterator.
+pub fn and_then_ok<I, F, T, U, E>(iter: I, f: F) -> AndThenOk<I, F>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(T) -> Result<U, E>,
 {
-    pub fn new(it: I) -> LazyBuffer<I> {
-        let mut it = it;
-        let mut buffer = Vec::new();
-        let done;
-        if let Some(first) = it.next() {
-            buffer.push(first);
-            done = false;
-        } else {
-            done = true;
-        }
-        LazyBuffer {
-            it: it,
-            done: done,
-            buffer: buffer,
-        }
+    AndThenOk {
+        iter: iter,
+        f: f,
     }
+}
 
-    pub fn len(&self) -> usize {
-        self.buffer.len()
-    }
+impl<I, F, T, U, E> Iterator for AndThenOk<I, F>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(T) -> Result<U, E>,
+{
+    type Item = Result<U, E>;
 
-    pub fn is_done(&self) -> bool {
-        self.done
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(Ok(v)) => Some((self.f)(v)),
+            Some(Err(e)) => Some(Err(e)),
+            None => None,
+        }
     }
 
-    pub fn get_next(&mut self) -> bool {
-        if self.done {
-            return false;
-        }
-        let next_item = self.it.next();
-        match next_item {
-            Some(x) => {
-                self.buffer.push(x);
-                true
-            }
-            None => {
-                self.done = true;
-                false
-            }
-        }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
     }
 }
 
-impl<I> Index<usize> for LazyBuffer<I>
-    where I: Iterator,
-          I::Item: Sized
+impl<I, F, T, U, E> DoubleEndedIterator for AndThenOk<I, F>
+    where I: DoubleEndedIterator<Item = Result<T, E>>,
+          F: FnMut(T) -> Result<U, E>,
 {
-    type Output = I::Item;
-
-    fn index<'b>(&'b self, _index: usize) -> &'b I::Item {
-        self.buffer.index(_index)
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.iter.next_back() {
+            Some(Ok(v)) => Some((self.f)(v)),
+            Some(Err(e)) => Some(Err(e)),
+            None => None,
+        }
     }
 }
 
-/// An iterator to iterate through all the `n`-length combinations in an iterator.
+impl<I, F, T, U, E> ExactSizeIterator for AndThenOk<I, F>
+    where I: ExactSizeIterator<Item = Result<T, E>>,
+          F: FnMut(T) -> Result<U, E>,
+{}
+
+/// An iterator adapter that pulls again from the adapted iterator when it
+/// yields a `Result::Err` that should be retried.
 ///
-/// See [*.combinations_n()*](trait.Itertools.html#method.combinations_n) for more information.
-pub struct CombinationsN<I: Iterator> {
-    n: usize,
-    indices: Vec<usize>,
-    pool: LazyBuffer<I>,
-    first: bool,
+/// This iterator is *fused*.
+///
+/// See [*.retry_ok()*](trait.Itertools.html#method.retry_ok) for more information.
+#[derive(Clone)]
+pub struct RetryOk<I, F> {
+    iter: Fuse<I>,
+    max_attempts: usize,
+    f: F,
 }
-impl<I> CombinationsN<I>
-    where I: Iterator
-{
-    /// Create a new `CombinationsN` from a clonable iterator.
-    pub fn new(iter: I, n: usize) -> CombinationsN<I> {
-        let mut indices: Vec<usize> = Vec::with_capacity(n);
-        for i in 0..n {
-            indices.push(i);
-        }
-        let mut pool: LazyBuffer<I> = LazyBuffer::new(iter);
-
-        for _ in 0..n {
-            if !pool.get_next() {
-                break;
-            }
-        }
 
-        CombinationsN {
-            n: n,
-            indices: indices,
-            pool: pool,
-            first: true,
-        }
+/// Create a new `RetryOk` iterator.
+///
+/// **Panics** if `max_attempts` is 0.
+pub fn retry_ok<I, F, T, E>(iter: I, max_attempts: usize, f: F) -> RetryOk<I, F>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(&E) -> bool,
+{
+    assert!(max_attempts != 0, "retry_ok: max_attempts must be at least 1");
+    RetryOk {
+        iter: iter.fuse(),
+        max_attempts: max_attempts,
+        f: f,
     }
 }
 
-impl<I> Iterator for CombinationsN<I>
-    where I: Iterator,
-          I::Item: Clone
+impl<I, F, T, E> Iterator for RetryOk<I, F>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(&E) -> bool,
 {
-    type Item = Vec<I::Item>;
+    type Item = Result<T, E>;
+
     fn next(&mut self) -> Option<Self::Item> {
-        let mut pool_len = self.pool.len();
-        if self.pool.is_done() {
-            if pool_len == 0 || self.n > pool_len {
-                return None;
+        let mut last_err = None;
+        for attempt in 1.. {
+            match self.iter.next() {
+                Some(Err(e)) => {
+                    if attempt >= self.max_attempts || !(self.f)(&e) {
+                        return Some(Err(e));
+                    }
+                    last_err = Some(e);
+                }
+                // the source ended while retrying: report the last error
+                None => return last_err.map(Err),
+                elt => return elt,
             }
         }
+        unreachable!()
+    }
 
-        if self.first {
-            self.first = false;
-        } else {
-            // Scan from the end, looking for an index to increment
-            let mut i: usize = self.n - 1;
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // each element yielded consumes at most `max_attempts` elements
+        let (low, hi) = self.iter.size_hint();
+        let low = low / self.max_attempts + (low % self.max_attempts != 0) as usize;
+        (low, hi)
+    }
+}
 
-            // Check if we need to consume more from the iterator
-            if self.indices[i] == pool_len - 1 && !self.pool.is_done() {
-                if self.pool.get_next() {
-                    pool_len += 1;
-                }
-            }
+impl<I, F, T, E> FusedIterator for RetryOk<I, F>
+    where I: Iterator<Item = Result<T, E>>,
+          F: FnMut(&E) -> bool,
+{}
 
-            while self.indices[i] == i + pool_len - self.n {
-                if i > 0 {
-                    i -= 1;
-                } else {
-                    // Reached the last combination
-                    return None;
-                }
-            }
+/// An iterator adaptor that accumulates a state seeded by the first element,
+/// yielding each successive state.
+///
+/// This iterator is *fused*.
+///
+/// See [*.scan1()*](trait.Itertools.html#method.scan1) for more information.
+#[derive(Clone)]
+pub struct Scan1<I: Iterator, F> {
+    iter: I,
+    f: F,
+    state: Option<I::Item>,
+    done: bool,
+}
+
+/// Create a new `Scan1` iterator.
+pub fn scan1<I, F>(iter: I, f: F) -> Scan1<I, F>
+    where I: Iterator,
+{
+    Scan1 {
+        iter: iter,
+        f: f,
+        state: None,
+        done: false,
+    }
+}
+
+impl<I, F> Iterator for Scan1<I, F>
+    where I: Iterator,
+          I::Item: Clone,
+          F: FnMut(&I::Item, I::Item) -> Option<I::Item>,
+{
+    type Item = I::Item;
 
-            // Increment index, and reset the ones to its right
-            self.indices[i] += 1;
-            let mut j = i + 1;
-            while j < self.n {
-                self.indices[j] = self.indices[j - 1] + 1;
-                j += 1;
+    fn next(&mut self) -> Option<I::Item> {
+        if self.done {
+            return None;
+        }
+        let next = match (self.iter.next(), self.state.take()) {
+            (Some(elt), None) => Some(elt),
+            (Some(elt), Some(state)) => (self.f)(&state, elt),
+            (None, _) => None,
+        };
+        match next {
+            Some(state) => {
+                self.state = Some(state.clone());
+                Some(state)
+            }
+            None => {
+                self.done = true;
+                None
             }
         }
+    }
 
-        // Create result vector based on the indices
-        let mut result = Vec::with_capacity(self.n);
-        for i in self.indices.iter() {
-            result.push(self.pool[*i].clone());
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
         }
-        Some(result)
     }
 }
 
-/// An iterator adapter to filter out duplicate elements.
+impl<I, F> FusedIterator for Scan1<I, F>
+    where I: Iterator,
+          I::Item: Clone,
+          F: FnMut(&I::Item, I::Item) -> Option<I::Item>,
+{}
+
+/// An iterator adaptor that inserts one value into a sorted base iterator,
+/// at its sorted position.
 ///
-/// See [*.unique_by()*](trait.Itertools.html#method.unique) for more information.
+/// See [*.insert_sorted()*](trait.Itertools.html#method.insert_sorted) for more information.
 #[derive(Clone)]
-pub struct UniqueBy<I: Iterator, V, F> {
-    iter: I,
-    used: HashSet<V>,
-    f: F,
+pub struct InsertSorted<I: Iterator> {
+    iter: Peekable<I>,
+    value: Option<I::Item>,
 }
 
-impl<I: Iterator, V, F> UniqueBy<I, V, F>
-    where V: Eq + Hash,
-          F: FnMut(&I::Item) -> V
+/// Create a new `InsertSorted` iterator.
+pub fn insert_sorted<I>(iter: I, value: I::Item) -> InsertSorted<I>
+    where I: Iterator,
 {
-    /// Create a new `UniqueBy` iterator.
-    pub fn new(iter: I, f: F) -> UniqueBy<I, V, F> {
-        UniqueBy {
-            iter: iter,
-            used: HashSet::new(),
-            f: f,
-        }
+    InsertSorted {
+        iter: iter.peekable(),
+        value: Some(value),
     }
 }
 
-impl<I, V, F> Iterator for UniqueBy<I, V, F>
+impl<I> Iterator for InsertSorted<I>
     where I: Iterator,
-          V: Eq + Hash,
-          F: FnMut(&I::Item) -> V
+          I::Item: PartialOrd,
 {
     type Item = I::Item;
 
     fn next(&mut self) -> Option<I::Item> {
-        loop {
-            match self.iter.next() {
-                None => return None,
-                Some(v) => {
-                    let key = (self.f)(&v);
-                    if self.used.insert(key) {
-                        return Some(v);
-                    }
-                }
-            }
+        let insert_now = match (&self.value, self.iter.peek()) {
+            (&Some(ref value), Some(elt)) => *value < *elt,
+            (&Some(_), None) => true,
+            (&None, _) => false,
+        };
+        if insert_now {
+            self.value.take()
+        } else {
+            self.iter.next()
         }
     }
 
-    #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let (low, hi) = self.iter.size_hint();
-        ((low > 0 && self.used.is_empty()) as usize, hi)
+        let extra = if self.value.is_some() { 1 } else { 0 };
+        size_hint::add_scalar(self.iter.size_hint(), extra)
     }
 }
 
-impl<I> Iterator for Unique<I>
+impl<I> ExactSizeIterator for InsertSorted<I>
+    where I: ExactSizeIterator,
+          I::Item: PartialOrd,
+{}
+
+/// An iterator adaptor that merges three base iterators in ascending order.
+/// If all base iterators are sorted (ascending), the result is sorted.
+///
+/// Each element is chosen by comparing the heads of the three iterators
+/// directly, instead of going through two nested `Merge` adaptors.
+///
+/// Iterator element type is `I::Item`.
+///
+/// See [`free::merge3()`](free/fn.merge3.html) for more information.
+pub struct Merge3<I, J, K>
     where I: Iterator,
-          I::Item: Eq + Hash + Clone
+          J: Iterator<Item = I::Item>,
+          K: Iterator<Item = I::Item>
+{
+    a: Peekable<I>,
+    b: Peekable<J>,
+    c: Peekable<K>,
+}
+
+impl<I, J, K> Clone for Merge3<I, J, K>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          K: Iterator<Item = I::Item>,
+          Peekable<I>: Clone,
+          Peekable<J>: Clone,
+          Peekable<K>: Clone
+{
+    fn clone(&self) -> Self {
+        clone_fields!(Merge3, self, a, b, c)
+    }
+}
+
+/// Create a `Merge3` iterator.
+pub fn merge3_new<I, J, K>(a: I, b: J, c: K) -> Merge3<I, J, K>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          K: Iterator<Item = I::Item>
+{
+    Merge3 {
+        a: a.peekable(),
+        b: b.peekable(),
+        c: c.peekable(),
+    }
+}
+
+impl<I, J, K> Iterator for Merge3<I, J, K>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          K: Iterator<Item = I::Item>,
+          I::Item: PartialOrd
 {
     type Item = I::Item;
 
     fn next(&mut self) -> Option<I::Item> {
-        loop {
-            match self.iter.iter.next() {
-                None => return None,
-                Some(v) => {
-                    if !self.iter.used.contains(&v) {
-                        // FIXME: Avoid this double lookup when the entry api allows
-                        self.iter.used.insert(v.clone());
-                        return Some(v);
+        // on ties, prefer `a`, then `b`, then `c`
+        let mut pick = 0;
+        {
+            let mut least = self.a.peek();
+            if let Some(elt) = self.b.peek() {
+                match least {
+                    Some(x) if x <= elt => {}
+                    _ => {
+                        least = Some(elt);
+                        pick = 1;
                     }
                 }
             }
+            if let Some(elt) = self.c.peek() {
+                match least {
+                    Some(x) if x <= elt => {}
+                    _ => pick = 2,
+                }
+            }
+        }
+        match pick {
+            0 => self.a.next(),
+            1 => self.b.next(),
+            _ => self.c.next(),
         }
     }
 
-    #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let (low, hi) = self.iter.iter.size_hint();
-        ((low > 0 && self.iter.used.is_empty()) as usize, hi)
+        size_hint::add(size_hint::add(self.a.size_hint(), self.b.size_hint()),
+                       self.c.size_hint())
     }
 }
 
-/// An iterator adapter to filter out duplicate elements.
+/// An iterator adaptor that yields all elements of three base iterators,
+/// one after the other.
 ///
-/// See [*.unique()*](trait.Itertools.html#method.unique) for more information.
+/// Each call to `next` dispatches directly to the current base iterator,
+/// instead of going through two nested `Chain` adaptors.
+///
+/// Iterator element type is `I::Item`.
+///
+/// See [`free::chain3()`](free/fn.chain3.html) for more information.
 #[derive(Clone)]
-pub struct Unique<I: Iterator> {
-    iter: UniqueBy<I, I::Item, ()>,
+pub struct Chain3<I, J, K> {
+    a: I,
+    b: J,
+    c: K,
+    // index of the current base iterator
+    state: u8,
 }
 
-pub fn unique<I>(iter: I) -> Unique<I>
+/// Create a `Chain3` iterator.
+pub fn chain3_new<I, J, K>(a: I, b: J, c: K) -> Chain3<I, J, K>
     where I: Iterator,
-          I::Item: Eq + Hash,
+          J: Iterator<Item = I::Item>,
+          K: Iterator<Item = I::Item>
 {
-    Unique {
-        iter: UniqueBy {
-            iter: iter,
-            used: HashSet::new(),
-            f: (),
+    Chain3 {
+        a: a,
+        b: b,
+        c: c,
+        state: 0,
+    }
+}
+
+impl<I, J, K> Iterator for Chain3<I, J, K>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          K: Iterator<Item = I::Item>
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.state == 0 {
+            match self.a.next() {
+                elt @ Some(_) => return elt,
+                None => self.state = 1,
+            }
+        }
+        if self.state == 1 {
+            match self.b.next() {
+                elt @ Some(_) => return elt,
+                None => self.state = 2,
+            }
+        }
+        self.c.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let c = self.c.size_hint();
+        match self.state {
+            0 => size_hint::add(size_hint::add(self.a.size_hint(), self.b.size_hint()), c),
+            1 => size_hint::add(self.b.size_hint(), c),
+            _ => c,
         }
     }
 }
 
-/// An iterator adapter to simply flatten a structure.
+/// An iterator adaptor that yields chunks of `size` elements, where each
+/// chunk starts with the last `overlap` elements of the previous one.
 ///
-/// See [*.flatten()*](trait.Itertools.html#method.flatten) for more information.
-pub struct Flatten<I>
+/// See [*.chunks_overlapping()*](trait.Itertools.html#method.chunks_overlapping) for more information.
+#[derive(Clone)]
+#[cfg(feature = "use_std")]
+pub struct ChunksOverlapping<I: Iterator> {
+    iter: I,
+    buf: Vec<I::Item>,
+    size: usize,
+    overlap: usize,
+    started: bool,
+    done: bool,
+}
+
+/// Create a new `ChunksOverlapping` iterator.
+///
+/// **Panics** if `overlap >= size`.
+#[cfg(feature = "use_std")]
+pub fn chunks_overlapping<I>(iter: I, size: usize, overlap: usize) -> ChunksOverlapping<I>
     where I: Iterator,
-          I::Item: IntoIterator
 {
-    iter: FlatMap<I, I::Item, fn(I::Item) -> I::Item>,
+    assert!(overlap < size, "chunks_overlapping: overlap must be less than the chunk size");
+    ChunksOverlapping {
+        iter: iter,
+        buf: Vec::with_capacity(size),
+        size: size,
+        overlap: overlap,
+        started: false,
+        done: false,
+    }
 }
 
-impl<I> Flatten<I>
+#[cfg(feature = "use_std")]
+impl<I> Iterator for ChunksOverlapping<I>
     where I: Iterator,
-          I::Item: IntoIterator
+          I::Item: Clone,
 {
-    /// Create a new `Flatten` iterator.
-    pub fn new(iter: I) -> Flatten<I> {
-        fn identity<T>(t: T) -> T {
-            t
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        if self.done {
+            return None;
         }
-        Flatten { iter: iter.flat_map(identity) }
+        if self.started {
+            self.buf.drain(..self.size - self.overlap);
+        }
+        self.started = true;
+        let before = self.buf.len();
+        let missing = self.size - before;
+        self.buf.extend(self.iter.by_ref().take(missing));
+        if self.buf.len() == before {
+            // no new elements, so no new chunk
+            self.done = true;
+            return None;
+        }
+        if self.buf.len() < self.size {
+            self.done = true;
+        }
+        Some(self.buf.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        // the first chunk takes up to `size` elements, every later chunk
+        // up to `step` new elements
+        let step = self.size - self.overlap;
+        let size = self.size;
+        let started = self.started;
+        let chunks = |n: usize| {
+            let (first, n) = if started || n == 0 {
+                (0, n)
+            } else {
+                (1, n.saturating_sub(size))
+            };
+            first + n / step + (n % step != 0) as usize
+        };
+        let (lo, hi) = self.iter.size_hint();
+        (chunks(lo), hi.map(chunks))
     }
 }
 
-impl<I> Iterator for Flatten<I>
-    where I: Iterator,
-          I::Item: IntoIterator
+/// An iterator adaptor that folds the elements of a sorted iterator into
+/// one aggregate per bucket between consecutive boundaries.
+///
+/// See [*.fold_by_boundaries()*](trait.Itertools.html#method.fold_by_boundaries) for more information.
+pub struct FoldByBoundaries<I, J, G, F>
+    where I: Iterator
 {
-    type Item = <I::Item as IntoIterator>::Item;
-    fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next()
-    }
+    iter: Peekable<I>,
+    boundaries: J,
+    init: G,
+    f: F,
+    done: bool,
 }
 
-impl<I> DoubleEndedIterator for Flatten<I>
-    where I: DoubleEndedIterator,
-          I::Item: DoubleEndedIterator
+/// Create a new `FoldByBoundaries` iterator.
+pub fn fold_by_boundaries<I, J, G, F>(iter: I, boundaries: J, init: G, f: F)
+    -> FoldByBoundaries<I, J, G, F>
+    where I: Iterator
 {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        self.iter.next_back()
+    FoldByBoundaries {
+        iter: iter.peekable(),
+        boundaries: boundaries,
+        init: init,
+        f: f,
+        done: false,
     }
 }
 
-impl<I> Clone for Flatten<I> where
-    I: Iterator + Clone,
-    I::Item: IntoIterator + Clone,
-    <<I as Iterator>::Item as IntoIterator>::IntoIter: Clone
+impl<B, I, J, G, F> Iterator for FoldByBoundaries<I, J, G, F>
+    where I: Iterator,
+          I::Item: PartialOrd<J::Item>,
+          J: Iterator,
+          G: FnMut() -> B,
+          F: FnMut(B, I::Item) -> B
 {
-    fn clone(&self) -> Self {
-        Flatten {
-            iter: self.iter.clone()
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        if self.done {
+            return None;
+        }
+        let mut acc = (self.init)();
+        match self.boundaries.next() {
+            Some(bound) => {
+                while self.iter.peek().map_or(false, |elt| *elt < bound) {
+                    if let Some(elt) = self.iter.next() {
+                        acc = (self.f)(acc, elt);
+                    }
+                }
+                Some(acc)
+            }
+            None => {
+                // the last bucket has all the remaining elements, if any
+                self.done = true;
+                if self.iter.peek().is_none() {
+                    return None;
+                }
+                for elt in &mut self.iter {
+                    acc = (self.f)(acc, elt);
+                }
+                Some(acc)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
         }
+        // one bucket per boundary, and possibly one past the last
+        let (lo, _) = self.boundaries.size_hint();
+        let (_, hi) = size_hint::add_scalar(self.boundaries.size_hint(), 1);
+        (lo, hi)
     }
 }
@@ -0,0 +1,146 @@
+use std::array;
+use std::iter;
+use std::mem;
+
+#[cfg(feature = "use_std")]
+use adaptors::{LazyBuffer, next_combination_indices};
+
+/// Take the next `N` elements of `iter` as an array, or return `None` if it
+/// runs out first.
+///
+/// See [*.next_array()*](trait.Itertools.html#method.next_array) for more information.
+pub fn next_array<I, const N: usize>(iter: &mut I) -> Option<[I::Item; N]>
+    where I: Iterator,
+{
+    let mut buf: [Option<I::Item>; N] = [(); N].map(|_| None);
+    for slot in buf.iter_mut() {
+        match iter.next() {
+            None => return None,
+            elt => *slot = elt,
+        }
+    }
+    Some(buf.map(|elt| elt.unwrap()))
+}
+
+/// An iterator adaptor that yields non-overlapping chunks of `N` elements
+/// as arrays.
+///
+/// See [*.array_chunks()*](trait.Itertools.html#method.array_chunks) for more information.
+pub struct ArrayChunks<I, const N: usize>
+    where I: Iterator,
+{
+    iter: I,
+    /// The elements of the incomplete chunk, in a prefix of `Some`s
+    partial: [Option<I::Item>; N],
+}
+
+/// Create a new `ArrayChunks`.
+///
+/// **Panics** if `N` is 0.
+pub fn array_chunks<I, const N: usize>(iter: I) -> ArrayChunks<I, N>
+    where I: Iterator,
+{
+    assert!(N != 0, "array_chunks: chunk size must be non-zero");
+    ArrayChunks {
+        iter: iter,
+        partial: [(); N].map(|_| None),
+    }
+}
+
+impl<I, const N: usize> ArrayChunks<I, N>
+    where I: Iterator,
+{
+    /// Return an iterator of the elements of the incomplete last chunk.
+    ///
+    /// This is empty until the adaptor has been exhausted.
+    pub fn into_remainder(self) -> iter::Flatten<array::IntoIter<Option<I::Item>, N>> {
+        IntoIterator::into_iter(self.partial).flatten()
+    }
+}
+
+impl<I, const N: usize> Iterator for ArrayChunks<I, N>
+    where I: Iterator,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for i in 0..N {
+            match self.iter.next() {
+                None => return None,
+                elt => self.partial[i] = elt,
+            }
+        }
+        let chunk = mem::replace(&mut self.partial, [(); N].map(|_| None));
+        Some(chunk.map(|elt| elt.unwrap()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, hi) = self.iter.size_hint();
+        (low / N, hi.map(|hi| hi / N))
+    }
+}
+
+impl<I, const N: usize> ExactSizeIterator for ArrayChunks<I, N>
+    where I: ExactSizeIterator,
+{}
+
+/// An iterator to iterate through all the `K`-length combinations in an
+/// iterator, as arrays.
+///
+/// See [*.array_combinations()*](trait.Itertools.html#method.array_combinations) for more information.
+#[cfg(feature = "use_std")]
+pub struct ArrayCombinations<I: Iterator, const K: usize> {
+    indices: [usize; K],
+    pool: LazyBuffer<I>,
+    first: bool,
+}
+
+/// Create a new `ArrayCombinations`.
+#[cfg(feature = "use_std")]
+pub fn array_combinations<I, const K: usize>(iter: I) -> ArrayCombinations<I, K>
+    where I: Iterator,
+{
+    let mut indices = [0; K];
+    for (i, index) in indices.iter_mut().enumerate() {
+        *index = i;
+    }
+    let mut pool = LazyBuffer::new(iter);
+
+    for _ in 0..K {
+        if !pool.get_next() {
+            break;
+        }
+    }
+
+    ArrayCombinations {
+        indices: indices,
+        pool: pool,
+        first: true,
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<I, const K: usize> Iterator for ArrayCombinations<I, K>
+    where I: Iterator,
+          I::Item: Clone,
+{
+    type Item = [I::Item; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pool_len = self.pool.len();
+        if self.pool.is_done() {
+            if pool_len == 0 || K > pool_len {
+                return None;
+            }
+        }
+
+        if self.first {
+            self.first = false;
+        } else if !next_combination_indices(&mut self.indices, &mut self.pool) {
+            return None;
+        }
+
+        let pool = &self.pool;
+        Some(self.indices.map(|i| pool[i].clone()))
+    }
+}
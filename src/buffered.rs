@@ -0,0 +1,83 @@
+use std::collections::VecDeque;
+use std::iter::{Fuse, FusedIterator};
+use super::size_hint;
+
+#[derive(Clone)]
+/// An iterator adaptor that pulls elements from the adapted iterator
+/// ahead of time, keeping up to *n* of them in a queue.
+///
+/// Iterator element type is `I::Item`.
+///
+/// This iterator is *fused*.
+///
+/// See [*.buffered()*](trait.Itertools.html#method.buffered) for more information.
+pub struct Buffered<I>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    buf: VecDeque<I::Item>,
+    n: usize,
+}
+
+impl<I> Buffered<I>
+    where I: Iterator
+{
+    /// Create a new `Buffered` iterator.
+    ///
+    /// **Panics** if `n` is 0.
+    pub fn new(iter: I, n: usize) -> Self {
+        assert!(n != 0, "Buffered: buffer size must be non-zero");
+        Buffered {
+            iter: iter.fuse(),
+            buf: VecDeque::with_capacity(n),
+            n: n,
+        }
+    }
+
+    /// Return the number of elements currently held in the buffer.
+    ///
+    /// After a call to `.next()` this is `n - 1` while the adapted
+    /// iterator keeps up, and less when it has run dry.
+    pub fn fill_level(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Return the largest number of elements the buffer holds.
+    pub fn capacity(&self) -> usize {
+        self.n
+    }
+
+    /// Pull elements from the adapted iterator until the buffer is full,
+    /// or the adapted iterator is exhausted.
+    fn fill(&mut self) {
+        while self.buf.len() < self.n {
+            match self.iter.next() {
+                Some(elt) => self.buf.push_back(elt),
+                None => break,
+            }
+        }
+    }
+}
+
+impl<I> Iterator for Buffered<I>
+    where I: Iterator
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.fill();
+        self.buf.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::add_scalar(self.iter.size_hint(), self.buf.len())
+    }
+}
+
+impl<I> FusedIterator for Buffered<I>
+    where I: Iterator
+{}
+
+impl<I> ExactSizeIterator for Buffered<I>
+    where I: ExactSizeIterator
+{}
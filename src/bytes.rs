@@ -0,0 +1,153 @@
+//! Adaptors for iterators of bytes, enabled by the `bytes` feature.
+
+/// Extra iterator methods for iterators of bytes.
+///
+/// Unlike their generic counterparts, these adaptors produce owned,
+/// contiguous `Vec<u8>` buffers, which is what byte-oriented parsing code
+/// usually wants to hand on to slice-based APIs.
+///
+/// ```
+/// use itertools::ByteItertools;
+///
+/// let data = b"abcdefg".iter().cloned();
+/// let chunks: Vec<Vec<u8>> = data.chunk_bytes(3).collect();
+/// assert_eq!(chunks, vec![b"abc".to_vec(), b"def".to_vec(), b"g".to_vec()]);
+/// ```
+pub trait ByteItertools: Iterator<Item = u8> {
+    /// Return an iterator adaptor that yields the bytes of the base iterator
+    /// in chunks of `n` bytes, as `Vec<u8>`. The last chunk may be shorter.
+    ///
+    /// Each chunk is allocated once with capacity `n`.
+    ///
+    /// **Panics** if `n` is zero.
+    ///
+    /// ```
+    /// use itertools::ByteItertools;
+    ///
+    /// let mut chunks = b"hello world".iter().cloned().chunk_bytes(4);
+    /// assert_eq!(chunks.next(), Some(b"hell".to_vec()));
+    /// assert_eq!(chunks.next(), Some(b"o wo".to_vec()));
+    /// assert_eq!(chunks.next(), Some(b"rld".to_vec()));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    fn chunk_bytes(self, n: usize) -> ChunkBytes<Self>
+        where Self: Sized
+    {
+        assert!(n != 0, "chunk_bytes: chunk size must be non-zero");
+        ChunkBytes { iter: self, n: n }
+    }
+
+    /// Return an iterator adaptor that yields every window of `n`
+    /// consecutive bytes of the base iterator, as `Vec<u8>`.
+    ///
+    /// The bytes are gathered in an internal buffer of at most `2 * n`
+    /// bytes; every `n` windows its older half is dropped, so each byte is
+    /// moved at most once. Each window is produced as a copy of its `n`
+    /// bytes. If the base iterator has fewer than `n` bytes, no window is
+    /// produced.
+    ///
+    /// **Panics** if `n` is zero.
+    ///
+    /// ```
+    /// use itertools::ByteItertools;
+    ///
+    /// let windows: Vec<Vec<u8>> = b"abcd".iter().cloned().windows_bytes(2).collect();
+    /// assert_eq!(windows, vec![b"ab".to_vec(), b"bc".to_vec(), b"cd".to_vec()]);
+    /// ```
+    fn windows_bytes(self, n: usize) -> WindowsBytes<Self>
+        where Self: Sized
+    {
+        assert!(n != 0, "windows_bytes: window size must be non-zero");
+        WindowsBytes { iter: self, buf: Vec::with_capacity(2 * n), n: n }
+    }
+}
+
+impl<T: ?Sized> ByteItertools for T where T: Iterator<Item = u8> { }
+
+/// An iterator adaptor that yields the bytes of the base iterator in chunks.
+///
+/// See [*.chunk_bytes()*](trait.ByteItertools.html#method.chunk_bytes) for more information.
+#[derive(Clone, Debug)]
+pub struct ChunkBytes<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<I> Iterator for ChunkBytes<I>
+    where I: Iterator<Item = u8>
+{
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Vec<u8>> {
+        let mut chunk = Vec::with_capacity(self.n);
+        chunk.extend(self.iter.by_ref().take(self.n));
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        let n = self.n;
+        let div_ceil = |x: usize| x / n + (x % n != 0) as usize;
+        (div_ceil(lo), hi.map(div_ceil))
+    }
+}
+
+impl<I> ExactSizeIterator for ChunkBytes<I>
+    where I: ExactSizeIterator<Item = u8>
+{}
+
+/// An iterator adaptor that yields every window of consecutive bytes of the
+/// base iterator.
+///
+/// See [*.windows_bytes()*](trait.ByteItertools.html#method.windows_bytes) for more information.
+#[derive(Clone, Debug)]
+pub struct WindowsBytes<I> {
+    iter: I,
+    buf: Vec<u8>,
+    n: usize,
+}
+
+impl<I> Iterator for WindowsBytes<I>
+    where I: Iterator<Item = u8>
+{
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Vec<u8>> {
+        if self.buf.len() < self.n {
+            let missing = self.n - self.buf.len();
+            self.buf.extend(self.iter.by_ref().take(missing));
+            if self.buf.len() < self.n {
+                return None;
+            }
+        } else {
+            match self.iter.next() {
+                None => return None,
+                Some(byte) => {
+                    if self.buf.len() == 2 * self.n {
+                        self.buf.drain(..self.n);
+                    }
+                    self.buf.push(byte);
+                }
+            }
+        }
+        Some(self.buf[self.buf.len() - self.n..].to_vec())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        if self.buf.len() < self.n {
+            let missing = self.n - self.buf.len() - 1;
+            (lo.saturating_sub(missing), hi.map(|hi| hi.saturating_sub(missing)))
+        } else {
+            (lo, hi)
+        }
+    }
+}
+
+impl<I> ExactSizeIterator for WindowsBytes<I>
+    where I: ExactSizeIterator<Item = u8>
+{}
@@ -0,0 +1,69 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+
+/// The payload of a panic caught by `CatchUnwindMap`, as returned by
+/// `std::panic::catch_unwind`.
+pub type PanicPayload = Box<dyn Any + Send + 'static>;
+
+/// An iterator adaptor that maps elements with a closure, turning panics
+/// in the closure into error elements.
+///
+/// See [*.catch_unwind_map()*](trait.Itertools.html#method.catch_unwind_map)
+/// for more information.
+#[derive(Clone)]
+pub struct CatchUnwindMap<I, F> {
+    iter: I,
+    f: F,
+}
+
+/// Create a new `CatchUnwindMap` iterator.
+pub fn catch_unwind_map<I, F, B>(iter: I, f: F) -> CatchUnwindMap<I, F>
+    where I: Iterator,
+          F: FnMut(I::Item) -> B,
+{
+    CatchUnwindMap {
+        iter: iter,
+        f: f,
+    }
+}
+
+impl<I, F, B> Iterator for CatchUnwindMap<I, F>
+    where I: Iterator,
+          F: FnMut(I::Item) -> B,
+{
+    type Item = Result<B, PanicPayload>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            None => None,
+            Some(elt) => {
+                let f = &mut self.f;
+                Some(panic::catch_unwind(AssertUnwindSafe(move || f(elt))))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F, B> DoubleEndedIterator for CatchUnwindMap<I, F>
+    where I: DoubleEndedIterator,
+          F: FnMut(I::Item) -> B,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.iter.next_back() {
+            None => None,
+            Some(elt) => {
+                let f = &mut self.f;
+                Some(panic::catch_unwind(AssertUnwindSafe(move || f(elt))))
+            }
+        }
+    }
+}
+
+impl<I, F, B> ExactSizeIterator for CatchUnwindMap<I, F>
+    where I: ExactSizeIterator,
+          F: FnMut(I::Item) -> B,
+{}
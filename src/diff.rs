@@ -36,11 +36,28 @@ pub enum Diff<I, J>
 ///
 /// If `i` becomes exhausted before `j` becomes exhausted, the number of elements in `i` along with
 /// the remaining `j` elements will be returned as `Diff::Longer`.
-pub fn diff_with<I, J, F>(i: I, j: J, is_equal: F)
+///
+/// If both iterators yield the same number of elements and every step compares equal, `None` is
+/// returned.
+///
+/// ```
+/// use itertools::{diff_with, Diff};
+///
+/// match diff_with(&[1, 2, 3], &[1, 4, 3], |a, b| a == b) {
+///     Some(Diff::FirstMismatch(index, _, rest)) => {
+///         assert_eq!(index, 1);
+///         assert_eq!(rest.cloned().collect::<Vec<_>>(), vec![4, 3]);
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// assert!(diff_with(&[1, 2], &[1, 2], |a, b| a == b).is_none());
+/// ```
+pub fn diff_with<I, J, F>(i: I, j: J, mut is_equal: F)
     -> Option<Diff<I::IntoIter, J::IntoIter>>
     where I: IntoIterator,
           J: IntoIterator,
-          F: Fn(&I::Item, &J::Item) -> bool
+          F: FnMut(&I::Item, &J::Item) -> bool
 {
     let mut i = i.into_iter();
     let mut j = j.into_iter();
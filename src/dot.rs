@@ -0,0 +1,22 @@
+use std::iter::Sum;
+use std::ops::Add;
+
+/// Implementation guts for `dot` and `norm_squared`.
+///
+/// The elements are added into four partial sums in turn, through `fold`,
+/// and the partial sums are only combined at the end. The partial sums
+/// don't depend on each other, so their additions can overlap, where a
+/// single running sum waits for each addition to finish before the next.
+pub fn sum_interleaved<I>(it: I) -> I::Item
+    where I: Iterator,
+          I::Item: Add<Output = I::Item> + Sum
+{
+    let mut it = it.fuse();
+    let sums = match (it.next(), it.next(), it.next(), it.next()) {
+        (Some(a), Some(b), Some(c), Some(d)) => (a, b, c, d),
+        (a, b, c, _) => return a.into_iter().chain(b).chain(c).sum(),
+    };
+    // rotate the partial sums so that each element goes into the next one
+    let (s0, s1, s2, s3) = it.fold(sums, |(s0, s1, s2, s3), x| (s1, s2, s3, s0 + x));
+    (s0 + s1) + (s2 + s3)
+}
@@ -0,0 +1,42 @@
+/// A value of one of two types, `Left(L)` or `Right(R)`.
+///
+/// See [*.partition_map()*](trait.Itertools.html#method.partition_map) for
+/// how it is used.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Either<L, R> {
+    /// A value of the left type.
+    Left(L),
+    /// A value of the right type.
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    /// Return `true` if the value is the `Left` variant.
+    pub fn is_left(&self) -> bool {
+        match *self {
+            Either::Left(_) => true,
+            Either::Right(_) => false,
+        }
+    }
+
+    /// Return `true` if the value is the `Right` variant.
+    pub fn is_right(&self) -> bool {
+        !self.is_left()
+    }
+
+    /// Convert the left side of `Either<L, R>` to an `Option<L>`.
+    pub fn left(self) -> Option<L> {
+        match self {
+            Either::Left(l) => Some(l),
+            Either::Right(_) => None,
+        }
+    }
+
+    /// Convert the right side of `Either<L, R>` to an `Option<R>`.
+    pub fn right(self) -> Option<R> {
+        match self {
+            Either::Left(_) => None,
+            Either::Right(r) => Some(r),
+        }
+    }
+}
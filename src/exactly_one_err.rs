@@ -0,0 +1,113 @@
+#[cfg(feature = "use_std")]
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+
+use size_hint;
+
+/// Iterator returned for the error case of `Itertools::exactly_one()`.
+///
+/// This iterator yields exactly the same elements as the input iterator.
+///
+/// During the execution of `exactly_one` the iterator must be mutated. This
+/// wrapper effectively "restores" the state of the input iterator when it's
+/// handed back.
+///
+/// This is very similar to `PutBackN` except this iterator only supports 0-2
+/// elements and does not use a `Vec`.
+#[derive(Clone)]
+pub struct ExactlyOneError<I>
+    where I: Iterator
+{
+    first_two: (Option<I::Item>, Option<I::Item>),
+    inner: I,
+}
+
+impl<I> ExactlyOneError<I>
+    where I: Iterator
+{
+    /// Create a new `ExactlyOneError` iterator.
+    pub fn new(first_two: (Option<I::Item>, Option<I::Item>), inner: I) -> Self {
+        ExactlyOneError {
+            first_two: first_two,
+            inner: inner,
+        }
+    }
+
+    fn additional_len(&self) -> usize {
+        self.first_two.0.is_some() as usize + self.first_two.1.is_some() as usize
+    }
+}
+
+impl<I> Iterator for ExactlyOneError<I>
+    where I: Iterator
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(elt) = self.first_two.0.take() {
+            return Some(elt);
+        }
+        if let Some(elt) = self.first_two.1.take() {
+            return Some(elt);
+        }
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::add_scalar(self.inner.size_hint(), self.additional_len())
+    }
+}
+
+impl<I> ExactSizeIterator for ExactlyOneError<I>
+    where I: ExactSizeIterator
+{}
+
+impl<I> Display for ExactlyOneError<I>
+    where I: Iterator
+{
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let additional = self.additional_len();
+        if additional > 0 {
+            write!(f, "got at least 2 elements when exactly one was expected")
+        } else {
+            write!(f, "got zero elements when exactly one was expected")
+        }
+    }
+}
+
+impl<I> Debug for ExactlyOneError<I>
+    where I: Iterator + Debug,
+          I::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self.first_two {
+            (Some(ref first), Some(ref second)) => {
+                write!(f, "ExactlyOneError[First: {:?}, Second: {:?}, RemainingIter: {:?}]",
+                       first, second, self.inner)
+            }
+            (Some(ref first), None) => {
+                write!(f, "ExactlyOneError[First: {:?}, RemainingIter: {:?}]",
+                       first, self.inner)
+            }
+            (None, Some(ref second)) => {
+                write!(f, "ExactlyOneError[Second: {:?}, RemainingIter: {:?}]",
+                       second, self.inner)
+            }
+            (None, None) => write!(f, "ExactlyOneError[RemainingIter: {:?}]", self.inner),
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<I> Error for ExactlyOneError<I>
+    where I: Iterator + Debug,
+          I::Item: Debug,
+{
+    fn description(&self) -> &str {
+        if self.additional_len() > 0 {
+            "got at least 2 elements when exactly one was expected"
+        } else {
+            "got zero elements when exactly one was expected"
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use std::iter::Fuse;
+use size_hint;
+
+/// An iterator adaptor that flattens `Result::Ok` values and
+/// allows `Result::Err` values through unchanged.
+///
+/// See [*.flatten_ok()*](trait.Itertools.html#method.flatten_ok) for more information.
+pub struct FlattenOk<I, T, E>
+    where I: Iterator<Item = Result<T, E>>,
+          T: IntoIterator
+{
+    iter: Fuse<I>,
+    inner_front: Option<T::IntoIter>,
+    inner_back: Option<T::IntoIter>,
+}
+
+/// Create a new `FlattenOk` iterator.
+pub fn flatten_ok<I, T, E>(iter: I) -> FlattenOk<I, T, E>
+    where I: Iterator<Item = Result<T, E>>,
+          T: IntoIterator
+{
+    FlattenOk {
+        iter: iter.fuse(),
+        inner_front: None,
+        inner_back: None,
+    }
+}
+
+impl<I, T, E> Clone for FlattenOk<I, T, E>
+    where I: Iterator<Item = Result<T, E>> + Clone,
+          T: IntoIterator,
+          T::IntoIter: Clone
+{
+    fn clone(&self) -> Self {
+        FlattenOk {
+            iter: self.iter.clone(),
+            inner_front: self.inner_front.clone(),
+            inner_back: self.inner_back.clone(),
+        }
+    }
+}
+
+impl<I, T, E> Iterator for FlattenOk<I, T, E>
+    where I: Iterator<Item = Result<T, E>>,
+          T: IntoIterator
+{
+    type Item = Result<T::Item, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            // Handle the front inner iterator.
+            if let Some(ref mut inner) = self.inner_front {
+                if let Some(elt) = inner.next() {
+                    return Some(Ok(elt));
+                }
+            }
+            self.inner_front = None;
+
+            match self.iter.next() {
+                Some(Ok(ok)) => self.inner_front = Some(ok.into_iter()),
+                Some(Err(e)) => return Some(Err(e)),
+                None => {
+                    // The outer iterator is done; what is left is in the
+                    // back inner iterator.
+                    return match self.inner_back {
+                        Some(ref mut inner) => inner.next().map(Ok),
+                        None => None,
+                    };
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        fn inner_hint<J: Iterator>(inner: &Option<J>) -> (usize, Option<usize>) {
+            match *inner {
+                Some(ref it) => it.size_hint(),
+                None => (0, Some(0)),
+            }
+        }
+        let inner = size_hint::add(inner_hint(&self.inner_front),
+                                   inner_hint(&self.inner_back));
+        match self.iter.size_hint() {
+            (0, Some(0)) => inner,
+            // each outer element may flatten to any number of elements
+            _ => size_hint::add(inner, (0, None)),
+        }
+    }
+}
+
+impl<I, T, E> DoubleEndedIterator for FlattenOk<I, T, E>
+    where I: DoubleEndedIterator<Item = Result<T, E>>,
+          T: IntoIterator,
+          T::IntoIter: DoubleEndedIterator
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            // Handle the back inner iterator.
+            if let Some(ref mut inner) = self.inner_back {
+                if let Some(elt) = inner.next_back() {
+                    return Some(Ok(elt));
+                }
+            }
+            self.inner_back = None;
+
+            match self.iter.next_back() {
+                Some(Ok(ok)) => self.inner_back = Some(ok.into_iter()),
+                Some(Err(e)) => return Some(Err(e)),
+                None => {
+                    // The outer iterator is done; what is left is in the
+                    // front inner iterator.
+                    return match self.inner_front {
+                        Some(ref mut inner) => inner.next_back().map(Ok),
+                        None => None,
+                    };
+                }
+            }
+        }
+    }
+}
@@ -1,26 +1,73 @@
 use std::fmt;
 use std::cell::RefCell;
 
+/// Format all iterator elements lazily, separated by `sep`, using a custom
+/// closure for each element.
+///
+/// See [`.format_with()`](trait.Itertools.html#method.format_with) for more information.
+pub struct FormatWith<'a, I, F> {
+    sep: &'a str,
+    /// FormatWith uses interior mutability because Display::fmt takes &self.
+    inner: RefCell<(I, F)>,
+}
+
 /// Format all iterator elements lazily, separated by `sep`.
 ///
+/// The format value can only be formatted once, after that the iterator is
+/// exhausted.
+///
 /// See [`.format()`](trait.Itertools.html#method.format) for more information.
-pub struct Format<'a, I, F> {
+pub struct Format<'a, I> {
     sep: &'a str,
     /// Format uses interior mutability because Display::fmt takes &self.
-    inner: RefCell<(I, F)>,
+    inner: RefCell<I>,
 }
 
-pub fn new_format<'a, I, F>(iter: I, separator: &'a str, f: F) -> Format<'a, I, F>
+/// Format at most `max_items` iterator elements lazily, separated by `sep`,
+/// followed by a count of the elements that were left out.
+///
+/// The format value can only be formatted once, after that the iterator is
+/// exhausted.
+///
+/// See [`.format_truncated()`](trait.Itertools.html#method.format_truncated) for more information.
+pub struct FormatTruncated<'a, I> {
+    sep: &'a str,
+    max_items: usize,
+    /// FormatTruncated uses interior mutability because Display::fmt takes &self.
+    inner: RefCell<I>,
+}
+
+pub fn new_format<'a, I, F>(iter: I, separator: &'a str, f: F) -> FormatWith<'a, I, F>
     where I: Iterator,
           F: FnMut(I::Item, &mut FnMut(&fmt::Display) -> fmt::Result) -> fmt::Result
 {
-    Format {
+    FormatWith {
         sep: separator,
         inner: RefCell::new((iter, f)),
     }
 }
 
-impl<'a, I, F> fmt::Display for Format<'a, I, F>
+pub fn new_format_default<'a, I>(iter: I, separator: &'a str) -> Format<'a, I>
+    where I: Iterator,
+{
+    Format {
+        sep: separator,
+        inner: RefCell::new(iter),
+    }
+}
+
+pub fn new_format_truncated<'a, I>(iter: I, separator: &'a str, max_items: usize)
+    -> FormatTruncated<'a, I>
+    where I: Iterator,
+{
+    FormatTruncated {
+        sep: separator,
+        max_items: max_items,
+        inner: RefCell::new(iter),
+    }
+}
+
+impl<'a, I, F> fmt::Display for FormatWith<'a, I, F>
     where I: Iterator,
           F: FnMut(I::Item, &mut FnMut(&fmt::Display) -> fmt::Result) -> fmt::Result
 {
@@ -42,14 +89,77 @@ impl<'a, I, F> fmt::Display for Format<'a, I, F>
     }
 }
 
-/*
-impl<'a, I, F> fmt::Debug for Format<'a, I, F>
+impl<'a, I> Format<'a, I>
     where I: Iterator,
-          F: FnMut(I::Item, &mut FnMut(&fmt::Display) -> fmt::Result) -> fmt::Result,
 {
-    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Display::fmt(self, fmt)
+    fn format<F>(&self, f: &mut fmt::Formatter, mut cb: F) -> fmt::Result
+        where F: FnMut(&I::Item, &mut fmt::Formatter) -> fmt::Result,
+    {
+        let mut iter = self.inner.borrow_mut();
+
+        let mut first = true;
+        for elt in &mut *iter {
+            if !first && self.sep.len() > 0 {
+                if let Err(e) = f.write_str(self.sep) {
+                    return Err(e);
+                }
+            }
+            first = false;
+            if let Err(e) = cb(&elt, f) {
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a, I> FormatTruncated<'a, I>
+    where I: Iterator,
+{
+    fn format<F>(&self, f: &mut fmt::Formatter, mut cb: F) -> fmt::Result
+        where F: FnMut(&I::Item, &mut fmt::Formatter) -> fmt::Result,
+    {
+        let mut iter = self.inner.borrow_mut();
+
+        for (i, elt) in (&mut *iter).take(self.max_items).enumerate() {
+            if i > 0 && self.sep.len() > 0 {
+                if let Err(e) = f.write_str(self.sep) {
+                    return Err(e);
+                }
+            }
+            if let Err(e) = cb(&elt, f) {
+                return Err(e);
+            }
+        }
+        let rest = iter.by_ref().count();
+        if rest > 0 {
+            if self.max_items > 0 {
+                if let Err(e) = f.write_str(self.sep) {
+                    return Err(e);
+                }
+            }
+            return write!(f, "\u{2026} and {} more", rest);
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_format {
+    ($fmt_type:ident: $($fmt_trait:ident)*) => {
+        $(
+            impl<'a, I> fmt::$fmt_trait for $fmt_type<'a, I>
+                where I: Iterator,
+                      I::Item: fmt::$fmt_trait,
+            {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    self.format(f, fmt::$fmt_trait::fmt)
+                }
+            }
+        )*
     }
 }
 
-*/
+impl_format!{Format: Display Debug
+             UpperExp LowerExp UpperHex LowerHex Octal Binary Pointer}
+impl_format!{FormatTruncated: Display Debug
+             UpperExp LowerExp UpperHex LowerHex Octal Binary Pointer}
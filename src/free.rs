@@ -3,15 +3,40 @@
 //! The benefit of free functions is that they accept any `IntoIterator` as
 //! argument, so the resulting code may be easier to read.
 
+use std::borrow::Borrow;
+#[cfg(feature = "use_std")]
 use std::fmt::Display;
+#[cfg(feature = "use_std")]
+use std::hash::Hash;
 use std::iter::{self, Zip};
+use adaptors;
+use merge_join;
+#[cfg(feature = "use_std")]
+use std::vec::IntoIter as VecIntoIter;
+#[cfg(feature = "use_std")]
+use {
+    KMerge,
+    KMergeBy,
+    RoundRobin,
+};
 use {
     Itertools,
+    InnerJoin,
+    LeftJoin,
+    OuterJoin,
     Merge,
-    KMerge,
+    MergeBy,
+    Merge3,
+    Chain3,
+    DedupByKey,
     Interleave,
+    Intersperse,
+    PutBack,
+    ZipLongest,
 };
 
+pub use process_results_impl::process_results;
+
 /// Iterate `iterable` with a running index.
 ///
 /// `IntoIterator` enabled version of `.enumerate()`.
@@ -47,6 +72,42 @@ pub fn rev<I>(iterable: I) -> iter::Rev<I::IntoIter>
     iterable.into_iter().rev()
 }
 
+/// Iterate `iterable` with clones of its referenced elements.
+///
+/// `IntoIterator` enabled version of `i.cloned()`.
+///
+/// ```
+/// use itertools::free::cloned;
+///
+/// let words = vec!["a".to_string(), "b".to_string()];
+/// let owned: Vec<String> = cloned(&words).collect();
+/// assert_eq!(owned, words);
+/// ```
+pub fn cloned<'a, I, T: 'a>(iterable: I) -> iter::Cloned<I::IntoIter>
+    where I: IntoIterator<Item = &'a T>,
+          T: Clone
+{
+    iterable.into_iter().cloned()
+}
+
+/// Create an iterator that can put back one element once it has been read.
+///
+/// `IntoIterator` enabled version of `PutBack::new(i)`.
+///
+/// ```
+/// use itertools::free::put_back;
+///
+/// let mut it = put_back(&[1, 2, 3]);
+/// let first = it.next().unwrap();
+/// it.put_back(first);
+/// itertools::assert_equal(it, &[1, 2, 3]);
+/// ```
+pub fn put_back<I>(iterable: I) -> PutBack<I::IntoIter>
+    where I: IntoIterator
+{
+    PutBack::new(iterable.into_iter())
+}
+
 /// Iterate `i` and `j` in lock step.
 ///
 /// `IntoIterator` enabled version of `i.zip(j)`.
@@ -84,6 +145,24 @@ pub fn chain<I, J>(i: I, j: J) -> iter::Chain<I::IntoIter, J::IntoIter>
     i.into_iter().chain(j)
 }
 
+/// Create an iterator that first iterates `i`, then `j`, then `k`.
+///
+/// Unlike `chain(chain(i, j), k)`, the three iterables are held side by side
+/// in one adaptor.
+///
+/// ```
+/// use itertools::free::chain3;
+///
+/// itertools::assert_equal(chain3(&[1, 2], &[], &[3, 4]), &[1, 2, 3, 4]);
+/// ```
+pub fn chain3<I, J, K>(i: I, j: J, k: K) -> Chain3<I::IntoIter, J::IntoIter, K::IntoIter>
+    where I: IntoIterator,
+          J: IntoIterator<Item = I::Item>,
+          K: IntoIterator<Item = I::Item>
+{
+    adaptors::chain3_new(i.into_iter(), j.into_iter(), k.into_iter())
+}
+
 /// Perform a fold operation over the iterable.
 ///
 /// `IntoIterator` enabled version of `i.fold(init, f)`
@@ -100,6 +179,24 @@ pub fn fold<I, B, F>(iterable: I, init: B, f: F) -> B
     iterable.into_iter().fold(init, f)
 }
 
+/// Perform a fold operation over the iterable, using the first element as
+/// the initial accumulator.
+///
+/// `IntoIterator` enabled version of `i.fold1(f)`
+///
+/// ```
+/// use itertools::free::fold1;
+///
+/// assert_eq!(fold1(&[3, 1, 4], |a, b| if b > a { b } else { a }), Some(&4));
+/// assert_eq!(fold1(Vec::<String>::new(), |a, b| a + &b), None);
+/// ```
+pub fn fold1<I, F>(iterable: I, f: F) -> Option<I::Item>
+    where I: IntoIterator,
+          F: FnMut(I::Item, I::Item) -> I::Item
+{
+    iterable.into_iter().fold1(f)
+}
+
 /// Test whether the predicate holds for all elements in the iterable.
 ///
 /// `IntoIterator` enabled version of `i.all(f)`
@@ -132,6 +229,59 @@ pub fn any<I, F>(iterable: I, f: F) -> bool
     iterable.into_iter().any(f)
 }
 
+/// Test whether all elements in the iterable compare equal.
+///
+/// `IntoIterator` enabled version of `i.all_equal()`
+///
+/// ```
+/// use itertools::free::all_equal;
+///
+/// assert!(all_equal(&[1, 1, 1]));
+/// assert!(!all_equal(&[1, 2, 1]));
+/// ```
+pub fn all_equal<I>(iterable: I) -> bool
+    where I: IntoIterator,
+          I::Item: PartialEq
+{
+    iterable.into_iter().all_equal()
+}
+
+/// Test whether all elements in the iterable are unique.
+///
+/// `IntoIterator` enabled version of `i.all_unique()`
+///
+/// ```
+/// use itertools::free::all_unique;
+///
+/// assert!(all_unique(&[1, 2, 3]));
+/// assert!(!all_unique(&[1, 2, 1]));
+/// ```
+#[cfg(feature = "use_std")]
+pub fn all_unique<I>(iterable: I) -> bool
+    where I: IntoIterator,
+          I::Item: Eq + Hash
+{
+    iterable.into_iter().all_unique()
+}
+
+/// Test whether the iterable contains `query`.
+///
+/// `IntoIterator` enabled version of `i.contains(query)`
+///
+/// ```
+/// use itertools::free::contains;
+///
+/// assert!(contains(&[1, 2, 3], &2));
+/// assert!(!contains(vec![String::from("a")], "b"));
+/// ```
+pub fn contains<I, Q: ?Sized>(iterable: I, query: &Q) -> bool
+    where I: IntoIterator,
+          I::Item: Borrow<Q>,
+          Q: PartialEq
+{
+    iterable.into_iter().contains(query)
+}
+
 /// Return the maximum value of the iterable.
 ///
 /// `IntoIterator` enabled version of `i.max()`.
@@ -182,6 +332,40 @@ pub fn interleave<I, J>(i: I, j: J) -> Interleave<I::IntoIter, J::IntoIter>
     i.into_iter().interleave(j)
 }
 
+/// Iterate `iterable` with a clone of `element` between each pair of its
+/// elements.
+///
+/// `IntoIterator` enabled version of `i.intersperse(element)`.
+///
+/// ```
+/// use itertools::free::intersperse;
+///
+/// itertools::assert_equal(intersperse(vec![1, 2, 3], 0), vec![1, 0, 2, 0, 3]);
+/// ```
+pub fn intersperse<I>(iterable: I, element: I::Item) -> Intersperse<I::IntoIter>
+    where I: IntoIterator,
+          I::Item: Clone
+{
+    Intersperse::new(iterable.into_iter(), element)
+}
+
+/// Iterate `i` and `j` in lock step until both are exhausted.
+///
+/// `IntoIterator` enabled version of `i.zip_longest(j)`.
+///
+/// ```
+/// use itertools::free::zip_longest;
+/// use itertools::EitherOrBoth::{Both, Left};
+///
+/// itertools::assert_equal(zip_longest(&[1, 2], &[3]), vec![Both(&1, &3), Left(&2)]);
+/// ```
+pub fn zip_longest<I, J>(i: I, j: J) -> ZipLongest<I::IntoIter, J::IntoIter>
+    where I: IntoIterator,
+          J: IntoIterator
+{
+    i.into_iter().zip_longest(j)
+}
+
 /// Create an iterator that merges elements in `i` and `j`.
 ///
 /// `IntoIterator` enabled version of `i.merge(j)`.
@@ -201,6 +385,139 @@ pub fn merge<I, J>(i: I, j: J) -> Merge<I::IntoIter, J::IntoIter>
     i.into_iter().merge(j)
 }
 
+/// Join two iterators of `(key, value)` pairs that are sorted by key,
+/// yielding `(key, left_value, right_value)` for each key found in both.
+///
+/// Keys are compared with `Ord`, and both iterators must be sorted in
+/// ascending key order. This is built on `merge_join_by`, so each key is
+/// expected to appear at most once in each iterator; repeated keys are
+/// paired up in order, and any extra ones are left out.
+///
+/// ```
+/// use itertools::free::inner_join;
+///
+/// let names = vec![(1, "ann"), (2, "bob"), (4, "dan")];
+/// let ages = vec![(1, 31), (3, 27), (4, 45)];
+/// itertools::assert_equal(inner_join(names, ages),
+///                         vec![(1, "ann", 31), (4, "dan", 45)]);
+/// ```
+pub fn inner_join<I, J, K, V, W>(left: I, right: J) -> InnerJoin<I::IntoIter, J::IntoIter>
+    where I: IntoIterator<Item = (K, V)>,
+          J: IntoIterator<Item = (K, W)>,
+          K: Ord,
+{
+    merge_join::inner_join(left, right)
+}
+
+/// Join two iterators of `(key, value)` pairs that are sorted by key,
+/// yielding `(key, left_value, Option<right_value>)` for each key of
+/// `left`.
+///
+/// See [`inner_join`](fn.inner_join.html) for the requirements on the
+/// inputs.
+///
+/// ```
+/// use itertools::free::left_join;
+///
+/// let names = vec![(1, "ann"), (2, "bob"), (4, "dan")];
+/// let ages = vec![(1, 31), (3, 27), (4, 45)];
+/// itertools::assert_equal(left_join(names, ages),
+///                         vec![(1, "ann", Some(31)), (2, "bob", None), (4, "dan", Some(45))]);
+/// ```
+pub fn left_join<I, J, K, V, W>(left: I, right: J) -> LeftJoin<I::IntoIter, J::IntoIter>
+    where I: IntoIterator<Item = (K, V)>,
+          J: IntoIterator<Item = (K, W)>,
+          K: Ord,
+{
+    merge_join::left_join(left, right)
+}
+
+/// Join two iterators of `(key, value)` pairs that are sorted by key,
+/// yielding `(key, EitherOrBoth<left_value, right_value>)` for each key
+/// of either iterator, in ascending key order.
+///
+/// See [`inner_join`](fn.inner_join.html) for the requirements on the
+/// inputs.
+///
+/// ```
+/// use itertools::free::outer_join;
+/// use itertools::EitherOrBoth::{Both, Left, Right};
+///
+/// let names = vec![(1, "ann"), (2, "bob")];
+/// let ages = vec![(1, 31), (3, 27)];
+/// itertools::assert_equal(outer_join(names, ages),
+///                         vec![(1, Both("ann", 31)), (2, Left("bob")), (3, Right(27))]);
+/// ```
+pub fn outer_join<I, J, K, V, W>(left: I, right: J) -> OuterJoin<I::IntoIter, J::IntoIter>
+    where I: IntoIterator<Item = (K, V)>,
+          J: IntoIterator<Item = (K, W)>,
+          K: Ord,
+{
+    merge_join::outer_join(left, right)
+}
+
+/// Create an iterator that merges elements in `i`, `j` and `k`.
+///
+/// If all three are sorted (ascending), the result is sorted. Elements that
+/// compare equal come from `i` first, then `j`, then `k`.
+///
+/// Unlike `merge(merge(i, j), k)`, each element is picked by comparing the
+/// three heads directly.
+///
+/// ```
+/// use itertools::free::merge3;
+///
+/// itertools::assert_equal(merge3(&[1, 4, 7], &[2, 5], &[3, 6, 8]),
+///                         &[1, 2, 3, 4, 5, 6, 7, 8]);
+/// ```
+pub fn merge3<I, J, K>(i: I, j: J, k: K) -> Merge3<I::IntoIter, J::IntoIter, K::IntoIter>
+    where I: IntoIterator,
+          J: IntoIterator<Item = I::Item>,
+          K: IntoIterator<Item = I::Item>,
+          I::Item: PartialOrd
+{
+    adaptors::merge3_new(i.into_iter(), j.into_iter(), k.into_iter())
+}
+
+/// Create an iterator that merges elements in `i` and `j` using the
+/// ordering predicate `is_first`.
+///
+/// `IntoIterator` enabled version of `i.merge_by(j, is_first)`.
+///
+/// ```
+/// use itertools::free::merge_by;
+///
+/// // merge two sequences sorted in descending order
+/// itertools::assert_equal(merge_by(&[5, 3, 1], &[4, 3, 2], |a, b| a >= b),
+///                         &[5, 4, 3, 3, 2, 1]);
+/// ```
+pub fn merge_by<I, J, F>(i: I, j: J, is_first: F) -> MergeBy<I::IntoIter, J::IntoIter, F>
+    where I: IntoIterator,
+          J: IntoIterator<Item = I::Item>,
+          F: FnMut(&I::Item, &I::Item) -> bool
+{
+    i.into_iter().merge_by(j, is_first)
+}
+
+/// Create an iterator that takes one element from each of the contained
+/// iterators in turn.
+///
+/// Equivalent to `i.into_iter().round_robin()`.
+///
+/// ```
+/// use itertools::free::round_robin;
+///
+/// itertools::assert_equal(round_robin(vec!["ab".chars(), "".chars(), "cde".chars()]),
+///                         "acbde".chars());
+/// ```
+#[cfg(feature = "use_std")]
+pub fn round_robin<I>(i: I) -> RoundRobin<<I::Item as IntoIterator>::IntoIter>
+    where I: IntoIterator,
+          I::Item: IntoIterator
+{
+    i.into_iter().round_robin()
+}
+
 /// Create an iterator that merges elements of the contained iterators.
 ///
 /// Equivalent to `i.into_iter().kmerge()`.
@@ -212,6 +529,7 @@ pub fn merge<I, J>(i: I, j: J) -> Merge<I::IntoIter, J::IntoIter>
 ///     /* loop body */
 /// }
 /// ```
+#[cfg(feature = "use_std")]
 pub fn kmerge<I>(i: I) -> KMerge<<<I as IntoIterator>::Item as IntoIterator>::IntoIter>
     where I: IntoIterator,
           I::Item: IntoIterator,
@@ -220,6 +538,65 @@ pub fn kmerge<I>(i: I) -> KMerge<<<I as IntoIterator>::Item as IntoIterator>::In
     i.into_iter().kmerge()
 }
 
+/// Create an iterator that merges elements of the contained iterators,
+/// using the ordering predicate `first`.
+///
+/// Equivalent to `i.into_iter().kmerge_by(first)`.
+///
+/// ```
+/// use itertools::free::kmerge_by;
+///
+/// // merge sequences sorted in descending order
+/// itertools::assert_equal(kmerge_by(vec![vec![6, 3], vec![5, 2], vec![4, 1]], |a, b| a > b),
+///                         vec![6, 5, 4, 3, 2, 1]);
+/// ```
+#[cfg(feature = "use_std")]
+pub fn kmerge_by<I, F>(i: I, first: F)
+    -> KMergeBy<<<I as IntoIterator>::Item as IntoIterator>::IntoIter, F>
+    where I: IntoIterator,
+          I::Item: IntoIterator,
+          F: FnMut(&<I::Item as IntoIterator>::Item,
+                   &<I::Item as IntoIterator>::Item) -> bool
+{
+    i.into_iter().kmerge_by(first)
+}
+
+/// Remove duplicates from sections of consecutive elements with equal keys.
+///
+/// `IntoIterator` enabled version of `iterable.dedup_by_key(key)`, known as
+/// `unique_justseen` in Python's itertools recipes.
+///
+/// ```
+/// use itertools::free::dedup_by_key;
+///
+/// itertools::assert_equal(dedup_by_key("AAAABBBCCDAABBB".chars(), |c| *c), "ABCDAB".chars());
+/// itertools::assert_equal(dedup_by_key("ABBcCAD".chars(), |c| c.to_ascii_lowercase()),
+///                         "ABcAD".chars());
+/// ```
+pub fn dedup_by_key<I, K, F>(iterable: I, key: F) -> DedupByKey<I::IntoIter, F>
+    where I: IntoIterator,
+          F: FnMut(&I::Item) -> K,
+          K: PartialEq
+{
+    iterable.into_iter().dedup_by_key(key)
+}
+
+/// Combine all the containers of `iterable` into one, in order.
+///
+/// `IntoIterator` enabled version of `iterable.concat()`.
+///
+/// ```
+/// use itertools::free::concat;
+///
+/// assert_eq!(concat(vec![vec![1, 2], vec![3]]), vec![1, 2, 3]);
+/// ```
+pub fn concat<I>(iterable: I) -> I::Item
+    where I: IntoIterator,
+          I::Item: Extend<<I::Item as IntoIterator>::Item> + IntoIterator + Default
+{
+    iterable.into_iter().concat()
+}
+
 /// Combine all iterator elements into one String, seperated by `sep`.
 ///
 /// `IntoIterator` enabled version of `iterable.join(sep)`.
@@ -229,6 +606,7 @@ pub fn kmerge<I>(i: I) -> KMerge<<<I as IntoIterator>::Item as IntoIterator>::In
 ///
 /// assert_eq!(join(&[1, 2, 3], ", "), "1, 2, 3");
 /// ```
+#[cfg(feature = "use_std")]
 pub fn join<I>(iterable: I, sep: &str) -> String
     where I: IntoIterator,
           I::Item: Display
@@ -236,7 +614,7 @@ pub fn join<I>(iterable: I, sep: &str) -> String
     iterable.into_iter().join(sep)
 }
 
-/// Collect all the iterable's elements into a sorted vector in ascending order.
+/// Sort all the elements of the iterable into a new iterator in ascending order.
 ///
 /// `IntoIterator` enabled version of `iterable.sorted()`.
 ///
@@ -246,7 +624,8 @@ pub fn join<I>(iterable: I, sep: &str) -> String
 ///
 /// assert_equal(sorted("rust".chars()), "rstu".chars());
 /// ```
-pub fn sorted<I>(iterable: I) -> Vec<I::Item>
+#[cfg(feature = "use_std")]
+pub fn sorted<I>(iterable: I) -> VecIntoIter<I::Item>
     where I: IntoIterator,
           I::Item: Ord
 {
@@ -0,0 +1,113 @@
+use std::cmp;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::vec;
+
+/// Return a `HashMap` of keys mapped to a list of their corresponding values.
+///
+/// See [`.into_group_map()`](trait.Itertools.html#method.into_group_map)
+/// for more information.
+pub fn into_group_map<I, K, V>(iter: I) -> HashMap<K, Vec<V>>
+    where I: Iterator<Item = (K, V)>,
+          K: Hash + Eq,
+{
+    let mut lookup = HashMap::new();
+
+    for (key, val) in iter {
+        lookup.entry(key).or_insert_with(Vec::new).push(val);
+    }
+
+    lookup
+}
+
+/// Return a `HashMap` of the keys computed by `f` mapped to a list of the
+/// elements that have them.
+///
+/// See [`.into_group_map_by()`](trait.Itertools.html#method.into_group_map_by)
+/// for more information.
+pub fn into_group_map_by<I, K, F>(iter: I, mut f: F) -> HashMap<K, Vec<I::Item>>
+    where I: Iterator,
+          K: Hash + Eq,
+          F: FnMut(&I::Item) -> K,
+{
+    into_group_map(iter.map(|v| (f(&v), v)))
+}
+
+/// An iterator adaptor that groups all elements with equal keys, adjacent
+/// or not, and yields one group per distinct key.
+///
+/// See [`.group_by_hash()`](trait.Itertools.html#method.group_by_hash)
+/// for more information.
+pub struct GroupByHash<K, I, F>
+    where I: Iterator,
+{
+    iter: Option<I>,
+    key: F,
+    groups: vec::IntoIter<(K, Vec<I::Item>)>,
+}
+
+/// Create a new `GroupByHash`.
+pub fn group_by_hash<K, I, F>(iter: I, key: F) -> GroupByHash<K, I, F>
+    where I: Iterator,
+{
+    GroupByHash {
+        iter: Some(iter),
+        key: key,
+        groups: Vec::new().into_iter(),
+    }
+}
+
+impl<K, I, F> GroupByHash<K, I, F>
+    where I: Iterator,
+          K: Hash + Eq,
+          F: FnMut(&I::Item) -> K,
+{
+    /// Consume the whole underlying iterator, and gather its elements by key
+    /// in the order each key first appears.
+    fn fill(&mut self, iter: I) {
+        let mut index = HashMap::new();
+        let mut groups: Vec<(Option<K>, Vec<I::Item>)> = Vec::new();
+        for elt in iter {
+            let key = (self.key)(&elt);
+            let next_index = groups.len();
+            let i = *index.entry(key).or_insert(next_index);
+            if i == next_index {
+                groups.push((None, Vec::new()));
+            }
+            groups[i].1.push(elt);
+        }
+        for (key, i) in index {
+            groups[i].0 = Some(key);
+        }
+        self.groups = groups.into_iter()
+                            .map(|(key, group)| (key.unwrap(), group))
+                            .collect::<Vec<_>>()
+                            .into_iter();
+    }
+}
+
+impl<K, I, F> Iterator for GroupByHash<K, I, F>
+    where I: Iterator,
+          K: Hash + Eq,
+          F: FnMut(&I::Item) -> K,
+{
+    type Item = (K, vec::IntoIter<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(iter) = self.iter.take() {
+            self.fill(iter);
+        }
+        self.groups.next().map(|(key, group)| (key, group.into_iter()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.iter {
+            // there is at least one group if there is at least one element
+            Some(ref iter) => {
+                let (low, hi) = iter.size_hint();
+                (cmp::min(low, 1), hi)
+            }
+            None => self.groups.size_hint(),
+        }
+    }
+}
@@ -71,6 +71,10 @@ struct GroupInner<K, I, F>
     buffer: Vec<vec::IntoIter<I::Item>>,
     /// index of last group iter that was dropped, usize::MAX == none
     dropped_group: usize,
+    /// Number of elements currently held in `buffer`
+    buffered: usize,
+    /// Maximum value of `buffered`, usize::MAX == unbounded
+    max_buffered: usize,
 }
 
 impl<K, I, F> GroupInner<K, I, F>
@@ -84,7 +88,7 @@ impl<K, I, F> GroupInner<K, I, F>
         /*
         println!("client={}, bufbot={}, bot={}, top={}, buffers=[{}]",
                  client, self.bufbot, self.bot, self.top,
-                 self.buffer.iter().format(", ", |elt, f| f(&elt.len())));
+                 self.buffer.iter().format_with(", ", |elt, f| f(&elt.len())));
          */
         if client < self.bot {
             None
@@ -109,6 +113,9 @@ impl<K, I, F> GroupInner<K, I, F>
             return None;
         }
         let elt = self.buffer.get_mut(bufidx).and_then(|queue| queue.next());
+        if elt.is_some() {
+            self.buffered -= 1;
+        }
         if elt.is_none() && client == self.bot {
             // FIXME: VecDeque is unfortunately not zero allocation when empty,
             // so we do this job manually.
@@ -159,6 +166,7 @@ impl<K, I, F> GroupInner<K, I, F>
 
         if let Some(elt) = self.current_elt.take() {
             if self.top != self.dropped_group {
+                self.count_buffered();
                 group.push(elt);
             }
         }
@@ -176,6 +184,7 @@ impl<K, I, F> GroupInner<K, I, F>
             }
             self.current_key = Some(key);
             if self.top != self.dropped_group {
+                self.count_buffered();
                 group.push(elt);
             }
         }
@@ -190,6 +199,18 @@ impl<K, I, F> GroupInner<K, I, F>
         first_elt
     }
 
+    /// Account for one more buffered element.
+    ///
+    /// **Panics** if this would exceed `max_buffered`.
+    #[inline]
+    fn count_buffered(&mut self) {
+        if self.buffered >= self.max_buffered {
+            panic!("GroupByLazy: buffering would exceed the bound of {} elements",
+                   self.max_buffered);
+        }
+        self.buffered += 1;
+    }
+
     fn push_next_group(&mut self, group: Vec<I::Item>) {
         // When we add a new buffered group, fill up slots between bot and top
         while self.top - self.bufbot > self.buffer.len() {
@@ -295,6 +316,14 @@ pub struct GroupByLazy<K, I, F>
 pub fn new<K, J, F>(iter: J, f: F) -> GroupByLazy<K, J::IntoIter, F>
     where J: IntoIterator,
           F: FnMut(&J::Item) -> K,
+{
+    new_bounded(iter, !0, f)
+}
+
+/// Create a new `GroupByLazy` that buffers at most `max_buffered` elements
+pub fn new_bounded<K, J, F>(iter: J, max_buffered: usize, f: F) -> GroupByLazy<K, J::IntoIter, F>
+    where J: IntoIterator,
+          F: FnMut(&J::Item) -> K,
 {
     GroupByLazy {
         inner: RefCell::new(GroupInner {
@@ -308,6 +337,8 @@ pub fn new<K, J, F>(iter: J, f: F) -> GroupByLazy<K, J::IntoIter, F>
             bufbot: 0,
             buffer: Vec::new(),
             dropped_group: !0,
+            buffered: 0,
+            max_buffered: max_buffered,
         }),
         index: Cell::new(0),
     }
@@ -424,6 +455,14 @@ impl<'a, K, I, F> Iterator for Group<'a, K, I, F>
 /// Create a new
 pub fn new_chunks<J>(iter: J, size: usize) -> ChunksLazy<J::IntoIter>
     where J: IntoIterator,
+{
+    new_chunks_bounded(iter, size, !0)
+}
+
+/// Create a new `ChunksLazy` that buffers at most `max_buffered` elements
+pub fn new_chunks_bounded<J>(iter: J, size: usize, max_buffered: usize)
+    -> ChunksLazy<J::IntoIter>
+    where J: IntoIterator,
 {
     ChunksLazy {
         inner: RefCell::new(GroupInner {
@@ -437,6 +476,8 @@ pub fn new_chunks<J>(iter: J, size: usize) -> ChunksLazy<J::IntoIter>
             bufbot: 0,
             buffer: Vec::new(),
             dropped_group: !0,
+            buffered: 0,
+            max_buffered: max_buffered,
         }),
         index: Cell::new(0),
     }
@@ -1,4 +1,4 @@
-use std::iter::Fuse;
+use std::iter::{Fuse, FusedIterator};
 use super::size_hint;
 
 #[derive(Clone)]
@@ -57,4 +57,244 @@ impl<I> Iterator for Intersperse<I>
         let sh = self.iter.size_hint();
         size_hint::add_scalar(size_hint::add(sh, sh), has_peek)
     }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+        where F: FnMut(B, Self::Item) -> B
+    {
+        let mut accum = init;
+        if let Some(x) = self.peek {
+            accum = f(accum, x);
+        }
+        let element = &self.element;
+        self.iter.fold(accum, |accum, x| {
+            let accum = f(accum, element.clone());
+            f(accum, x)
+        })
+    }
+}
+
+impl<I> FusedIterator for Intersperse<I>
+    where I: Iterator,
+          I::Item: Clone
+{}
+
+#[derive(Clone)]
+/// An iterator adaptor to insert a value produced by a closure
+/// between each element of the adapted iterator.
+///
+/// Iterator element type is `I::Item`
+///
+/// This iterator is *fused*.
+///
+/// See [*.intersperse_with()*](trait.Itertools.html#method.intersperse_with)
+/// for more information.
+pub struct IntersperseWith<I, F>
+    where I: Iterator
+{
+    element: F,
+    iter: Fuse<I>,
+    peek: Option<I::Item>,
 }
+
+impl<I, F> IntersperseWith<I, F>
+    where I: Iterator,
+          F: FnMut() -> I::Item
+{
+    /// Create a new IntersperseWith iterator
+    pub fn new(iter: I, elt: F) -> Self {
+        let mut iter = iter.fuse();
+        IntersperseWith {
+            peek: iter.next(),
+            iter: iter,
+            element: elt,
+        }
+    }
+}
+
+impl<I, F> Iterator for IntersperseWith<I, F>
+    where I: Iterator,
+          F: FnMut() -> I::Item
+{
+    type Item = I::Item;
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        if self.peek.is_some() {
+            self.peek.take()
+        } else {
+            self.peek = self.iter.next();
+            if self.peek.is_some() {
+                Some((self.element)())
+            } else {
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let has_peek = self.peek.is_some() as usize;
+        let sh = self.iter.size_hint();
+        size_hint::add_scalar(size_hint::add(sh, sh), has_peek)
+    }
+
+    fn fold<B, G>(self, init: B, mut g: G) -> B
+        where G: FnMut(B, Self::Item) -> B
+    {
+        let mut accum = init;
+        if let Some(x) = self.peek {
+            accum = g(accum, x);
+        }
+        let mut element = self.element;
+        self.iter.fold(accum, |accum, x| {
+            let accum = g(accum, element());
+            g(accum, x)
+        })
+    }
+}
+
+impl<I, F> FusedIterator for IntersperseWith<I, F>
+    where I: Iterator,
+          F: FnMut() -> I::Item
+{}
+
+#[derive(Clone)]
+/// An iterator adaptor to insert a particular value after every *n*
+/// elements of the adapted iterator.
+///
+/// Iterator element type is `I::Item`
+///
+/// This iterator is *fused*.
+///
+/// See [*.intersperse_every()*](trait.Itertools.html#method.intersperse_every)
+/// for more information.
+pub struct IntersperseEvery<I>
+    where I: Iterator
+{
+    element: I::Item,
+    iter: Fuse<I>,
+    peek: Option<I::Item>,
+    n: usize,
+    count: usize,
+}
+
+impl<I> IntersperseEvery<I>
+    where I: Iterator
+{
+    /// Create a new IntersperseEvery iterator
+    ///
+    /// **Panics** if `n` is 0.
+    pub fn new(iter: I, n: usize, elt: I::Item) -> Self {
+        assert!(n != 0);
+        let mut iter = iter.fuse();
+        IntersperseEvery {
+            peek: iter.next(),
+            iter: iter,
+            element: elt,
+            n: n,
+            count: 0,
+        }
+    }
+}
+
+impl<I> Iterator for IntersperseEvery<I>
+    where I: Iterator,
+          I::Item: Clone
+{
+    type Item = I::Item;
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        let elt = match self.peek.take() {
+            None => return None,
+            Some(elt) => elt,
+        };
+        if self.count == self.n {
+            self.count = 0;
+            self.peek = Some(elt);
+            Some(self.element.clone())
+        } else {
+            self.count += 1;
+            self.peek = self.iter.next();
+            Some(elt)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // remaining elements + separators between them and the current run
+        let sh = size_hint::add_scalar(self.iter.size_hint(),
+                                       self.peek.is_some() as usize);
+        let count = self.count;
+        let n = self.n;
+        let with_separators = |x: usize| {
+            if x == 0 {
+                Some(0)
+            } else {
+                count.checked_add(x - 1)
+                     .and_then(|run| x.checked_add(run / n))
+            }
+        };
+        let low = with_separators(sh.0).unwrap_or(::std::usize::MAX);
+        let hi = sh.1.and_then(with_separators);
+        (low, hi)
+    }
+}
+
+impl<I> FusedIterator for IntersperseEvery<I>
+    where I: Iterator,
+          I::Item: Clone
+{}
+
+#[derive(Clone)]
+/// An iterator adaptor that yields a prefix element, the elements of the
+/// adapted iterator with a separator between each, and then a suffix element.
+///
+/// Iterator element type is `I::Item`
+///
+/// This iterator is *fused*.
+///
+/// See [*.delimited()*](trait.Itertools.html#method.delimited) for more information.
+pub struct Delimited<I>
+    where I: Iterator
+{
+    prefix: Option<I::Item>,
+    inner: Intersperse<I>,
+    suffix: Option<I::Item>,
+}
+
+impl<I> Delimited<I>
+    where I: Iterator
+{
+    /// Create a new Delimited iterator
+    pub fn new(iter: I, prefix: I::Item, sep: I::Item, suffix: I::Item) -> Self {
+        Delimited {
+            prefix: Some(prefix),
+            inner: Intersperse::new(iter, sep),
+            suffix: Some(suffix),
+        }
+    }
+}
+
+impl<I> Iterator for Delimited<I>
+    where I: Iterator,
+          I::Item: Clone
+{
+    type Item = I::Item;
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        if self.prefix.is_some() {
+            return self.prefix.take();
+        }
+        match self.inner.next() {
+            None => self.suffix.take(),
+            elt => elt,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let ends = self.prefix.is_some() as usize + self.suffix.is_some() as usize;
+        size_hint::add_scalar(self.inner.size_hint(), ends)
+    }
+}
+
+impl<I> FusedIterator for Delimited<I>
+    where I: Iterator,
+          I::Item: Clone
+{}
@@ -0,0 +1,59 @@
+use std::cmp::Ordering;
+
+/// Move the element at `origin` down the max-heap `heap` until both of its
+/// children compare less than or equal to it.
+fn sift_down<T, F>(heap: &mut [T], mut origin: usize, cmp: &mut F)
+    where F: FnMut(&T, &T) -> Ordering
+{
+    loop {
+        let left = 2 * origin + 1;
+        let right = left + 1;
+        let mut largest = origin;
+        if left < heap.len() && cmp(&heap[left], &heap[largest]) == Ordering::Greater {
+            largest = left;
+        }
+        if right < heap.len() && cmp(&heap[right], &heap[largest]) == Ordering::Greater {
+            largest = right;
+        }
+        if largest == origin {
+            return;
+        }
+        heap.swap(origin, largest);
+        origin = largest;
+    }
+}
+
+/// Collect the `k` smallest elements of `iter` according to `cmp`, sorted
+/// in ascending order.
+///
+/// Only `k` elements are kept at any time, in a max-heap whose root is the
+/// largest element selected so far.
+pub fn k_smallest_general<I, F>(iter: I, k: usize, mut cmp: F) -> Vec<I::Item>
+    where I: Iterator,
+          F: FnMut(&I::Item, &I::Item) -> Ordering
+{
+    if k == 0 {
+        return Vec::new();
+    }
+    let mut iter = iter;
+    let mut heap: Vec<I::Item> = iter.by_ref().take(k).collect();
+    for i in (0..heap.len() / 2).rev() {
+        sift_down(&mut heap, i, &mut cmp);
+    }
+
+    for elt in iter {
+        if cmp(&elt, &heap[0]) == Ordering::Less {
+            heap[0] = elt;
+            sift_down(&mut heap, 0, &mut cmp);
+        }
+    }
+
+    // the heap is a max-heap: repeatedly move its root to the back
+    let mut end = heap.len();
+    while end > 1 {
+        end -= 1;
+        heap.swap(0, end);
+        sift_down(&mut heap[..end], 0, &mut cmp);
+    }
+    heap
+}
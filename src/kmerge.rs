@@ -2,7 +2,6 @@
 use size_hint;
 use Itertools;
 
-use std::cmp::Ordering;
 use std::mem::replace;
 
 macro_rules! clone_fields {
@@ -17,11 +16,8 @@ macro_rules! clone_fields {
 
 /// Head element and Tail iterator pair
 ///
-/// `PartialEq`, `Eq`, `PartialOrd` and `Ord` are implemented by comparing sequences based on
+/// The heap used in `KMerge` and `KMergeBy` orders sequences based on their
 /// first items (which are guaranteed to exist).
-///
-/// The meanings of `PartialOrd` and `Ord` are reversed so as to turn the heap used in
-/// `KMerge` into a min-heap.
 struct HeadTail<I>
     where I: Iterator
 {
@@ -69,63 +65,19 @@ impl<I> Clone for HeadTail<I>
     }
 }
 
-impl<I> PartialEq for HeadTail<I>
-    where I: Iterator,
-          I::Item: PartialEq
-{
-    fn eq(&self, other: &HeadTail<I>) -> bool {
-        self.head.eq(&other.head)
-    }
-}
-
-impl<I> Eq for HeadTail<I>
-    where I: Iterator,
-          I::Item: Eq
-{}
-
-impl<I> PartialOrd for HeadTail<I>
-    where I: Iterator,
-          I::Item: PartialOrd
+/// Make `data` a heap (min-heap w.r.t the sorting).
+fn heapify<T, S>(data: &mut [T], mut less_than: S)
+    where S: FnMut(&T, &T) -> bool
 {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        other.head.partial_cmp(&self.head)
-    }
-
-    fn lt(&self, other: &Self) -> bool {
-        other.head.lt(&self.head)
-    }
-
-    fn le(&self, other: &Self) -> bool {
-        other.head.le(&self.head)
-    }
-
-    fn gt(&self, other: &Self) -> bool {
-        other.head.gt(&self.head)
-    }
-
-    fn ge(&self, other: &Self) -> bool {
-        other.head.ge(&self.head)
+    for i in (0..data.len() / 2).rev() {
+        sift_down(data, i, &mut less_than);
     }
 }
 
-impl<I> Ord for HeadTail<I>
-    where I: Iterator,
-          I::Item: Ord
+/// Sift down element at `index` (`heap` is a min-heap wrt the ordering)
+fn sift_down<T, S>(heap: &mut [T], index: usize, mut less_than: S)
+    where S: FnMut(&T, &T) -> bool
 {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.head.cmp(&self.head)
-    }
-}
-
-/// Make `data` a heap (max-heap w.r.t T's Ord).
-fn heapify<T: Ord>(data: &mut [T]) {
-    for i in 0..data.len() / 2 {
-        sift_down(data, i);
-    }
-}
-
-/// Sift down element at `index` (`heap` is a max-heap wrt T's Ord).
-fn sift_down<T: Ord>(heap: &mut [T], index: usize) {
     debug_assert!(index <= heap.len());
     let mut pos = index;
     let mut child = 2 * pos + 1;
@@ -133,13 +85,13 @@ fn sift_down<T: Ord>(heap: &mut [T], index: usize) {
     while pos < heap.len() && child < heap.len() {
         let right = child + 1;
 
-        // pick the bigger of the two children
-        if right < heap.len() && heap[child] < heap[right] {
+        // pick the smaller of the two children
+        if right < heap.len() && less_than(&heap[right], &heap[child]) {
             child = right;
         }
 
         // sift down is done if we are already in order
-        if heap[pos] >= heap[child] {
+        if !less_than(&heap[child], &heap[pos]) {
             return;
         }
         heap.swap(pos, child);
@@ -148,6 +100,55 @@ fn sift_down<T: Ord>(heap: &mut [T], index: usize) {
     }
 }
 
+/// Pop the least element of the heap of sequences, keeping it a heap.
+fn kmerge_next<I, S>(heap: &mut Vec<HeadTail<I>>, mut less_than: S) -> Option<I::Item>
+    where I: Iterator,
+          S: FnMut(&I::Item, &I::Item) -> bool
+{
+    if heap.is_empty() {
+        return None;
+    }
+    let result = if let Some(next) = heap[0].next() {
+        next
+    } else {
+        heap.swap_remove(0).head
+    };
+    sift_down(heap, 0, |a, b| less_than(&a.head, &b.head));
+    Some(result)
+}
+
+/// Fold the sequences in merged order; the last sequence left in the heap
+/// is folded without further comparisons.
+fn kmerge_fold<I, S, B, G>(mut heap: Vec<HeadTail<I>>, mut less_than: S, init: B, mut g: G) -> B
+    where I: Iterator,
+          S: FnMut(&I::Item, &I::Item) -> bool,
+          G: FnMut(B, I::Item) -> B
+{
+    let mut accum = init;
+    while heap.len() > 1 {
+        match kmerge_next(&mut heap, &mut less_than) {
+            Some(x) => accum = g(accum, x),
+            None => break,
+        }
+    }
+    match heap.pop() {
+        Some(HeadTail { head, tail }) => {
+            accum = g(accum, head);
+            tail.fold(accum, g)
+        }
+        None => accum,
+    }
+}
+
+fn kmerge_size_hint<I>(heap: &[HeadTail<I>]) -> (usize, Option<usize>)
+    where I: Iterator
+{
+    heap.iter()
+        .map(|i| i.size_hint())
+        .fold1(size_hint::add)
+        .unwrap_or((0, Some(0)))
+}
+
 /// An iterator adaptor that merges an abitrary number of base iterators in ascending order.
 /// If all base iterators are sorted (ascending), the result is sorted.
 ///
@@ -169,7 +170,7 @@ pub fn kmerge_new<I>(iter: I) -> KMerge<<I::Item as IntoIterator>::IntoIter>
     let (lower, _) = iter.size_hint();
     let mut heap = Vec::with_capacity(lower);
     heap.extend(iter.filter_map(|it| HeadTail::new(it.into_iter())));
-    heapify(&mut heap);
+    heapify(&mut heap, |a, b| a.head < b.head);
     KMerge { heap: heap }
 }
 
@@ -189,23 +190,74 @@ impl<I> Iterator for KMerge<I>
     type Item = I::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.heap.is_empty() {
-            return None;
-        }
-        let result = if let Some(next) = self.heap[0].next() {
-            next
-        } else {
-            self.heap.swap_remove(0).head
-        };
-        sift_down(&mut self.heap, 0);
-        Some(result)
+        kmerge_next(&mut self.heap, |a, b| a < b)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.heap.iter()
-                 .map(|i| i.size_hint())
-                 .fold1(size_hint::add)
-                 .unwrap_or((0, Some(0)))
+        kmerge_size_hint(&self.heap)
+    }
+
+    fn fold<B, G>(self, init: B, g: G) -> B
+        where G: FnMut(B, Self::Item) -> B
+    {
+        kmerge_fold(self.heap, |a, b| a < b, init, g)
     }
 }
 
+/// An iterator adaptor that merges an abitrary number of base iterators
+/// according to an ordering function.
+///
+/// Iterator element type is `I::Item`.
+///
+/// See [*.kmerge_by()*](trait.Itertools.html#method.kmerge_by) for more information.
+pub struct KMergeBy<I, F>
+    where I: Iterator
+{
+    heap: Vec<HeadTail<I>>,
+    less_than: F,
+}
+
+/// Create a `KMergeBy` iterator.
+pub fn kmerge_by_new<I, F>(iter: I, mut less_than: F)
+    -> KMergeBy<<I::Item as IntoIterator>::IntoIter, F>
+    where I: Iterator,
+          I::Item: IntoIterator,
+          F: FnMut(&<I::Item as IntoIterator>::Item, &<I::Item as IntoIterator>::Item) -> bool
+{
+    let (lower, _) = iter.size_hint();
+    let mut heap = Vec::with_capacity(lower);
+    heap.extend(iter.filter_map(|it| HeadTail::new(it.into_iter())));
+    heapify(&mut heap, |a, b| less_than(&a.head, &b.head));
+    KMergeBy { heap: heap, less_than: less_than }
+}
+
+impl<I, F> Clone for KMergeBy<I, F>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          F: Clone
+{
+    fn clone(&self) -> KMergeBy<I, F> {
+        clone_fields!(KMergeBy, self, heap, less_than)
+    }
+}
+
+impl<I, F> Iterator for KMergeBy<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item, &I::Item) -> bool
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        kmerge_next(&mut self.heap, &mut self.less_than)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        kmerge_size_hint(&self.heap)
+    }
+
+    fn fold<B, G>(self, init: B, g: G) -> B
+        where G: FnMut(B, Self::Item) -> B
+    {
+        kmerge_fold(self.heap, self.less_than, init, g)
+    }
+}
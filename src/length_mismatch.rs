@@ -0,0 +1,22 @@
+#[cfg(feature = "use_std")]
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Error returned by `Itertools::zip_eq_checked()` when the two iterators
+/// have different lengths.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LengthMismatch {
+    /// The length of the first iterator.
+    pub left: usize,
+    /// The length of the second iterator.
+    pub right: usize,
+}
+
+impl Display for LengthMismatch {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "iterators have different lengths: {} and {}", self.left, self.right)
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl Error for LengthMismatch {}
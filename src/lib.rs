@@ -5,6 +5,7 @@
                 core_intrinsics,
                 ))]
 #![crate_name="itertools"]
+#![cfg_attr(not(feature = "use_std"), no_std)]
 
 //! Itertools — extra iterator adaptors, functions and macros.
 //!
@@ -24,6 +25,25 @@
 //! #[macro_use] extern crate itertools;
 //! ```
 //!
+//! ## Crate Features
+//!
+//! - `use_std`
+//!   - Enabled by default.
+//!   - Disable to compile itertools using `#![no_std]`. This disables
+//!     any items that depend on allocation or on `std` (like `join`,
+//!     `sorted`, `group_by_lazy`, `kmerge` and `unique`).
+//! - `catch_unwind`
+//!   - Optional, implies `use_std`.
+//!   - Enables `.catch_unwind_map()`, which turns panics in a mapping
+//!     closure into error elements.
+//! - `rayon`
+//!   - Optional, implies `use_std`.
+//!   - Enables `.par_sorted()`, `.par_sorted_by()`, `.par_sorted_by_key()`
+//!     and `.par_into_group_map()`, which sort and group in parallel.
+//!   - Re-exports rayon's [`ParallelBridge`](./trait.ParallelBridge.html),
+//!     which hands any adaptor of this crate with `Send` elements, like
+//!     `.combinations()`, to rayon's thread pool.
+//!
 //! ## License
 //! Dual-licensed to be compatible with the Rust project.
 //!
@@ -35,74 +55,224 @@
 //!
 //!
 
-use std::iter::{self, IntoIterator};
+#[cfg(not(feature = "use_std"))]
+extern crate core as std;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+
+use std::iter::{self, FromIterator, IntoIterator};
+#[cfg(feature = "use_std")]
 use std::fmt::Write;
+use std::borrow::Borrow;
 use std::cmp::Ordering;
+#[cfg(feature = "use_std")]
+use std::cmp;
 use std::fmt;
+#[cfg(feature = "use_std")]
+use std::io;
+#[cfg(feature = "use_std")]
 use std::hash::Hash;
+#[cfg(feature = "use_std")]
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::iter::Sum;
+#[cfg(feature = "use_std")]
+use std::vec::IntoIter as VecIntoIter;
+#[cfg(feature = "rayon")]
+use rayon::slice::ParallelSliceMut;
+use std::ops::{Add, Mul};
 
 pub use adaptors::{
     Dedup,
+    DedupByKey,
+    RunLengthEncode,
+    RunLengthDecode,
+    EnumerateAs,
+    EnumerateIndex,
+    CollapseRuns,
+    CollapseRunsBy,
     Interleave,
     InterleaveShortest,
+    InterleaveWeighted,
     Product,
     PutBack,
-    PutBackN,
     Batching,
-    GroupBy,
     Step,
+    StepBack,
+    TakeExact,
+    CycledToLen,
+    InspectEvery,
+    ZipOffset,
+    WithChunkIndex,
+    FoldByBoundaries,
     Merge,
     MergeBy,
-    MultiPeek,
+    MergeByKey,
+    Merge3,
+    Chain3,
     TakeWhileRef,
     WhileSome,
+    WhileStep,
+    FilterMapWhile,
     Coalesce,
     MendSlices,
+    Positions,
+    Update,
+    MapInto,
+    MapOk,
+    InspectOk,
+    FilterOk,
+    FilterMapOk,
+    AndThenOk,
+    RetryOk,
+    Scan1,
+    InsertSorted,
+    Flatten,
+};
+#[cfg(feature = "use_std")]
+pub use adaptors::{
+    PutBackN,
+    GroupBy,
+    MultiPeek,
     Combinations,
     CombinationsN,
     Unique,
     UniqueBy,
-    Flatten,
+    Duplicates,
+    DuplicatesBy,
+    ChunksOverlapping,
+    FoldChunksExact,
 };
 #[cfg(feature = "unstable")]
 pub use adaptors::EnumerateFrom;
+#[cfg(feature = "arrays")]
+pub use arrays::ArrayChunks;
+#[cfg(all(feature = "arrays", feature = "use_std"))]
+pub use arrays::ArrayCombinations;
+#[cfg(feature = "use_std")]
+pub use buffered::Buffered;
+#[cfg(feature = "bytes")]
+pub use bytes::{ByteItertools, ChunkBytes, WindowsBytes};
+#[cfg(feature = "catch_unwind")]
+pub use catch_unwind_map::{CatchUnwindMap, PanicPayload};
 pub use diff::{diff_with, Diff};
-pub use format::Format;
+pub use either::Either;
+pub use exactly_one_err::ExactlyOneError;
+pub use flatten_ok::FlattenOk;
+pub use format::{Format, FormatTruncated, FormatWith};
 pub use free::{enumerate, rev};
+#[cfg(feature = "use_std")]
+pub use group_map::GroupByHash;
+#[cfg(feature = "use_std")]
+pub use grouping_map::{GroupingMap, GroupingMapBy, MapForGrouping};
+#[cfg(feature = "use_std")]
 pub use groupbylazy::{ChunksLazy, Chunk, Chunks, GroupByLazy, Group, Groups};
-pub use intersperse::Intersperse;
+pub use intersperse::{Delimited, Intersperse, IntersperseWith, IntersperseEvery};
 pub use islice::ISlice;
-pub use kmerge::KMerge;
+#[cfg(feature = "use_std")]
+pub use kmerge::{KMerge, KMergeBy};
+pub use length_mismatch::LengthMismatch;
 pub use linspace::{linspace, Linspace};
-pub use pad_tail::PadUsing;
+pub use merge_join::{Difference, InnerJoin, Intersection, LeftJoin, MergeJoinBy, OuterJoin,
+                     SymmetricDifference, Union};
+pub use minmax::{MinMaxResult, Extrema};
+pub use pad_tail::{PadUsing, PadToMultiple};
+/// Any adaptor of this crate whose elements are `Send` can be turned into a
+/// rayon parallel iterator with `.par_bridge()`.
+///
+/// ```
+/// extern crate itertools;
+/// extern crate rayon;
+///
+/// use itertools::{Itertools, ParallelBridge};
+/// use rayon::prelude::*;
+///
+/// # fn main() {
+/// let sum: u32 = (1..5u32).combinations()
+///                         .par_bridge()
+///                         .map(|(a, b)| a * b)
+///                         .sum();
+/// assert_eq!(sum, 35);
+/// # }
+/// ```
+#[cfg(feature = "rayon")]
+pub use rayon::iter::ParallelBridge;
+pub use process_results_impl::{process_results, ProcessResults};
+#[cfg(feature = "use_std")]
 pub use rciter::RcIter;
-pub use repeatn::RepeatN;
-pub use sources::{RepeatCall, Unfold};
+pub use repeatn::{repeat_n, RepeatN};
+#[cfg(feature = "use_std")]
+pub use round_robin::RoundRobin;
+pub use sources::{iterate, Iterate, repeat_call, RepeatCall, unfold, Unfold};
+#[cfg(feature = "use_std")]
+pub use sources::{indices, Indices, UnfoldMany, unfold_many};
 pub use stride::Stride;
 pub use stride::StrideMut;
+#[cfg(feature = "use_std")]
 pub use tee::Tee;
-pub use zip_longest::{ZipLongest, EitherOrBoth};
-pub use ziptuple::Zip;
+pub use tuple_impls::TupleCollect;
+#[cfg(feature = "use_std")]
+pub use tuple_impls::RefTupleWindows;
+pub use unziptuple::MultiUnzip;
+#[cfg(feature = "use_std")]
+pub use windows::Windows;
+pub use with_position::{WithPosition, Position, MapEnds};
+pub use zip_longest::{ZipLongest, ZipLongestMap, EitherOrBoth};
+pub use ziptuple::{multizip, Zip};
 #[cfg(feature = "unstable")]
 pub use ziptrusted::{ZipTrusted, TrustedIterator};
 pub use zipslices::ZipSlices;
 mod adaptors;
+#[cfg(feature = "arrays")]
+mod arrays;
+#[cfg(feature = "use_std")]
+mod buffered;
+#[cfg(feature = "bytes")]
+mod bytes;
+#[cfg(feature = "catch_unwind")]
+mod catch_unwind_map;
+mod either;
+mod exactly_one_err;
+mod flatten_ok;
 pub mod free;
+#[cfg(feature = "use_std")]
+mod group_map;
+#[cfg(feature = "use_std")]
+mod grouping_map;
 mod format;
+#[cfg(feature = "use_std")]
 mod groupbylazy;
 mod intersperse;
 mod islice;
+#[cfg(feature = "use_std")]
+mod k_smallest;
 mod diff;
+mod dot;
+#[cfg(feature = "use_std")]
 mod kmerge;
+mod length_mismatch;
 mod linspace;
+mod merge_join;
+mod minmax;
 pub mod misc;
 mod pad_tail;
+#[cfg(feature = "rayon")]
+mod par;
+mod process_results_impl;
+#[cfg(feature = "use_std")]
 mod rciter;
 mod repeatn;
+#[cfg(feature = "use_std")]
+mod round_robin;
 mod sources;
 pub mod size_hint;
 mod stride;
+#[cfg(feature = "use_std")]
 mod tee;
+mod tuple_impls;
+mod unziptuple;
+#[cfg(feature = "use_std")]
+mod windows;
+mod with_position;
 mod zip_longest;
 mod ziptuple;
 #[cfg(feature = "unstable")]
@@ -233,6 +403,56 @@ pub trait Itertools : Iterator {
         InterleaveShortest::new(self, other.into_iter())
     }
 
+    /// Alternate runs of elements from two iterators: `weight_self` elements
+    /// from `self`, then `weight_other` from `other`, and so on until both
+    /// run out.
+    ///
+    /// When one of the iterators runs out, the rest of the other follows.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// **Panics** if either weight is zero.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // mix three parts of `a` with one part of `b`
+    /// let it = "aaaaaaa".chars().interleave_weighted("bbbb".chars(), 3, 1);
+    /// itertools::assert_equal(it, "aaabaaababb".chars());
+    /// ```
+    fn interleave_weighted<J>(self, other: J, weight_self: usize, weight_other: usize)
+        -> InterleaveWeighted<Self, J::IntoIter>
+        where J: IntoIterator<Item = Self::Item>,
+              Self: Sized
+    {
+        InterleaveWeighted::new(self, other.into_iter(), weight_self, weight_other)
+    }
+
+    /// Return an iterator adaptor that flattens an iterator of iterators by
+    /// taking one element from each of them in turn.
+    ///
+    /// Base iterators that run out are skipped from then on; the adaptor
+    /// ends when all of them are exhausted. All base iterators are
+    /// collected into a vector when the adaptor is created.
+    ///
+    /// Iterator element type is `<Self::Item as IntoIterator>::Item`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = vec![vec![1, 2, 3], vec![4], vec![5, 6]].into_iter().round_robin();
+    /// itertools::assert_equal(it, vec![1, 4, 5, 2, 6, 3]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn round_robin(self) -> RoundRobin<<Self::Item as IntoIterator>::IntoIter>
+        where Self: Sized,
+              Self::Item: IntoIterator
+    {
+        round_robin::round_robin_new(self)
+    }
+
     /// An iterator adaptor to insert a particular value
     /// between each element of the adapted iterator.
     ///
@@ -252,6 +472,81 @@ pub trait Itertools : Iterator {
         Intersperse::new(self, element)
     }
 
+    /// An iterator adaptor to insert a value returned by the closure `f`
+    /// between each element of the adapted iterator.
+    ///
+    /// Use this instead of `.intersperse()` when the separator is not
+    /// `Clone` or is expensive to build up front; `f` is called once
+    /// for each separator.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut sep = 10;
+    /// let it = (0..3).intersperse_with(|| { sep += 1; sep });
+    /// itertools::assert_equal(it, vec![0, 11, 1, 12, 2]);
+    /// ```
+    fn intersperse_with<F>(self, f: F) -> IntersperseWith<Self, F>
+        where Self: Sized,
+              F: FnMut() -> Self::Item
+    {
+        IntersperseWith::new(self, f)
+    }
+
+    /// An iterator adaptor to insert a particular value after every `n`
+    /// elements of the adapted iterator.
+    ///
+    /// The value is only inserted between elements, never after the last
+    /// one; `.intersperse(x)` is the same as `.intersperse_every(1, x)`.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// **Panics** if `n` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let grouped = "1234567".chars().intersperse_every(3, ',').collect::<String>();
+    /// assert_eq!(grouped, "123,456,7");
+    /// ```
+    fn intersperse_every(self, n: usize, element: Self::Item) -> IntersperseEvery<Self>
+        where Self: Sized,
+              Self::Item: Clone
+    {
+        IntersperseEvery::new(self, n, element)
+    }
+
+    /// An iterator adaptor that frames the adapted iterator: it yields
+    /// `prefix`, then the elements with `sep` between each, then `suffix`.
+    ///
+    /// The prefix and suffix are yielded even if the iterator is empty.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let list = vec!["a", "b", "c"].into_iter().delimited("[", ", ", "]");
+    /// assert_eq!(list.collect::<String>(), "[a, b, c]");
+    ///
+    /// itertools::assert_equal((0..0).delimited(-1, 0, 1), vec![-1, 1]);
+    /// ```
+    fn delimited(self, prefix: Self::Item, sep: Self::Item, suffix: Self::Item)
+        -> Delimited<Self>
+        where Self: Sized,
+              Self::Item: Clone
+    {
+        Delimited::new(self, prefix, sep, suffix)
+    }
+
     /// Create an iterator which iterates over both this and the specified
     /// iterator simultaneously, yielding pairs of two optional elements.
     ///
@@ -277,6 +572,68 @@ pub trait Itertools : Iterator {
         ZipLongest::new(self, other.into_iter())
     }
 
+    /// Iterate `self` and `other` together like `.zip_longest()`, and map
+    /// each step with one of three closures: `on_both` while both iterators
+    /// have elements, then `on_left` or `on_right` for the rest of the
+    /// longer one.
+    ///
+    /// Iterator element type is the common return type `R` of the closures.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // add two polynomials given by their coefficients
+    /// let p = vec![1, 2, 3];
+    /// let q = vec![10, 20];
+    /// let sum = p.into_iter().zip_longest_map(q, |a, b| a + b, |a| a, |b| b);
+    /// itertools::assert_equal(sum, vec![11, 22, 3]);
+    /// ```
+    fn zip_longest_map<J, FB, FL, FR, R>(self, other: J, on_both: FB, on_left: FL, on_right: FR)
+        -> ZipLongestMap<Self, J::IntoIter, FB, FL, FR>
+        where J: IntoIterator,
+              Self: Sized,
+              FB: FnMut(Self::Item, J::Item) -> R,
+              FL: FnMut(Self::Item) -> R,
+              FR: FnMut(J::Item) -> R
+    {
+        zip_longest::zip_longest_map(self, other.into_iter(), on_both, on_left, on_right)
+    }
+
+    /// Zip `self` with `other` after checking that both have the same length.
+    ///
+    /// The lengths are compared before any element is consumed. If they
+    /// differ, both are returned in a `LengthMismatch` error instead of an
+    /// iterator.
+    ///
+    /// Iterator element type is `(Self::Item, J::Item)`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let names = ["x", "y", "z"];
+    /// let values = vec![1, 2, 3];
+    /// let pairs = names.iter().zip_eq_checked(&values).unwrap().collect_vec();
+    /// assert_eq!(pairs, [(&"x", &1), (&"y", &2), (&"z", &3)]);
+    ///
+    /// let err = names.iter().zip_eq_checked(&values[1..]).unwrap_err();
+    /// assert_eq!((err.left, err.right), (3, 2));
+    /// assert_eq!(err.to_string(), "iterators have different lengths: 3 and 2");
+    /// ```
+    fn zip_eq_checked<J>(self, other: J) -> Result<iter::Zip<Self, J::IntoIter>, LengthMismatch>
+        where Self: Sized + ExactSizeIterator,
+              J: IntoIterator,
+              J::IntoIter: ExactSizeIterator,
+    {
+        let other = other.into_iter();
+        if self.len() == other.len() {
+            Ok(self.zip(other))
+        } else {
+            Err(LengthMismatch { left: self.len(), right: other.len() })
+        }
+    }
+
     /// A “meta iterator adaptor”. Its closure recives a reference to the iterator
     /// and may pick off as many elements as it likes, to produce the next iterator element.
     ///
@@ -309,8 +666,14 @@ pub trait Itertools : Iterator {
     /// Group iterator elements. Consecutive elements that map to the same key (“runs”),
     /// are returned as the iterator elements of `GroupBy`.
     ///
+    /// Each group is collected into an owned vector, so the groups can be
+    /// used like any other iterator elements. Use `.group_by_lazy()` to
+    /// avoid the allocations.
+    ///
     /// Iterator element type is `(K, Vec<Self::Item>)`
     ///
+    /// This iterator is *fused*.
+    ///
     /// ```
     /// use itertools::Itertools;
     ///
@@ -323,6 +686,7 @@ pub trait Itertools : Iterator {
     ///     assert_eq!(4, group.iter().fold(0_i32, |a, b| a + b).abs());
     /// }
     /// ```
+    #[cfg(feature = "use_std")]
     fn group_by<K, F>(self, key: F) -> GroupBy<K, Self, F>
         where Self: Sized,
               F: FnMut(&Self::Item) -> K,
@@ -330,6 +694,34 @@ pub trait Itertools : Iterator {
         GroupBy::new(self, key)
     }
 
+    /// Group consecutive elements that map to the same key into owned
+    /// `(key, Vec)` pairs, one per run.
+    ///
+    /// This is the same adaptor as [*.group_by()*](#method.group_by), under
+    /// the name that says it only groups adjacent elements; see
+    /// [*.into_group_map()*](#method.into_group_map) to group equal keys
+    /// wherever they occur.
+    ///
+    /// Iterator element type is `(K, Vec<Self::Item>)`
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let words = vec!["apple", "avocado", "banana", "apricot"];
+    /// itertools::assert_equal(words.into_iter().group_consecutive(|w| w.as_bytes()[0]),
+    ///                         vec![(b'a', vec!["apple", "avocado"]),
+    ///                              (b'b', vec!["banana"]),
+    ///                              (b'a', vec!["apricot"])]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn group_consecutive<K, F>(self, key: F) -> GroupBy<K, Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> K,
+    {
+        GroupBy::new(self, key)
+    }
 
     /// Return an iterable that can group iterator elements.
     /// Consecutive elements that map to the same key (“runs”), are assigned
@@ -364,6 +756,7 @@ pub trait Itertools : Iterator {
     ///     assert_eq!(4, group.fold(0_i32, |a, b| a + b).abs());
     /// }
     /// ```
+    #[cfg(feature = "use_std")]
     fn group_by_lazy<K, F>(self, key: F) -> GroupByLazy<K, Self, F>
         where Self: Sized,
               F: FnMut(&Self::Item) -> K,
@@ -371,6 +764,35 @@ pub trait Itertools : Iterator {
         groupbylazy::new(self, key)
     }
 
+    /// Like `.group_by_lazy()`, but never buffer more than `max` elements.
+    ///
+    /// Elements are only buffered when a later group is requested before an
+    /// earlier one is exhausted (or dropped). Bounding that buffer protects
+    /// against consumption patterns that would otherwise hold on to an
+    /// unbounded part of the input.
+    ///
+    /// **Panics** when buffering one more element would exceed `max`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1, 1, 2, 2, 2, 3];
+    /// let groups = data.into_iter().group_by_lazy_bounded(2, |elt| *elt);
+    /// let mut iter = groups.into_iter();
+    /// let (_, mut ones) = iter.next().unwrap();
+    /// // move on to the twos, buffering the rest of the ones
+    /// let (_, twos) = iter.next().unwrap();
+    /// assert_eq!(twos.collect::<Vec<_>>(), vec![2, 2, 2]);
+    /// assert_eq!(ones.next(), Some(1));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn group_by_lazy_bounded<K, F>(self, max: usize, key: F) -> GroupByLazy<K, Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> K,
+    {
+        groupbylazy::new_bounded(self, max, key)
+    }
+
     /// Return an iterable that can chunk the iterator.
     ///
     /// Yield subiterators (chunks) that each yield a fixed number elements,
@@ -398,6 +820,7 @@ pub trait Itertools : Iterator {
     ///     assert_eq!(4, chunk.fold(0_i32, |a, b| a + b));
     /// }
     /// ```
+    #[cfg(feature = "use_std")]
     fn chunks_lazy(self, size: usize) -> ChunksLazy<Self>
         where Self: Sized,
     {
@@ -405,6 +828,174 @@ pub trait Itertools : Iterator {
         groupbylazy::new_chunks(self, size)
     }
 
+    /// Like `.chunks_lazy()`, but never buffer more than `max` elements.
+    ///
+    /// **Panics** if `size` is 0, or when buffering one more element would
+    /// exceed `max`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let chunks = (0..6).chunks_lazy_bounded(2, 3);
+    /// for chunk in &chunks {
+    ///     // consumed in order: nothing is buffered
+    ///     assert_eq!(chunk.count(), 2);
+    /// }
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn chunks_lazy_bounded(self, size: usize, max: usize) -> ChunksLazy<Self>
+        where Self: Sized,
+    {
+        assert!(size != 0);
+        groupbylazy::new_chunks_bounded(self, size, max)
+    }
+
+    /// Return an iterator adaptor that yields non-overlapping chunks of `N`
+    /// elements as arrays.
+    ///
+    /// Unlike `.chunks_lazy()`, nothing is allocated. If the number of
+    /// elements is not a multiple of `N`, the last, incomplete chunk is not
+    /// yielded: use `.into_remainder()` on the adaptor to get its elements.
+    ///
+    /// This method is only available with the crate feature `arrays`.
+    ///
+    /// Iterator element type is `[Self::Item; N]`.
+    ///
+    /// **Panics** if `N` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // records of a 2-byte id and a 1-byte flag
+    /// let data = [0u8, 1, 1, 0, 2, 0, 0, 3];
+    /// let mut records = data.iter().cloned().array_chunks();
+    /// for [hi, lo, flag] in &mut records {
+    ///     assert!(u16::from_be_bytes([hi, lo]) <= 2 && flag <= 1);
+    /// }
+    /// itertools::assert_equal(records.into_remainder(), vec![0, 3]);
+    /// ```
+    #[cfg(feature = "arrays")]
+    fn array_chunks<const N: usize>(self) -> ArrayChunks<Self, N>
+        where Self: Sized,
+    {
+        arrays::array_chunks(self)
+    }
+
+    /// Return an iterator adaptor that pairs each element with the index
+    /// of the chunk of `n` elements it belongs to, without grouping them.
+    ///
+    /// The element at position `i` gets the chunk index `i / n`, the same
+    /// numbering `.chunks_lazy(n)` uses.
+    ///
+    /// Iterator element type is `(usize, Self::Item)`.
+    ///
+    /// **Panics** if `n` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = "abcde".chars().with_chunk_index(2);
+    /// itertools::assert_equal(it, vec![(0, 'a'), (0, 'b'), (1, 'c'), (1, 'd'), (2, 'e')]);
+    /// ```
+    fn with_chunk_index(self, n: usize) -> WithChunkIndex<Self>
+        where Self: Sized,
+    {
+        WithChunkIndex::new(self, n)
+    }
+
+    /// Return an iterator adaptor that yields chunks of `size` elements as
+    /// `Vec`s, where each chunk repeats the last `overlap` elements of the
+    /// previous one.
+    ///
+    /// Consecutive chunks start `size - overlap` elements apart. The last
+    /// chunk may be shorter than `size`; a chunk is only produced if it
+    /// contains elements that were not in the previous one.
+    ///
+    /// **Panics** if `overlap >= size`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let frames = (1..10).chunks_overlapping(4, 2);
+    /// itertools::assert_equal(frames, vec![vec![1, 2, 3, 4],
+    ///                                      vec![3, 4, 5, 6],
+    ///                                      vec![5, 6, 7, 8],
+    ///                                      vec![7, 8, 9]]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn chunks_overlapping(self, size: usize, overlap: usize) -> ChunksOverlapping<Self>
+        where Self: Sized,
+              Self::Item: Clone
+    {
+        adaptors::chunks_overlapping(self, size, overlap)
+    }
+
+    /// Return an iterator adaptor that folds each complete chunk of `n`
+    /// elements, and yields the result for every chunk.
+    ///
+    /// Each chunk starts from the accumulator `init()` and feeds the elements
+    /// through `f`. An incomplete chunk at the end is not folded: use
+    /// `.into_remainder()` on the adaptor to get its elements back. While the
+    /// iterator's lower size hint covers a whole chunk, the chunk is folded
+    /// straight from it; otherwise its elements are buffered until the chunk
+    /// is complete.
+    ///
+    /// Iterator element type is `B`.
+    ///
+    /// **Panics** if `n` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = [1u8, 2, 3, 4, 5, 6, 7, 8];
+    /// let mut sums = data.iter().fold_chunks_exact(3, || 0, |acc, &x| acc + x);
+    /// assert_eq!(sums.next(), Some(6));
+    /// assert_eq!(sums.next(), Some(15));
+    /// assert_eq!(sums.next(), None);
+    /// itertools::assert_equal(sums.into_remainder(), &[7, 8]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn fold_chunks_exact<B, G, F>(self, n: usize, init: G, f: F) -> FoldChunksExact<Self, G, F>
+        where Self: Sized,
+              G: FnMut() -> B,
+              F: FnMut(B, Self::Item) -> B,
+    {
+        FoldChunksExact::new(self, n, init, f)
+    }
+
+    /// Return an iterator adaptor that folds the elements into buckets
+    /// between consecutive `boundaries`, and yields one aggregate per bucket.
+    ///
+    /// Both the elements and the boundaries must be sorted in ascending
+    /// order. For each boundary `b`, the adaptor folds the next elements that
+    /// are less than `b`, starting from `init()`, so an empty bucket yields
+    /// `init()`. Once the boundaries run out, all remaining elements are
+    /// folded into one last bucket, if there are any.
+    ///
+    /// The elements are folded as they are read, so this works on unbounded
+    /// iterators too.
+    ///
+    /// Iterator element type is `B`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let latencies_ms = vec![1, 3, 4, 12, 15, 70, 140];
+    /// // how many requests took less than 10, 10 to 50, 50 to 100, and 100+ ms
+    /// let histogram = latencies_ms.into_iter()
+    ///     .fold_by_boundaries(vec![10, 50, 100], || 0, |n, _| n + 1);
+    /// itertools::assert_equal(histogram, vec![3, 2, 1, 1]);
+    /// ```
+    fn fold_by_boundaries<J, B, G, F>(self, boundaries: J, init: G, f: F)
+        -> FoldByBoundaries<Self, J::IntoIter, G, F>
+        where Self: Sized,
+              J: IntoIterator,
+              Self::Item: PartialOrd<J::Item>,
+              G: FnMut() -> B,
+              F: FnMut(B, Self::Item) -> B,
+    {
+        adaptors::fold_by_boundaries(self, boundaries.into_iter(), init, f)
+    }
 
     /// Split into an iterator pair that both yield all elements from
     /// the original iterator.
@@ -427,6 +1018,7 @@ pub trait Itertools : Iterator {
     /// assert_eq!(t1.next(), None);
     /// assert_eq!(t2.next(), Some(1));
     /// ```
+    #[cfg(feature = "use_std")]
     fn tee(self) -> (Tee<Self>, Tee<Self>)
         where Self: Sized,
               Self::Item: Clone
@@ -434,6 +1026,35 @@ pub trait Itertools : Iterator {
         tee::new(self)
     }
 
+    /// Like `.tee()`, but the backlog of elements read by one half and not
+    /// yet by the other never holds more than `max` elements.
+    ///
+    /// This keeps one half from running arbitrarily far ahead of the other
+    /// and buffering an unbounded part of the input.
+    ///
+    /// **Panics** when the leading half reads an element that would grow
+    /// the backlog beyond `max`.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let (mut t1, mut t2) = (0..10).tee_bounded(2);
+    /// assert_eq!(t1.next(), Some(0));
+    /// assert_eq!(t1.next(), Some(1));
+    /// // t1 may not get further ahead before t2 catches up
+    /// assert_eq!(t2.next(), Some(0));
+    /// assert_eq!(t1.next(), Some(2));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn tee_bounded(self, max: usize) -> (Tee<Self>, Tee<Self>)
+        where Self: Sized,
+              Self::Item: Clone
+    {
+        tee::new_bounded(self, max)
+    }
+
     /// Return a sliced iterator.
     ///
     /// **Note:** slicing an iterator is not constant time, and much less efficient than
@@ -482,27 +1103,61 @@ pub trait Itertools : Iterator {
     /// **Panics** in iterator methods if a borrow error is encountered,
     /// but it can only happen if the `RcIter` is reentered in for example `.next()`,
     /// i.e. if it somehow participates in an “iterator knot” where it is an adaptor of itself.
+    #[cfg(feature = "use_std")]
     fn into_rc(self) -> RcIter<Self>
         where Self: Sized
     {
         RcIter::new(self)
     }
 
-    /// Return an iterator adaptor that steps `n` elements in the base iterator
-    /// for each iteration.
+    /// Return an iterator adaptor that yields exactly `n` elements of the
+    /// base iterator.
     ///
-    /// The iterator steps by yielding the next element from the base iterator,
-    /// then skipping forward `n - 1` elements.
+    /// Like `.take(n)`, except that ending early is an error rather than
+    /// being silently truncated.
     ///
     /// Iterator element type is `Self::Item`.
     ///
-    /// **Panics** if the step is 0.
+    /// **Panics** during iteration if the base iterator has fewer than `n`
+    /// elements.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let it = (0..8).step(3);
-    /// itertools::assert_equal(it, vec![0, 3, 6]);
+    /// let mut record = vec![7, 1, 2, 3, 9].into_iter();
+    /// let header = record.by_ref().take_exact(1).collect::<Vec<_>>();
+    /// let body = record.by_ref().take_exact(3).collect::<Vec<_>>();
+    /// assert_eq!(header, vec![7]);
+    /// assert_eq!(body, vec![1, 2, 3]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use itertools::Itertools;
+    ///
+    /// // panics: only 3 elements available
+    /// (0..3).take_exact(4).count();
+    /// ```
+    fn take_exact(self, n: usize) -> TakeExact<Self>
+        where Self: Sized
+    {
+        TakeExact::new(self, n)
+    }
+
+    /// Return an iterator adaptor that steps `n` elements in the base iterator
+    /// for each iteration.
+    ///
+    /// The iterator steps by yielding the next element from the base iterator,
+    /// then skipping forward `n - 1` elements.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// **Panics** if the step is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..8).step(3);
+    /// itertools::assert_equal(it, vec![0, 3, 6]);
     /// ```
     fn step(self, n: usize) -> Step<Self>
         where Self: Sized
@@ -510,6 +1165,106 @@ pub trait Itertools : Iterator {
         Step::new(self, n)
     }
 
+    /// Return an iterator adaptor that yields every `n`-th element counted
+    /// from the end, starting with the last element and moving towards the
+    /// front.
+    ///
+    /// The selected elements are aligned to the back rather than to the
+    /// front, so the last element is always included. Use `.rev()` to get
+    /// them in their original order.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// **Panics** if the step is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..8).step_back(3);
+    /// itertools::assert_equal(it, vec![7, 4, 1]);
+    ///
+    /// let it = (0..8).step_back(3).rev();
+    /// itertools::assert_equal(it, vec![1, 4, 7]);
+    /// ```
+    fn step_back(self, n: usize) -> StepBack<Self>
+        where Self: Sized + DoubleEndedIterator + ExactSizeIterator
+    {
+        StepBack::new(self, n)
+    }
+
+    /// Return an iterator adaptor that repeats the iterator until exactly
+    /// `total` elements have been produced, possibly ending in the middle
+    /// of a cycle.
+    ///
+    /// If the iterator is empty, the resulting iterator is empty too.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..3).cycled_to_len(7);
+    /// assert_eq!(it.len(), 7);
+    /// itertools::assert_equal(it, vec![0, 1, 2, 0, 1, 2, 0]);
+    /// ```
+    fn cycled_to_len(self, total: usize) -> CycledToLen<Self>
+        where Self: Sized + Clone
+    {
+        CycledToLen::new(self, total)
+    }
+
+    /// Return an iterator adaptor that calls the closure `f` with the index
+    /// and a reference to every `n`-th element, starting with the first,
+    /// and passes all elements on unchanged.
+    ///
+    /// The inspected elements are the ones `.step(n)` would yield.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// **Panics** if `n` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut progress = Vec::new();
+    /// let sum = (0..1000).inspect_every(250, |i, _| progress.push(i))
+    ///                    .fold(0, |a, b| a + b);
+    /// assert_eq!(sum, 499500);
+    /// assert_eq!(progress, vec![0, 250, 500, 750]);
+    /// ```
+    fn inspect_every<F>(self, n: usize, f: F) -> InspectEvery<Self, F>
+        where Self: Sized,
+              F: FnMut(usize, &Self::Item)
+    {
+        InspectEvery::new(self, n, f)
+    }
+
+    /// Return an iterator adaptor that yields pairs of each element and
+    /// the element `lag` places after it: `(x[i], x[i + lag])`.
+    ///
+    /// The iterator is cloned, and the clone advanced by `lag` elements, so
+    /// nothing is collected. With `lag` 1 this yields overlapping pairs of
+    /// consecutive elements.
+    ///
+    /// Iterator element type is `(Self::Item, Self::Item)`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1, 2, 4, 8, 16];
+    /// itertools::assert_equal(data.iter().zip_offset(2),
+    ///                         vec![(&1, &4), (&2, &8), (&4, &16)]);
+    ///
+    /// // lag-1 autocovariance style sum
+    /// let s: i32 = data.iter().zip_offset(1).map(|(a, b)| a * b).sum();
+    /// assert_eq!(s, 2 + 8 + 32 + 128);
+    /// ```
+    fn zip_offset(self, lag: usize) -> ZipOffset<Self>
+        where Self: Sized + Clone
+    {
+        ZipOffset::new(self, lag)
+    }
+
     /// Return an iterator adaptor that merges the two base iterators in ascending order.
     /// If both base iterators are sorted (ascending), the result is sorted.
     ///
@@ -555,6 +1310,208 @@ pub trait Itertools : Iterator {
         adaptors::merge_by_new(self, other.into_iter(), is_first)
     }
 
+    /// Return an iterator adaptor that merges the two base iterators in
+    /// ascending order of the key computed by `key`.
+    ///
+    /// If both base iterators are sorted by that key, the result is sorted by
+    /// it too. The merge is stable: when two elements have equal keys, the
+    /// one from `self` comes first.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = vec![(1, "a1"), (3, "a3"), (3, "a3'")];
+    /// let b = vec![(2, "b2"), (3, "b3")];
+    /// let it = a.into_iter().merge_by_key(b, |x| x.0).map(|x| x.1);
+    /// itertools::assert_equal(it, vec!["a1", "b2", "a3", "a3'", "b3"]);
+    /// ```
+    fn merge_by_key<J, F, K>(self, other: J, key: F) -> MergeByKey<Self, J::IntoIter, F>
+        where Self: Sized,
+              J: IntoIterator<Item = Self::Item>,
+              F: FnMut(&Self::Item) -> K,
+              K: PartialOrd
+    {
+        adaptors::merge_by_key_new(self, other.into_iter(), key)
+    }
+
+    /// Return an iterator adaptor that inserts `value` into the sorted
+    /// (ascending) base iterator, at its sorted position.
+    ///
+    /// `value` is produced right before the first element that is greater
+    /// than it, so it comes after any elements equal to it. If there is no
+    /// such element, it is produced last.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// itertools::assert_equal(vec![1, 3, 5, 7].into_iter().insert_sorted(4),
+    ///                         vec![1, 3, 4, 5, 7]);
+    /// itertools::assert_equal(vec![1, 3].into_iter().insert_sorted(9),
+    ///                         vec![1, 3, 9]);
+    /// ```
+    fn insert_sorted(self, value: Self::Item) -> InsertSorted<Self>
+        where Self: Sized,
+              Self::Item: PartialOrd
+    {
+        adaptors::insert_sorted(self, value)
+    }
+
+    /// Create an iterator that merges items from both this and the specified
+    /// iterator in ascending order.
+    ///
+    /// It chooses whether to pair elements based on the `Ordering` returned by the
+    /// specified compare function. At any point, inspecting the tip of the
+    /// iterators `I` and `J` as items `i` of type `I::Item` and `j` of type
+    /// `J::Item` respectively, the resulting iterator will:
+    ///
+    /// - Emit `EitherOrBoth::Left(i)` when `i < j`,
+    ///   and remove `i` from its source iterator
+    /// - Emit `EitherOrBoth::Right(j)` when `i > j`,
+    ///   and remove `j` from its source iterator
+    /// - Emit `EitherOrBoth::Both(i, j)` when  `i == j`,
+    ///   and remove both `i` and `j` from their respective source iterators
+    ///
+    /// If both base iterators are sorted (ascending), the result is sorted.
+    ///
+    /// Iterator element type is
+    /// [`EitherOrBoth<Self::Item, J::Item>`](enum.EitherOrBoth.html).
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::EitherOrBoth::{Left, Right, Both};
+    ///
+    /// let ki = (0..10).step(3);
+    /// let ku = (0..10).step(5);
+    /// let ki_ku = ki.merge_join_by(ku, |i, j| i.cmp(j)).map(|either| {
+    ///     match either {
+    ///         Left(_) => "Ki",
+    ///         Right(_) => "Ku",
+    ///         Both(_, _) => "KiKu"
+    ///     }
+    /// });
+    ///
+    /// itertools::assert_equal(ki_ku, vec!["KiKu", "Ki", "Ku", "Ki", "Ki"]);
+    /// ```
+    #[inline]
+    fn merge_join_by<J, F>(self, other: J, cmp_fn: F) -> MergeJoinBy<Self, J::IntoIter, F>
+        where J: IntoIterator,
+              F: FnMut(&Self::Item, &J::Item) -> Ordering,
+              Self: Sized
+    {
+        merge_join::merge_join_by(self, other, cmp_fn)
+    }
+
+    /// Return an iterator adaptor that yields the union of this iterator and
+    /// `other`, both of which must be sorted in ascending order.
+    ///
+    /// The sequences are treated as multisets: an element that occurs `m`
+    /// times in `self` and `n` times in `other` is yielded `max(m, n)`
+    /// times. Of two equal elements, the one from `self` is yielded.
+    ///
+    /// Nothing is hashed or buffered; if the inputs are not sorted, the
+    /// result is unspecified.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = vec![1, 2, 2, 4, 7];
+    /// let b = vec![2, 3, 4, 8];
+    /// itertools::assert_equal(a.into_iter().union(b), vec![1, 2, 2, 3, 4, 7, 8]);
+    /// ```
+    fn union<J>(self, other: J) -> Union<Self, J::IntoIter>
+        where J: IntoIterator<Item = Self::Item>,
+              Self::Item: Ord,
+              Self: Sized
+    {
+        merge_join::union(self, other.into_iter())
+    }
+
+    /// Return an iterator adaptor that yields the intersection of this
+    /// iterator and `other`, both of which must be sorted in ascending order.
+    ///
+    /// An element that occurs `m` times in `self` and `n` times in `other`
+    /// is yielded `min(m, n)` times, taken from `self`.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = vec![1, 2, 2, 4, 7];
+    /// let b = vec![2, 3, 4, 8];
+    /// itertools::assert_equal(a.into_iter().intersection(b), vec![2, 4]);
+    /// ```
+    fn intersection<J>(self, other: J) -> Intersection<Self, J::IntoIter>
+        where J: IntoIterator<Item = Self::Item>,
+              Self::Item: Ord,
+              Self: Sized
+    {
+        merge_join::intersection(self, other.into_iter())
+    }
+
+    /// Return an iterator adaptor that yields the elements of this iterator
+    /// that are not in `other`; both must be sorted in ascending order.
+    ///
+    /// An element that occurs `m` times in `self` and `n` times in `other`
+    /// is yielded `m - n` times if `m > n`, and not at all otherwise.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = vec![1, 2, 2, 4, 7];
+    /// let b = vec![2, 3, 4, 8];
+    /// itertools::assert_equal(a.into_iter().difference(b), vec![1, 2, 7]);
+    /// ```
+    fn difference<J>(self, other: J) -> Difference<Self, J::IntoIter>
+        where J: IntoIterator<Item = Self::Item>,
+              Self::Item: Ord,
+              Self: Sized
+    {
+        merge_join::difference(self, other.into_iter())
+    }
+
+    /// Return an iterator adaptor that yields the elements that are in
+    /// exactly one of this iterator and `other`, both of which must be
+    /// sorted in ascending order.
+    ///
+    /// An element that occurs `m` times in `self` and `n` times in `other`
+    /// is yielded `|m - n|` times, from whichever side has more.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = vec![1, 2, 2, 4, 7];
+    /// let b = vec![2, 3, 4, 8];
+    /// itertools::assert_equal(a.into_iter().symmetric_difference(b), vec![1, 2, 3, 7, 8]);
+    /// ```
+    fn symmetric_difference<J>(self, other: J) -> SymmetricDifference<Self, J::IntoIter>
+        where J: IntoIterator<Item = Self::Item>,
+              Self::Item: Ord,
+              Self: Sized
+    {
+        merge_join::symmetric_difference(self, other.into_iter())
+    }
+
     /// Return an iterator adaptor that flattens an iterator of iterators by
     /// merging them in ascending order.
     ///
@@ -571,6 +1528,7 @@ pub trait Itertools : Iterator {
     /// let it = vec![a, b, c].into_iter().kmerge();
     /// itertools::assert_equal(it, vec![0, 1, 2, 3, 4, 5]);
     /// ```
+    #[cfg(feature = "use_std")]
     fn kmerge(self) -> KMerge<<<Self as Iterator>::Item as IntoIterator>::IntoIter> where
         Self: Sized,
         Self::Item: IntoIterator,
@@ -579,6 +1537,37 @@ pub trait Itertools : Iterator {
         kmerge::kmerge_new(self)
     }
 
+    /// Return an iterator adaptor that flattens an iterator of iterators by
+    /// merging them according to the given closure.
+    ///
+    /// The closure `first` is called with two elements *a*, *b* and should
+    /// return `true` if *a* is ordered before *b*.
+    ///
+    /// If all base iterators are sorted according to `first`, the result is
+    /// sorted.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = vec![-1f64, 2., 3., -5., 6., -7.];
+    /// let b = vec![0., 2., -4.];
+    /// let mut it = vec![a, b].into_iter().kmerge_by(|a, b| a.abs() < b.abs());
+    /// assert_eq!(it.next(), Some(0.));
+    /// assert_eq!(it.last(), Some(-7.));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn kmerge_by<F>(self, first: F)
+        -> KMergeBy<<<Self as Iterator>::Item as IntoIterator>::IntoIter, F>
+        where Self: Sized,
+              Self::Item: IntoIterator,
+              F: FnMut(&<Self::Item as IntoIterator>::Item,
+                       &<Self::Item as IntoIterator>::Item) -> bool
+    {
+        kmerge::kmerge_by_new(self, first)
+    }
+
     /// Return an iterator adaptor that iterates over the cartesian product of
     /// the element sets of two iterators `self` and `J`.
     ///
@@ -599,6 +1588,47 @@ pub trait Itertools : Iterator {
         Product::new(self, other.into_iter())
     }
 
+    /// Return an iterator adaptor that enumerates the iterator elements with
+    /// indices of the integer type `T`, starting from zero.
+    ///
+    /// Unlike `.enumerate()`, the index does not silently wrap around: the
+    /// adaptor **panics** if an element's index does not fit in `T`.
+    ///
+    /// Iterator element type is `(T, Self::Item)`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = "abc".chars().enumerate_as::<u8>();
+    /// itertools::assert_equal(it, vec![(0, 'a'), (1, 'b'), (2, 'c')]);
+    /// ```
+    fn enumerate_as<T>(self) -> EnumerateAs<Self, T>
+        where Self: Sized,
+              T: EnumerateIndex
+    {
+        adaptors::enumerate_as(self)
+    }
+
+    /// Return an iterator adaptor that enumerates the iterator elements with
+    /// `u64` indices.
+    ///
+    /// This is `.enumerate_as::<u64>()`: on targets where `usize` is 32 bits,
+    /// it can count the elements of streams longer than `usize::MAX`.
+    ///
+    /// Iterator element type is `(u64, Self::Item)`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let (index, _) = (0..10).enumerate_u64().last().unwrap();
+    /// assert_eq!(index, 9u64);
+    /// ```
+    fn enumerate_u64(self) -> EnumerateAs<Self, u64>
+        where Self: Sized
+    {
+        self.enumerate_as()
+    }
+
     /// Return an iterator adaptor that enumerates the iterator elements,
     /// starting from `start` and incrementing by one.
     ///
@@ -634,72 +1664,318 @@ pub trait Itertools : Iterator {
     /// assert_eq!(peekable.next(), Some(1));
     /// assert_eq!(peekable.peek(), Some(&2));
     /// ```
+    #[cfg(feature = "use_std")]
     fn multipeek(self) -> MultiPeek<Self>
         where Self: Sized
     {
         MultiPeek::new(self)
     }
 
-    /// Return an iterator adaptor that uses the passed-in closure to
-    /// optionally merge together consecutive elements. For each pair the closure
-    /// is passed the latest two elements, `x`, `y` and may return either `Ok(z)`
-    /// to merge the two values or `Err((x, y))` to indicate they can't be merged.
+    /// Return an iterator adaptor that pulls up to `n` elements ahead of
+    /// time from the adapted iterator, and yields them in order.
     ///
-    /// `.dedup()` and `.mend_slices()` are specializations of the coalesce
-    /// adaptor.
+    /// On each call to `.next()` the internal buffer is topped up to `n`
+    /// elements before the front one is returned. Use `.fill_level()` to
+    /// see how far ahead of the consumer the adapted iterator is.
     ///
     /// Iterator element type is `Self::Item`.
     ///
     /// This iterator is *fused*.
     ///
+    /// **Panics** if `n` is 0.
+    ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// // sum same-sign runs together
-    /// let data = vec![-1., -2., -3., 3., 1., 0., -1.];
-    /// itertools::assert_equal(data.into_iter().coalesce(|x, y|
-    ///         if (x >= 0.) == (y >= 0.) {
-    ///             Ok(x + y)
-    ///         } else {
-    ///             Err((x, y))
-    ///         }),
-    ///         vec![-6., 4., -1.]);
+    /// let mut it = (0..5).buffered(3);
+    /// assert_eq!(it.fill_level(), 0);
+    /// assert_eq!(it.next(), Some(0));
+    /// assert_eq!(it.fill_level(), 2);
+    /// itertools::assert_equal(it.by_ref().take(2), vec![1, 2]);
+    /// assert_eq!(it.fill_level(), 2);
+    /// assert_eq!(it.next(), Some(3));
+    /// assert_eq!(it.fill_level(), 1);
     /// ```
-    fn coalesce<F>(self, f: F) -> Coalesce<Self, F>
-        where Self: Sized,
-              F: FnMut(Self::Item, Self::Item)
-                       -> Result<Self::Item, (Self::Item, Self::Item)>
+    #[cfg(feature = "use_std")]
+    fn buffered(self, n: usize) -> Buffered<Self>
+        where Self: Sized
     {
-        Coalesce::new(self, f)
+        Buffered::new(self, n)
     }
 
-    /// Remove duplicates from sections of consecutive identical elements.
-    /// If the iterator is sorted, all elements will be unique.
+    /// Return a sliding window of `n` consecutive elements over the
+    /// iterator, for window sizes only known at runtime.
     ///
-    /// Iterator element type is `Self::Item`.
+    /// Windows are taken with [`.next_window()`](struct.Windows.html#method.next_window),
+    /// which returns a slice into an internal buffer of at most `2 * n`
+    /// elements. Nothing is copied or allocated per window, so the input
+    /// need not be collected first. An iterator with fewer than `n`
+    /// elements has no windows.
     ///
-    /// This iterator is *fused*.
+    /// **Panics** if `n` is 0.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let data = vec![1., 1., 2., 3., 3., 2., 2.];
-    /// itertools::assert_equal(data.into_iter().dedup(),
-    ///                         vec![1., 2., 3., 2.]);
+    /// let readings = vec![3., 5., 4., 8., 10.];
+    /// let mut windows = readings.into_iter().windows(3);
+    /// let mut averages = Vec::new();
+    /// while let Some(w) = windows.next_window() {
+    ///     averages.push(w.iter().sum::<f64>() / 3.);
+    /// }
+    /// assert_eq!(averages, vec![4., 17. / 3., 22. / 3.]);
     /// ```
-    fn dedup(self) -> Dedup<Self>
-        where Self: Sized,
-              Self::Item: PartialEq,
+    #[cfg(feature = "use_std")]
+    fn windows(self, n: usize) -> Windows<Self>
+        where Self: Sized
     {
-        Dedup::new(self)
+        Windows::new(self, n)
     }
 
-    /// Return an iterator adaptor that filters out elements that have
-    /// already been produced once during the iteration. Duplicates
-    /// are detected using hash and equality.
+    /// Return an iterator adaptor that wraps each element in a `Position` to
+    /// ease special-case handling of the first or last elements.
     ///
-    /// Clones of visited elements are stored in a hash set in the
-    /// iterator.
+    /// Iterator element type is
+    /// [`Position<Self::Item>`](enum.Position.html).
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::{Itertools, Position};
+    ///
+    /// let it = (0..4).with_position();
+    /// itertools::assert_equal(it,
+    ///                         vec![Position::First(0),
+    ///                              Position::Middle(1),
+    ///                              Position::Middle(2),
+    ///                              Position::Last(3)]);
+    ///
+    /// let it = (0..1).with_position();
+    /// itertools::assert_equal(it, vec![Position::Only(0)]);
+    ///
+    /// // omit the trailing comma
+    /// let mut text = String::new();
+    /// for elt in ["a", "b", "c"].iter().with_position() {
+    ///     match elt {
+    ///         Position::Last(x) | Position::Only(x) => text.push_str(x),
+    ///         Position::First(x) | Position::Middle(x) => {
+    ///             text.push_str(x);
+    ///             text.push_str(", ");
+    ///         }
+    ///     }
+    /// }
+    /// assert_eq!(text, "a, b, c");
+    /// ```
+    fn with_position(self) -> WithPosition<Self>
+        where Self: Sized,
+    {
+        with_position::with_position(self)
+    }
+
+    /// Return an iterator adaptor that maps the first element with `first`,
+    /// the last element with `last`, and all the elements in between with
+    /// `mid`.
+    ///
+    /// It looks one element ahead to find the last one. The only element of
+    /// a single element iterator is mapped with `first`.
+    ///
+    /// Iterator element type is `B`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = ["a", "b", "c"].iter().map_ends(|s| format!("[{},", s),
+    ///                                           |s| format!("{},", s),
+    ///                                           |s| format!("{}]", s));
+    /// assert_eq!(it.collect::<String>(), "[a,b,c]");
+    /// ```
+    fn map_ends<B, F, G, H>(self, first: F, mid: G, last: H) -> MapEnds<Self, F, G, H>
+        where Self: Sized,
+              F: FnMut(Self::Item) -> B,
+              G: FnMut(Self::Item) -> B,
+              H: FnMut(Self::Item) -> B,
+    {
+        with_position::map_ends(self, first, mid, last)
+    }
+
+    /// Return an iterator adaptor that uses the passed-in closure to
+    /// optionally merge together consecutive elements. For each pair the closure
+    /// is passed the latest two elements, `x`, `y` and may return either `Ok(z)`
+    /// to merge the two values or `Err((x, y))` to indicate they can't be merged.
+    ///
+    /// `.dedup()` and `.mend_slices()` are specializations of the coalesce
+    /// adaptor.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // sum same-sign runs together
+    /// let data = vec![-1., -2., -3., 3., 1., 0., -1.];
+    /// itertools::assert_equal(data.into_iter().coalesce(|x, y|
+    ///         if (x >= 0.) == (y >= 0.) {
+    ///             Ok(x + y)
+    ///         } else {
+    ///             Err((x, y))
+    ///         }),
+    ///         vec![-6., 4., -1.]);
+    /// ```
+    fn coalesce<F>(self, f: F) -> Coalesce<Self, F>
+        where Self: Sized,
+              F: FnMut(Self::Item, Self::Item)
+                       -> Result<Self::Item, (Self::Item, Self::Item)>
+    {
+        Coalesce::new(self, f)
+    }
+
+    /// Remove duplicates from sections of consecutive identical elements.
+    /// If the iterator is sorted, all elements will be unique.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1., 1., 2., 3., 3., 2., 2.];
+    /// itertools::assert_equal(data.into_iter().dedup(),
+    ///                         vec![1., 2., 3., 2.]);
+    /// ```
+    fn dedup(self) -> Dedup<Self>
+        where Self: Sized,
+              Self::Item: PartialEq,
+    {
+        Dedup::new(self)
+    }
+
+    /// Remove duplicates from sections of consecutive elements with equal
+    /// keys, as computed by `key`. The first element of each section is kept.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec!["apple", "avocado", "banana", "blueberry", "apricot"];
+    /// itertools::assert_equal(data.into_iter().dedup_by_key(|s| s.as_bytes()[0]),
+    ///                         vec!["apple", "banana", "apricot"]);
+    /// ```
+    fn dedup_by_key<K, F>(self, key: F) -> DedupByKey<Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> K,
+              K: PartialEq,
+    {
+        DedupByKey::new(self, key)
+    }
+
+    /// Return an iterator adaptor that yields each run of consecutive equal
+    /// elements as a pair of the element and the length of the run.
+    ///
+    /// The first element of each run is kept. See
+    /// [*.run_length_decode()*](#method.run_length_decode) for the inverse.
+    ///
+    /// Iterator element type is `(Self::Item, usize)`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![0, 0, 0, 3, 0, 0, 5, 5];
+    /// itertools::assert_equal(data.into_iter().run_length_encode(),
+    ///                         vec![(0, 3), (3, 1), (0, 2), (5, 2)]);
+    /// ```
+    fn run_length_encode(self) -> RunLengthEncode<Self>
+        where Self: Sized,
+              Self::Item: PartialEq,
+    {
+        RunLengthEncode::new(self)
+    }
+
+    /// Return an iterator adaptor that expands each `(element, count)` pair
+    /// into `count` copies of the element.
+    ///
+    /// This is the inverse of
+    /// [*.run_length_encode()*](#method.run_length_encode). Pairs with a
+    /// count of zero yield nothing.
+    ///
+    /// Iterator element type is `T`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let runs = vec![('a', 3), ('b', 0), ('c', 1)];
+    /// itertools::assert_equal(runs.into_iter().run_length_decode(),
+    ///                         "aaac".chars());
+    /// ```
+    fn run_length_decode<T>(self) -> RunLengthDecode<Self, T>
+        where Self: Sized + Iterator<Item = (T, usize)>,
+              T: Clone,
+    {
+        RunLengthDecode::new(self)
+    }
+
+    /// Collapse each run of consecutive elements equal to `value` into a
+    /// single occurrence; other elements pass through unchanged.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let text = "a  b   c d".chars().collapse_runs(' ').collect::<String>();
+    /// assert_eq!(text, "a b c d");
+    ///
+    /// let data = vec![0, 0, 1, 1, 0, 2, 0, 0];
+    /// itertools::assert_equal(data.iter().collapse_runs(&0),
+    ///                         &[0, 1, 1, 0, 2, 0]);
+    /// ```
+    fn collapse_runs(self, value: Self::Item) -> CollapseRuns<Self>
+        where Self: Sized,
+              Self::Item: PartialEq,
+    {
+        CollapseRuns::new(self, value)
+    }
+
+    /// Collapse each run of consecutive elements for which `f` returns
+    /// `true` into the first element of the run; other elements pass
+    /// through unchanged.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let text = "a \t b\n\nc".chars()
+    ///                          .collapse_runs_by(|c| c.is_whitespace())
+    ///                          .collect::<String>();
+    /// assert_eq!(text, "a b\nc");
+    /// ```
+    fn collapse_runs_by<F>(self, f: F) -> CollapseRunsBy<Self, F>
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> bool,
+    {
+        CollapseRunsBy::new(self, f)
+    }
+
+    /// Return an iterator adaptor that filters out elements that have
+    /// already been produced once during the iteration. Duplicates
+    /// are detected using hash and equality.
+    ///
+    /// Clones of visited elements are stored in a hash set in the
+    /// iterator.
     ///
     /// ```
     /// use itertools::Itertools;
@@ -708,6 +1984,7 @@ pub trait Itertools : Iterator {
     /// itertools::assert_equal(data.into_iter().unique(),
     ///                         vec![10, 20, 30, 40, 50]);
     /// ```
+    #[cfg(feature = "use_std")]
     fn unique(self) -> Unique<Self>
         where Self: Sized,
               Self::Item: Clone + Eq + Hash
@@ -729,6 +2006,7 @@ pub trait Itertools : Iterator {
     /// itertools::assert_equal(data.into_iter().unique_by(|s| s.len()),
     ///                         vec!["a", "bb", "ccc"]);
     /// ```
+    #[cfg(feature = "use_std")]
     fn unique_by<V, F>(self, f: F) -> UniqueBy<Self, V, F>
         where Self: Sized,
               V: Eq + Hash,
@@ -737,6 +2015,54 @@ pub trait Itertools : Iterator {
         UniqueBy::new(self, f)
     }
 
+    /// Return an iterator adaptor that produces elements that appear more
+    /// than once during the iteration. Duplicates are detected using hash
+    /// and equality.
+    ///
+    /// Each duplicate is produced only once, at the position of its
+    /// second occurrence.
+    ///
+    /// Clones of visited elements are stored in a hash map in the
+    /// iterator.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![10, 20, 30, 20, 40, 10, 50, 20];
+    /// itertools::assert_equal(data.into_iter().duplicates(),
+    ///                         vec![20, 10]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn duplicates(self) -> Duplicates<Self>
+        where Self: Sized,
+              Self::Item: Clone + Eq + Hash
+    {
+        adaptors::duplicates(self)
+    }
+
+    /// Return an iterator adaptor that produces elements that appear more
+    /// than once during the iteration.
+    ///
+    /// Duplicates are detected by comparing the key they map to
+    /// with the keying function `f` by hash and equality.
+    /// The keys are stored in a hash map in the iterator.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec!["a", "bb", "aa", "c", "ccc", "d"];
+    /// itertools::assert_equal(data.into_iter().duplicates_by(|s| s.len()),
+    ///                         vec!["aa", "c"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn duplicates_by<V, F>(self, f: F) -> DuplicatesBy<Self, V, F>
+        where Self: Sized,
+              V: Eq + Hash,
+              F: FnMut(&Self::Item) -> V
+    {
+        DuplicatesBy::new(self, f)
+    }
+
     /// Return an iterator adaptor that joins together adjacent slices if possible.
     ///
     /// Only implemented for iterators with slice or string slice elements.
@@ -791,6 +2117,9 @@ pub trait Itertools : Iterator {
     ///
     /// Iterator element type is `A`, the unwrapped element.
     ///
+    /// This iterator is *fused*: no elements are produced after the first
+    /// `None`, even if the base iterator has more.
+    ///
     /// ```
     /// use itertools::Itertools;
     ///
@@ -806,6 +2135,91 @@ pub trait Itertools : Iterator {
         WhileSome::new(self)
     }
 
+    /// Return an iterator adaptor that, like `scan`, threads a state through
+    /// the elements, but seeds the state with the first element instead of
+    /// an initial value.
+    ///
+    /// The first element is produced as-is. After that, `f` is called with
+    /// the previous state and the next element, and its result is both
+    /// produced and kept as the new state. Iteration stops when the base
+    /// iterator is exhausted or when `f` returns `None`.
+    ///
+    /// `.scan1()` relates to `scan` like `.fold1()` relates to `fold`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // running sums
+    /// itertools::assert_equal((1..6).scan1(|&acc, x| Some(acc + x)),
+    ///                         vec![1, 3, 6, 10, 15]);
+    ///
+    /// // running sums, stopping once they would exceed 8
+    /// itertools::assert_equal((1..6).scan1(|&acc, x| if acc + x <= 8 { Some(acc + x) } else { None }),
+    ///                         vec![1, 3, 6]);
+    /// ```
+    fn scan1<F>(self, f: F) -> Scan1<Self, F>
+        where Self: Sized,
+              Self::Item: Clone,
+              F: FnMut(&Self::Item, Self::Item) -> Option<Self::Item>
+    {
+        adaptors::scan1(self, f)
+    }
+
+    /// Return an iterator adaptor that applies `f` to each element and
+    /// decides from its result whether to produce a value, skip the element
+    /// or stop the iteration altogether.
+    ///
+    /// `f` returns a `WhileStep`: `Yield(x)` produces `x`, `Skip` moves on to
+    /// the next element and `Stop` ends the iteration.
+    ///
+    /// This iterator is *fused*: no elements are produced after the first
+    /// `Stop`, even if the base iterator has more.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::WhileStep;
+    ///
+    /// // Parse numbers, skipping blanks, until the end marker
+    /// let tokens = vec!["1", "", "2", "3", ";", "4"];
+    /// let numbers = tokens.into_iter().filter_map_while(|t| match t {
+    ///     "" => WhileStep::Skip,
+    ///     ";" => WhileStep::Stop,
+    ///     t => WhileStep::Yield(t.parse::<i32>().unwrap()),
+    /// });
+    /// itertools::assert_equal(numbers, vec![1, 2, 3]);
+    /// ```
+    fn filter_map_while<B, F>(self, f: F) -> FilterMapWhile<Self, F>
+        where Self: Sized,
+              F: FnMut(Self::Item) -> WhileStep<B>
+    {
+        adaptors::filter_map_while(self, f)
+    }
+
+    /// Return an iterator adaptor that applies `f` to each element and
+    /// produces the `Some` values, stopping at the first `None`.
+    ///
+    /// This is `.map(f).while_some()`; use `.filter_map_while()` when
+    /// elements also need to be skipped.
+    ///
+    /// This iterator is *fused*: no elements are produced after the first
+    /// `None`, even if the base iterator has more.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let tokens = vec!["1", "2", "x", "3"];
+    /// let numbers = tokens.into_iter().map_while_some(|t| t.parse::<i32>().ok());
+    /// itertools::assert_equal(numbers, vec![1, 2]);
+    /// ```
+    fn map_while_some<B, F>(self, f: F) -> WhileSome<iter::Map<Self, F>>
+        where Self: Sized,
+              F: FnMut(Self::Item) -> Option<B>
+    {
+        WhileSome::new(self.map(f))
+    }
+
     /// Return an iterator adaptor that iterates over the combinations of
     /// the elements from an iterator.
     ///
@@ -817,6 +2231,7 @@ pub trait Itertools : Iterator {
     /// let it = (1..5).combinations();
     /// itertools::assert_equal(it, vec![(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)]);
     /// ```
+    #[cfg(feature = "use_std")]
     fn combinations(self) -> Combinations<Self>
         where Self: Sized + Clone,
               Self::Item: Clone
@@ -841,6 +2256,7 @@ pub trait Itertools : Iterator {
     ///     vec![2, 3, 4],
     ///     ]);
     /// ```
+    #[cfg(feature = "use_std")]
     fn combinations_n(self, n: usize) -> CombinationsN<Self>
         where Self: Sized,
               Self::Item: Clone
@@ -848,6 +2264,35 @@ pub trait Itertools : Iterator {
         CombinationsN::new(self, n)
     }
 
+    /// Return an iterator adaptor that iterates over the `K`-length
+    /// combinations of the elements from an iterator, as arrays.
+    ///
+    /// Like `.combinations_n(K)`, but each combination is an array, so there
+    /// is no allocation per combination.
+    ///
+    /// This method is only available with the crate feature `arrays`.
+    ///
+    /// Iterator element type is `[Self::Item; K]`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (1..5).array_combinations();
+    /// itertools::assert_equal(it, vec![
+    ///     [1, 2, 3],
+    ///     [1, 2, 4],
+    ///     [1, 3, 4],
+    ///     [2, 3, 4],
+    ///     ]);
+    /// ```
+    #[cfg(all(feature = "arrays", feature = "use_std"))]
+    fn array_combinations<const K: usize>(self) -> ArrayCombinations<Self, K>
+        where Self: Sized,
+              Self::Item: Clone
+    {
+        arrays::array_combinations(self)
+    }
+
     /// Return an iterator adaptor that pads the sequence to a minimum length of
     /// `min` by filling missing elements using a closure `f`.
     ///
@@ -872,6 +2317,32 @@ pub trait Itertools : Iterator {
         PadUsing::new(self, min, f)
     }
 
+    /// Return an iterator adaptor that pads the sequence so that its length
+    /// is a multiple of `n`, filling missing elements using a closure `f`.
+    ///
+    /// `f` is called with the index of each padding element. An empty
+    /// sequence is not padded, since 0 is a multiple of `n`.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// **Panics** if `n` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..5).pad_to_multiple(4, |i| 10 * i);
+    /// itertools::assert_equal(it, vec![0, 1, 2, 3, 4, 50, 60, 70]);
+    ///
+    /// let it = (0..8).pad_to_multiple(4, |_| panic!());
+    /// itertools::assert_equal(it, 0..8);
+    /// ```
+    fn pad_to_multiple<F>(self, n: usize, f: F) -> PadToMultiple<Self, F>
+        where Self: Sized,
+              F: FnMut(usize) -> Self::Item
+    {
+        PadToMultiple::new(self, n, f)
+    }
+
     /// Unravel a nested iterator.
     ///
     /// This is a shortcut for `it.flat_map(|x| x)`.
@@ -880,7 +2351,7 @@ pub trait Itertools : Iterator {
     /// use itertools::Itertools;
     ///
     /// let data = vec![vec![1, 2, 3], vec![4, 5, 6]];
-    /// let flattened = data.into_iter().flatten();
+    /// let flattened = Itertools::flatten(data.into_iter());
     ///
     /// itertools::assert_equal(flattened, vec![1, 2, 3, 4, 5, 6]);
     /// ```
@@ -891,21 +2362,295 @@ pub trait Itertools : Iterator {
         Flatten::new(self)
     }
 
-    /// Like regular `.map()`, specialized to using a simple function pointer instead,
-    /// so that the resulting `Map` iterator value can be cloned.
+    /// Return an iterator adaptor that flattens every `Result::Ok` value into
+    /// a series of `Result::Ok` values. `Result::Err` values are unchanged.
     ///
-    /// Iterator element type is `B`.
+    /// This is useful when you have some common error type for your crate and
+    /// need to propagate it upwards, but the `Result::Ok` case needs to be flattened.
+    ///
+    /// The iterator is double ended when the base iterator and the
+    /// flattened iterators are.
+    ///
+    /// Iterator element type is `Result<T::Item, E>`.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let data = vec![Ok(1), Ok(0), Err("No result")];
-    ///
-    /// let iter = data.iter().cloned().map_fn(Result::ok);
-    /// let iter_copy = iter.clone();
+    /// let input = vec![Ok(0..2), Err(false), Ok(2..4)];
+    /// let it = input.iter().cloned().flatten_ok();
+    /// itertools::assert_equal(it.clone(), vec![Ok(0), Ok(1), Err(false), Ok(2), Ok(3)]);
     ///
-    /// itertools::assert_equal(iter, vec![Some(1), Some(0), None]);
-    /// itertools::assert_equal(iter_copy, vec![Some(1), Some(0), None]);
+    /// // from the back
+    /// itertools::assert_equal(it.rev(), vec![Ok(3), Ok(2), Err(false), Ok(1), Ok(0)]);
+    /// ```
+    fn flatten_ok<T, E>(self) -> FlattenOk<Self, T, E>
+        where Self: Sized + Iterator<Item = Result<T, E>>,
+              T: IntoIterator
+    {
+        flatten_ok::flatten_ok(self)
+    }
+
+    /// Return an iterator adaptor that converts each element into `U` with
+    /// `Into::into`.
+    ///
+    /// This is `.map(Into::into)`, with the target type named at the call.
+    ///
+    /// Iterator element type is `U`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let wide = [1u8, 2, 255].iter().cloned().map_into::<u32>().map(|x| x * 256);
+    /// itertools::assert_equal(wide, vec![256, 512, 65280]);
+    ///
+    /// let owned = vec!["a", "b"].into_iter().map_into::<String>().collect_vec();
+    /// assert_eq!(owned, ["a", "b"]);
+    /// ```
+    fn map_into<U>(self) -> MapInto<Self, U>
+        where Self: Sized,
+              Self::Item: Into<U>,
+    {
+        adaptors::map_into(self)
+    }
+
+    /// Return an iterator adaptor that applies `f` to every element and
+    /// catches any panic in `f`, yielding `Ok` with the result of `f` or
+    /// `Err` with the panic payload.
+    ///
+    /// This lets a long pipeline skip or report the elements that make `f`
+    /// panic instead of unwinding through the whole job. The panic hook still
+    /// runs for every caught panic, so by default each one is printed to
+    /// stderr. Panics are not caught if the program is built with
+    /// `panic = "abort"`.
+    ///
+    /// `f` is run as if it were `UnwindSafe`: it keeps being called after it
+    /// panicked, so any state it mutates may be left half updated.
+    ///
+    /// Iterator element type is `Result<B, PanicPayload>`.
+    ///
+    /// This method requires the `catch_unwind` crate feature.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let results = vec![4, 0, 2].into_iter()
+    ///     .catch_unwind_map(|x| 8 / x)
+    ///     .map(|r| r.ok())
+    ///     .collect_vec();
+    /// assert_eq!(results, vec![Some(2), None, Some(4)]);
+    /// ```
+    #[cfg(feature = "catch_unwind")]
+    fn catch_unwind_map<B, F>(self, f: F) -> CatchUnwindMap<Self, F>
+        where Self: Sized,
+              F: FnMut(Self::Item) -> B,
+    {
+        catch_unwind_map::catch_unwind_map(self, f)
+    }
+
+    /// Return an iterator adaptor that applies the provided closure
+    /// to every `Result::Ok` value. `Result::Err` values are
+    /// unchanged.
+    ///
+    /// Iterator element type is `Result<U, E>`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let input = vec![Ok(41), Err(false), Ok(11)];
+    /// let it = input.into_iter().map_ok(|i| i + 1);
+    /// itertools::assert_equal(it, vec![Ok(42), Err(false), Ok(12)]);
+    /// ```
+    fn map_ok<F, T, U, E>(self, f: F) -> MapOk<Self, F>
+        where Self: Sized + Iterator<Item = Result<T, E>>,
+              F: FnMut(T) -> U,
+    {
+        adaptors::map_ok(self, f)
+    }
+
+    /// Return an iterator adaptor that calls the provided closure with a
+    /// reference to every `Result::Ok` value, and passes all elements on
+    /// unchanged. `Result::Err` values are not inspected.
+    ///
+    /// Iterator element type is `Result<T, E>`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let input = vec![Ok(41), Err(false), Ok(11)];
+    /// let mut seen = Vec::new();
+    /// let it = input.into_iter().inspect_ok(|&i| seen.push(i));
+    /// itertools::assert_equal(it, vec![Ok(41), Err(false), Ok(11)]);
+    /// assert_eq!(seen, vec![41, 11]);
+    /// ```
+    fn inspect_ok<F, T, E>(self, f: F) -> InspectOk<Self, F>
+        where Self: Sized + Iterator<Item = Result<T, E>>,
+              F: FnMut(&T),
+    {
+        adaptors::inspect_ok(self, f)
+    }
+
+    /// Return an iterator adaptor that filters every `Result::Ok`
+    /// value with the provided closure. `Result::Err` values are
+    /// unchanged.
+    ///
+    /// Iterator element type is `Result<T, E>`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let input = vec![Ok(22), Err(false), Ok(11)];
+    /// let it = input.into_iter().filter_ok(|&i| i > 20);
+    /// itertools::assert_equal(it, vec![Ok(22), Err(false)]);
+    /// ```
+    fn filter_ok<F, T, E>(self, f: F) -> FilterOk<Self, F>
+        where Self: Sized + Iterator<Item = Result<T, E>>,
+              F: FnMut(&T) -> bool,
+    {
+        adaptors::filter_ok(self, f)
+    }
+
+    /// Return an iterator adaptor that filters and transforms every
+    /// `Result::Ok` value with the provided closure. `Result::Err`
+    /// values are unchanged.
+    ///
+    /// Iterator element type is `Result<U, E>`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let input = vec![Ok(22), Err(false), Ok(11)];
+    /// let it = input.into_iter().filter_map_ok(|i| if i > 20 { Some(i * 2) } else { None });
+    /// itertools::assert_equal(it, vec![Ok(44), Err(false)]);
+    /// ```
+    fn filter_map_ok<F, T, U, E>(self, f: F) -> FilterMapOk<Self, F>
+        where Self: Sized + Iterator<Item = Result<T, E>>,
+              F: FnMut(T) -> Option<U>,
+    {
+        adaptors::filter_map_ok(self, f)
+    }
+
+    /// Return an iterator adaptor that applies the provided fallible
+    /// closure to every `Result::Ok` value, yielding its result.
+    /// `Result::Err` values are unchanged.
+    ///
+    /// Iterator element type is `Result<U, E>`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let input = vec![Ok("1"), Err("io"), Ok("x")];
+    /// let it = input.into_iter().and_then_ok(|s| s.parse::<i32>().map_err(|_| "parse"));
+    /// itertools::assert_equal(it, vec![Ok(1), Err("io"), Err("parse")]);
+    /// ```
+    fn and_then_ok<F, T, U, E>(self, f: F) -> AndThenOk<Self, F>
+        where Self: Sized + Iterator<Item = Result<T, E>>,
+              F: FnMut(T) -> Result<U, E>,
+    {
+        adaptors::and_then_ok(self, f)
+    }
+
+    /// Return an iterator adaptor that retries the adapted iterator when it
+    /// yields a `Result::Err` for which `should_retry` returns `true`.
+    ///
+    /// This is meant for re-pollable sources, like those made with
+    /// `repeat_call` or `unfold`, where pulling again repeats the failed
+    /// step. Each element gets at most `max_attempts` pulls; an error that
+    /// is not retried, or that is still returned on the last attempt, is
+    /// yielded as is. `Result::Ok` values are unchanged.
+    ///
+    /// If the adapted iterator ends while retrying, the last error is
+    /// yielded before the iterator ends.
+    ///
+    /// Iterator element type is `Result<T, E>`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// **Panics** if `max_attempts` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // a flaky source that only succeeds every third call
+    /// let mut calls = 0;
+    /// let flaky = itertools::repeat_call(|| {
+    ///     calls += 1;
+    ///     if calls % 3 == 0 { Ok(calls) } else { Err("timeout") }
+    /// });
+    /// itertools::assert_equal(flaky.retry_ok(3, |&e| e == "timeout").take(2),
+    ///                         vec![Ok(3), Ok(6)]);
+    ///
+    /// let input = vec![Err("timeout"), Err("timeout"), Err("fatal"), Ok(1)];
+    /// itertools::assert_equal(input.into_iter().retry_ok(2, |&e| e == "timeout"),
+    ///                         vec![Err("timeout"), Err("fatal"), Ok(1)]);
+    /// ```
+    fn retry_ok<F, T, E>(self, max_attempts: usize, should_retry: F) -> RetryOk<Self, F>
+        where Self: Sized + Iterator<Item = Result<T, E>>,
+              F: FnMut(&E) -> bool,
+    {
+        adaptors::retry_ok(self, max_attempts, should_retry)
+    }
+
+    /// Return an iterator adaptor that yields the indices of all elements
+    /// satisfying a predicate, counted from the start of the iterator.
+    ///
+    /// The iterator is double ended when the adapted iterator is double
+    /// ended and has an exact size, so `.positions(p).rev()` searches from
+    /// the back.
+    ///
+    /// Iterator element type is `usize`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1, 2, 3, 3, 4, 6, 7, 9];
+    /// itertools::assert_equal(data.iter().positions(|v| v % 2 == 0), vec![1, 4, 5]);
+    ///
+    /// itertools::assert_equal(data.iter().positions(|v| v % 2 == 1).rev(), vec![7, 6, 3, 2, 0]);
+    /// ```
+    fn positions<P>(self, predicate: P) -> Positions<Self, P>
+        where Self: Sized,
+              P: FnMut(Self::Item) -> bool,
+    {
+        Positions::new(self, predicate)
+    }
+
+    /// Return an iterator adaptor that applies the mutating function `f` to
+    /// each element, and then yields it.
+    ///
+    /// This is `.map(|mut x| { f(&mut x); x })`, but states that the elements
+    /// are modified in place rather than transformed.
+    ///
+    /// Iterator element type is `Self::Item`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let words = vec![String::from("a"), String::from("b")];
+    /// let it = words.into_iter().update(|s| s.push('!'));
+    /// itertools::assert_equal(it, vec!["a!", "b!"]);
+    /// ```
+    fn update<F>(self, updater: F) -> Update<Self, F>
+        where Self: Sized,
+              F: FnMut(&mut Self::Item),
+    {
+        adaptors::update(self, updater)
+    }
+
+    /// Like regular `.map()`, specialized to using a simple function pointer instead,
+    /// so that the resulting `Map` iterator value can be cloned.
+    ///
+    /// Iterator element type is `B`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![Ok(1), Ok(0), Err("No result")];
+    ///
+    /// let iter = data.iter().cloned().map_fn(Result::ok);
+    /// let iter_copy = iter.clone();
+    ///
+    /// itertools::assert_equal(iter, vec![Some(1), Some(0), None]);
+    /// itertools::assert_equal(iter_copy, vec![Some(1), Some(0), None]);
     /// ```
     fn map_fn<B>(self, f: fn(Self::Item) -> B) -> MapFn<Self, B>
         where Self: Sized
@@ -915,6 +2660,108 @@ pub trait Itertools : Iterator {
 
     // non-adaptor methods
 
+    /// Classify the iterator as empty, having a single element, or having more
+    /// than one, pulling at most two elements.
+    ///
+    /// No element is lost: with two or more elements, the first is returned
+    /// along with an iterator over the rest, starting with the second.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::OneOrMore::{Empty, Single, More};
+    ///
+    /// fn describe(args: Vec<&str>) -> String {
+    ///     match args.into_iter().one_or_more() {
+    ///         Empty => "nothing".to_string(),
+    ///         Single(a) => format!("just {}", a),
+    ///         More(a, mut rest) => format!("{} and {}", a, rest.join(", ")),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(describe(vec![]), "nothing");
+    /// assert_eq!(describe(vec!["x"]), "just x");
+    /// assert_eq!(describe(vec!["x", "y", "z"]), "x and y, z");
+    /// ```
+    fn one_or_more(mut self) -> OneOrMore<Self>
+        where Self: Sized
+    {
+        let first = match self.next() {
+            None => return OneOrMore::Empty,
+            Some(elt) => elt,
+        };
+        match self.next() {
+            None => OneOrMore::Single(first),
+            Some(second) => OneOrMore::More(first, PutBack::with_value(second, self)),
+        }
+    }
+
+    /// If the iterator yields exactly one element, that element will be returned, otherwise
+    /// an error will be returned containing an iterator that has the same output as the input
+    /// iterator.
+    ///
+    /// This provides an additional layer of validation over just calling `Iterator::next()`.
+    /// If your assumption that there should only be one element yielded is false this provides
+    /// the opportunity to detect and handle that, preventing errors at a distance.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!((0..10).filter(|&x| x == 2).exactly_one().unwrap(), 2);
+    /// assert!((0..10).filter(|&x| x > 1 && x < 4).exactly_one().unwrap_err().eq(2..4));
+    /// assert!((0..10).filter(|&x| x > 1 && x < 5).exactly_one().unwrap_err().eq(2..5));
+    /// assert!((0..10).filter(|&_| false).exactly_one().unwrap_err().eq(0..0));
+    ///
+    /// let err = (0..3).exactly_one().unwrap_err();
+    /// assert_eq!(err.to_string(), "got at least 2 elements when exactly one was expected");
+    /// ```
+    fn exactly_one(mut self) -> Result<Self::Item, ExactlyOneError<Self>>
+        where Self: Sized,
+    {
+        match self.next() {
+            Some(first) => {
+                match self.next() {
+                    Some(second) => {
+                        Err(ExactlyOneError::new((Some(first), Some(second)), self))
+                    }
+                    None => Ok(first),
+                }
+            }
+            None => Err(ExactlyOneError::new((None, None), self)),
+        }
+    }
+
+    /// If the iterator yields no elements, `Ok(None)` will be returned. If the iterator yields
+    /// exactly one element, that element will be returned, otherwise an error will be returned
+    /// containing an iterator that has the same output as the input iterator.
+    ///
+    /// This provides an additional layer of validation over just calling `Iterator::next()`.
+    /// If your assumption that there should be at most one element yielded is false this provides
+    /// the opportunity to detect and handle that, preventing errors at a distance.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!((0..10).filter(|&x| x == 2).at_most_one().unwrap(), Some(2));
+    /// assert!((0..10).filter(|&x| x > 1 && x < 4).at_most_one().unwrap_err().eq(2..4));
+    /// assert!((0..10).filter(|&x| x > 1 && x < 5).at_most_one().unwrap_err().eq(2..5));
+    /// assert_eq!((0..10).filter(|&_| false).at_most_one().unwrap(), None);
+    /// ```
+    fn at_most_one(mut self) -> Result<Option<Self::Item>, ExactlyOneError<Self>>
+        where Self: Sized,
+    {
+        match self.next() {
+            Some(first) => {
+                match self.next() {
+                    Some(second) => {
+                        Err(ExactlyOneError::new((Some(first), Some(second)), self))
+                    }
+                    None => Ok(Some(first)),
+                }
+            }
+            None => Ok(None),
+        }
+    }
+
     /// Find the position and value of the first element satisfying a predicate.
     ///
     /// The iterator is not advanced past the first element found.
@@ -938,6 +2785,43 @@ pub trait Itertools : Iterator {
         None
     }
 
+    /// Find the first occurrence of `needle` as a contiguous subsequence of
+    /// the iterator, and return the index where it starts.
+    ///
+    /// Only the last `needle.len()` elements are kept in a buffer while
+    /// searching. An empty `needle` matches at index `0`.
+    ///
+    /// The iterator is not advanced past the last element of the match.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let tokens = vec!["let", "x", "=", "1", ";", "let", "y", "=", "2", ";"];
+    /// assert_eq!(tokens.iter().cloned().find_subsequence(&["let", "y"]), Some(5));
+    /// assert_eq!(tokens.iter().cloned().find_subsequence(&["y", "x"]), None);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn find_subsequence(&mut self, needle: &[Self::Item]) -> Option<usize>
+        where Self::Item: PartialEq
+    {
+        if needle.is_empty() {
+            return Some(0);
+        }
+        let mut buf = VecDeque::with_capacity(needle.len());
+        let mut index = 0usize;
+        for elt in self {
+            if buf.len() == needle.len() {
+                buf.pop_front();
+                index += 1;
+            }
+            buf.push_back(elt);
+            if buf.len() == needle.len() && buf.iter().eq(needle.iter()) {
+                return Some(index);
+            }
+        }
+        None
+    }
+
     /// Consume the first `n` elements of the iterator eagerly.
     ///
     /// Return actual number of elements consumed, until done or reaching the end.
@@ -970,7 +2854,7 @@ pub trait Itertools : Iterator {
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let mut iter = "αβγ".chars().dropping(2);
+    /// let iter = "αβγ".chars().dropping(2);
     /// itertools::assert_equal(iter, "γ".chars());
     /// ```
     fn dropping(mut self, n: usize) -> Self
@@ -982,6 +2866,33 @@ pub trait Itertools : Iterator {
         self
     }
 
+    /// Collect the first `n` elements into a vector, and return it along with
+    /// the same iterator, which yields the rest of the elements.
+    ///
+    /// The vector is shorter than `n` if the iterator runs out first.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let (header, body) = "HDR:payload".chars().split_nth(4);
+    /// assert_eq!(header, vec!['H', 'D', 'R', ':']);
+    /// assert_eq!(body.collect::<String>(), "payload");
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn split_nth(mut self, n: usize) -> (Vec<Self::Item>, Self)
+        where Self: Sized
+    {
+        let (lower, _) = self.size_hint();
+        let mut prefix = Vec::with_capacity(cmp::min(n, lower));
+        while prefix.len() < n {
+            match self.next() {
+                Some(elt) => prefix.push(elt),
+                None => break,
+            }
+        }
+        (prefix, self)
+    }
+
     /// Consume the last `n` elements from the iterator eagerly,
     /// and return the same iterator again.
     ///
@@ -997,343 +2908,2413 @@ pub trait Itertools : Iterator {
     /// let init = vec![0, 3, 6, 9].into_iter().dropping_back(1);
     /// itertools::assert_equal(init, vec![0, 3, 6]);
     /// ```
-    fn dropping_back(mut self, n: usize) -> Self
+    fn dropping_back(mut self, n: usize) -> Self
+        where Self: Sized,
+              Self: DoubleEndedIterator
+    {
+        if n > 0 {
+            self.nth_back(n - 1);
+        }
+        self
+    }
+
+    /// Run the closure `f` eagerly on each element of the iterator.
+    ///
+    /// Consumes the iterator until its end.
+    ///
+    /// ```
+    /// use std::sync::mpsc::channel;
+    /// use itertools::Itertools;
+    ///
+    /// let (tx, rx) = channel();
+    ///
+    /// // use .foreach() to apply a function to each value -- sending it
+    /// (0..5).map(|x| x * 2 + 1).foreach(|x| { tx.send(x).unwrap(); } );
+    ///
+    /// drop(tx);
+    ///
+    /// itertools::assert_equal(rx.iter(), vec![1, 3, 5, 7, 9]);
+    /// ```
+    fn foreach<F>(&mut self, mut f: F)
+        where F: FnMut(Self::Item)
+    {
+        for elt in self {
+            f(elt)
+        }
+    }
+
+    /// Pass the whole iterator to the closure `f`, and return its result.
+    ///
+    /// This lets functions that take and return iterators, like adaptors
+    /// defined outside of this crate, be used in the middle of a method
+    /// chain: `it.apply(f)` is the same as `f(it)`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// fn evens<I: Iterator<Item = u32>>(iter: I) -> std::iter::StepBy<I> {
+    ///     iter.step_by(2)
+    /// }
+    ///
+    /// let v = (0..10).map(|x| x * 3).apply(evens).map(|x| x + 1).collect_vec();
+    /// assert_eq!(v, vec![1, 7, 13, 19, 25]);
+    ///
+    /// let sum = (1..4).apply(|it| it.chain(10..12)).fold(0, |a, b| a + b);
+    /// assert_eq!(sum, 27);
+    /// ```
+    fn apply<F, R>(self, f: F) -> R
+        where Self: Sized,
+              F: FnOnce(Self) -> R,
+    {
+        f(self)
+    }
+
+    /// Return an iterator adaptor over all windows of consecutive elements of
+    /// an iterator of references, as tuples of references.
+    ///
+    /// The tuple type `T` decides the window size; tuples of 1 to 12
+    /// elements are supported. Only the references are copied into each
+    /// window, so the referenced elements don't need to implement `Clone`.
+    ///
+    /// Iterator element type is `T`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // not Clone
+    /// #[derive(Debug, PartialEq)]
+    /// struct Record(u32);
+    ///
+    /// let records = vec![Record(1), Record(3), Record(2)];
+    /// let mut windows = records.iter().ref_tuple_windows();
+    /// assert_eq!(windows.next(), Some((&Record(1), &Record(3))));
+    /// assert_eq!(windows.next(), Some((&Record(3), &Record(2))));
+    /// assert_eq!(windows.next(), None);
+    ///
+    /// let rising = records.iter().ref_tuple_windows().filter(|&(a, b)| a.0 < b.0).count();
+    /// assert_eq!(rising, 1);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn ref_tuple_windows<'a, X, T>(self) -> RefTupleWindows<Self, T>
+        where Self: Sized + Iterator<Item = &'a X>,
+              X: 'a + ?Sized,
+              T: TupleCollect<Item = &'a X>,
+    {
+        tuple_impls::ref_tuple_windows(self)
+    }
+
+    /// Advance the iterator and return its next elements as a tuple, or
+    /// `None` if it runs out before the tuple is filled.
+    ///
+    /// The tuple type `T` decides how many elements are taken; tuples of 1
+    /// to 12 elements are supported. On `None`, the elements that were taken
+    /// are lost.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut iter = 1..6;
+    /// assert_eq!(iter.next_tuple(), Some((1, 2)));
+    /// assert_eq!(iter.next_tuple(), Some((3, 4)));
+    /// assert_eq!(iter.next_tuple::<(_, _)>(), None);
+    /// ```
+    fn next_tuple<T>(&mut self) -> Option<T>
+        where Self: Sized + Iterator<Item = T::Item>,
+              T: TupleCollect,
+    {
+        T::collect_from_iter(self)
+    }
+
+    /// Collect all the iterator's elements into a tuple, if it has exactly
+    /// as many elements as the tuple, or return `None` otherwise.
+    ///
+    /// Tuples of 1 to 12 elements are supported.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let line = "x=10,y=-3";
+    /// let (x, y) = line.split(',').collect_tuple().unwrap();
+    /// assert_eq!((x, y), ("x=10", "y=-3"));
+    ///
+    /// assert_eq!("a,b,c".split(',').collect_tuple::<(_, _)>(), None);
+    /// assert_eq!("a".split(',').collect_tuple::<(_, _)>(), None);
+    /// ```
+    fn collect_tuple<T>(mut self) -> Option<T>
+        where Self: Sized + Iterator<Item = T::Item>,
+              T: TupleCollect,
+    {
+        match self.next_tuple() {
+            elt @ Some(_) => match self.next() {
+                Some(_) => None,
+                None => elt,
+            },
+            _ => None,
+        }
+    }
+
+    /// Advance the iterator and return its next `N` elements as an array, or
+    /// `None` if it runs out before the array is filled.
+    ///
+    /// On `None`, the elements that were taken are lost.
+    ///
+    /// This method is only available with the crate feature `arrays`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut bytes = b"\x7fELF\x02\x01".iter().cloned();
+    /// let magic: [u8; 4] = bytes.next_array().unwrap();
+    /// assert_eq!(&magic, b"\x7fELF");
+    /// assert_eq!(bytes.next_array::<3>(), None);
+    /// ```
+    #[cfg(feature = "arrays")]
+    fn next_array<const N: usize>(&mut self) -> Option<[Self::Item; N]>
+        where Self: Sized,
+    {
+        arrays::next_array(self)
+    }
+
+    /// Collect all the iterator's elements into an array, if it has exactly
+    /// `N` elements, or return `None` otherwise.
+    ///
+    /// This method is only available with the crate feature `arrays`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!((1..4).collect_array(), Some([1, 2, 3]));
+    /// assert_eq!((1..4).collect_array::<2>(), None);
+    /// assert_eq!((1..4).collect_array::<4>(), None);
+    /// ```
+    #[cfg(feature = "arrays")]
+    fn collect_array<const N: usize>(mut self) -> Option<[Self::Item; N]>
+        where Self: Sized,
+    {
+        match self.next_array() {
+            elt @ Some(_) => match self.next() {
+                Some(_) => None,
+                None => elt,
+            },
+            _ => None,
+        }
+    }
+
+    /// `.collect_vec()` is simply a type specialization of `.collect()`,
+    /// for convenience.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let v = (1..4).map(|x| x * x).collect_vec();
+    /// assert_eq!(v, vec![1, 4, 9]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn collect_vec(self) -> Vec<Self::Item>
+        where Self: Sized
+    {
+        self.collect()
+    }
+
+    /// `.try_collect()` is a shorthand for `.collect::<Result<_, _>>()`.
+    ///
+    /// Collect the values of an iterator of `Result`s into a collection, or
+    /// stop at the first `Err` and return it.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let v: Vec<u8> = ["1", "2", "3"].iter().map(|s| s.parse()).try_collect().unwrap();
+    /// assert_eq!(v, vec![1, 2, 3]);
+    ///
+    /// let r: Result<Vec<u8>, _> = ["1", "x", "3"].iter().map(|s| s.parse::<u8>()).try_collect();
+    /// assert!(r.is_err());
+    /// ```
+    fn try_collect<T, U, E>(self) -> Result<U, E>
+        where Self: Iterator<Item = Result<T, E>> + Sized,
+              Result<U, E>: FromIterator<Result<T, E>>,
+    {
+        self.collect()
+    }
+
+    /// Assign to each reference in `self` from the `from` iterator,
+    /// stopping at the shortest of the two iterators.
+    ///
+    /// The `from` iterator is queried for its next element before the `self`
+    /// iterator, and if either is exhausted the method is done.
+    ///
+    /// Return the number of elements written.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut xs = [0; 4];
+    /// xs.iter_mut().set_from(1..);
+    /// assert_eq!(xs, [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    fn set_from<'a, A: 'a, J>(&mut self, from: J) -> usize
+        where Self: Iterator<Item = &'a mut A>,
+              J: IntoIterator<Item = A>
+    {
+        let mut count = 0;
+        for elt in from {
+            match self.next() {
+                None => break,
+                Some(ptr) => *ptr = elt,
+            }
+            count += 1;
+        }
+        count
+    }
+
+    /// Move all the iterator's elements into `sink`, and return how many
+    /// there were.
+    ///
+    /// The elements are passed to `sink` with a single call to
+    /// `Extend::extend`, so it can reserve space from the size hint.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut buffer = vec![0];
+    /// let moved = (1..4).map(|x| x * 10).drain_into(&mut buffer);
+    /// assert_eq!(moved, 3);
+    /// assert_eq!(buffer, [0, 10, 20, 30]);
+    /// ```
+    fn drain_into<E>(&mut self, sink: &mut E) -> usize
+        where E: Extend<Self::Item>
+    {
+        let mut count = 0;
+        sink.extend(self.inspect(|_| count += 1));
+        count
+    }
+
+    /// Combine all iterator elements into one String, seperated by `sep`.
+    ///
+    /// Use the `Display` implementation of each element.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!(["a", "b", "c"].iter().join(", "), "a, b, c");
+    /// assert_eq!([1, 2, 3].iter().join(", "), "1, 2, 3");
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn join(&mut self, sep: &str) -> String
+        where Self::Item: std::fmt::Display
+    {
+        match self.next() {
+            None => String::new(),
+            Some(first_elt) => {
+                // estimate lower bound of capacity needed
+                let (lower, _) = self.size_hint();
+                let mut result = String::with_capacity(sep.len() * lower);
+                write!(&mut result, "{}", first_elt).unwrap();
+                for elt in self {
+                    result.push_str(sep);
+                    write!(&mut result, "{}", elt).unwrap();
+                }
+                result
+            }
+        }
+    }
+
+    /// Write all iterator elements into `out`, a `fmt::Write` sink such as
+    /// a `String`, separated by `sep`.
+    ///
+    /// Use the `Display` implementation of each element. Unlike `.join()`,
+    /// this appends to an existing buffer instead of allocating a new
+    /// `String`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut sql = String::from("INSERT INTO t VALUES (");
+    /// [1, 2, 3].iter().join_into(&mut sql, ", ").unwrap();
+    /// sql.push(')');
+    /// assert_eq!(sql, "INSERT INTO t VALUES (1, 2, 3)");
+    /// ```
+    fn join_into<W: ?Sized>(&mut self, out: &mut W, sep: &str) -> fmt::Result
+        where W: fmt::Write,
+              Self::Item: fmt::Display
+    {
+        write!(out, "{}", Itertools::format(self, sep))
+    }
+
+    /// Write all iterator elements into `out`, an `io::Write` sink such as
+    /// a file or socket, separated by `sep`.
+    ///
+    /// Use the `Display` implementation of each element. Nothing is
+    /// buffered, so wrap `out` in a `BufWriter` when writing many small
+    /// elements to an unbuffered sink.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut csv = Vec::new();
+    /// ["a", "b", "c"].iter().write_join(&mut csv, ",").unwrap();
+    /// assert_eq!(csv, b"a,b,c");
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn write_join<W: ?Sized>(&mut self, out: &mut W, sep: &str) -> io::Result<()>
+        where W: io::Write,
+              Self::Item: fmt::Display
+    {
+        write!(out, "{}", Itertools::format(self, sep))
+    }
+
+    /// Format all iterator elements, separated by `sep`.
+    ///
+    /// All elements are formatted (any formatting trait)
+    /// with `sep` inserted between each element. The formatting options,
+    /// like a precision, are applied to each element.
+    ///
+    /// Unlike `.join()`, no intermediate `String` is allocated: the elements
+    /// are written directly into the formatter.
+    ///
+    /// **Note:** The iterator is consumed by the first formatting; formatting
+    /// the value again produces only what is left of it.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = [1.1, 2.71828, -3.];
+    /// assert_eq!(
+    ///     format!("{:.2}", data.iter().format(", ")),
+    ///            "1.10, 2.72, -3.00");
+    ///
+    /// assert_eq!(format!("{:?}", ["a", "b"].iter().format(" - ")), "\"a\" - \"b\"");
+    /// ```
+    fn format<'a>(self, sep: &'a str) -> Format<'a, Self>
+        where Self: Sized,
+    {
+        format::new_format_default(self, sep)
+    }
+
+    /// Format at most `max_items` iterator elements, separated by `sep`,
+    /// and then the number of remaining elements as `… and N more`.
+    ///
+    /// Like `.format()`, nothing is collected: the elements that are not
+    /// shown are only counted. The suffix is left out if no element was
+    /// dropped.
+    ///
+    /// **Note:** The iterator is consumed by the first formatting.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!(format!("{}", (1..100).format_truncated(", ", 3)),
+    ///            "1, 2, 3, \u{2026} and 96 more");
+    /// assert_eq!(format!("{:02}", (1..4).format_truncated(", ", 3)),
+    ///            "01, 02, 03");
+    /// ```
+    fn format_truncated<'a>(self, sep: &'a str, max_items: usize) -> FormatTruncated<'a, Self>
+        where Self: Sized,
+    {
+        format::new_format_truncated(self, sep, max_items)
+    }
+
+    /// Format all iterator elements, separated by `sep`, using a custom
+    /// closure for each element.
+    ///
+    /// The supplied closure `format` is called once per iterator element,
+    /// with two arguments: the element and a callback that takes a
+    /// `&Display` value, i.e. any reference to type that implements `Display`.
+    ///
+    /// Using `&format_args!(...)` is the most versatile way to apply custom
+    /// element formatting. The callback can be called multiple times if needed.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = [1.1, 2.71828, -3.];
+    /// let data_formatter = data.iter().format_with(", ", |elt, f| f(&format_args!("{:2.2}", elt)));
+    /// assert_eq!(format!("{}", data_formatter),
+    ///            "1.10, 2.72, -3.00");
+    ///
+    /// // .format_with() is recursively composable
+    /// let matrix = [[1., 2., 3.],
+    ///               [4., 5., 6.]];
+    /// let matrix_formatter = matrix.iter().format_with("\n", |row, f| {
+    ///                                 f(&row.iter().format(", "))
+    ///                              });
+    /// assert_eq!(format!("{}", matrix_formatter),
+    ///            "1, 2, 3\n4, 5, 6");
+    /// ```
+    fn format_with<F>(self, sep: &str, format: F) -> FormatWith<Self, F>
+        where Self: Sized,
+              F: FnMut(Self::Item, &mut FnMut(&fmt::Display) -> fmt::Result) -> fmt::Result,
+    {
+        format::new_format(self, sep, format)
+    }
+
+    /// Fold `Result` values from an iterator.
+    ///
+    /// Only `Ok` values are folded. If no error is encountered, the folded
+    /// value is returned inside `Ok`. Otherwise, the operation terminates
+    /// and returns the first `Err` value it encounters. No iterator elements are
+    /// consumed after the first error.
+    ///
+    /// The first accumulator value is the `start` parameter.
+    /// Each iteration passes the accumulator value and the next value inside `Ok`
+    /// to the fold function `f` and its return value becomes the new accumulator value.
+    ///
+    /// For example the sequence *Ok(1), Ok(2), Ok(3)* will result in a
+    /// computation like this:
+    ///
+    /// ```ignore
+    /// let mut accum = start;
+    /// accum = f(accum, 1);
+    /// accum = f(accum, 2);
+    /// accum = f(accum, 3);
+    /// ```
+    ///
+    /// With a `start` value of 0 and an addition as folding function,
+    /// this effetively results in *((0 + 1) + 2) + 3*
+    ///
+    /// ```
+    /// use std::ops::Add;
+    /// use itertools::Itertools;
+    ///
+    /// let values = [1, 2, -2, -1, 2, 1];
+    /// assert_eq!(
+    ///     values.iter()
+    ///           .map(Ok::<_, ()>)
+    ///           .fold_ok(0, Add::add),
+    ///     Ok(3)
+    /// );
+    /// assert!(
+    ///     values.iter()
+    ///           .map(|&x| if x >= 0 { Ok(x) } else { Err("Negative number") })
+    ///           .fold_ok(0, Add::add)
+    ///           .is_err()
+    /// );
+    /// ```
+    fn fold_ok<A, E, B, F>(&mut self, mut start: B, mut f: F) -> Result<B, E>
+        where Self: Iterator<Item = Result<A, E>>,
+              F: FnMut(B, A) -> B
+    {
+        for elt in self {
+            match elt {
+                Ok(v) => start = f(start, v),
+                Err(u) => return Err(u),
+            }
+        }
+        Ok(start)
+    }
+
+    /// **Deprecated:** renamed to `.fold_ok()`
+    fn fold_results<A, E, B, F>(&mut self, start: B, f: F) -> Result<B, E>
+        where Self: Iterator<Item = Result<A, E>>,
+              F: FnMut(B, A) -> B
+    {
+        self.fold_ok(start, f)
+    }
+
+    /// Run the closure `processor` on an iterator of the `Ok` values, stopping
+    /// at the first `Err`.
+    ///
+    /// Return the closure's result wrapped in `Ok`, or the first error. No
+    /// elements are consumed after the first error.
+    ///
+    /// See [`process_results`](fn.process_results.html) for more information.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let values = vec![Ok(1), Ok(4), Ok(2)];
+    /// let sum = values.into_iter().process_results(|it| it.sum::<i32>());
+    /// assert_eq!(sum, Ok::<_, &str>(7));
+    ///
+    /// let values = vec![Ok(1), Err("bad"), Ok(2)];
+    /// let joined = values.into_iter().process_results(|mut it| it.join(", "));
+    /// assert_eq!(joined, Err("bad"));
+    /// ```
+    fn process_results<F, T, E, R>(self, processor: F) -> Result<R, E>
+        where Self: Sized + Iterator<Item = Result<T, E>>,
+              F: FnOnce(ProcessResults<Self, E>) -> R
+    {
+        process_results(self, processor)
+    }
+
+    /// Fold `Option` values from an iterator.
+    ///
+    /// Only `Some` values are folded. If no `None` is encountered, the folded
+    /// value is returned inside `Some`. Otherwise, the operation terminates
+    /// and returns `None`. No iterator elements are consumed after the `None`.
+    ///
+    /// This is the `Option` equivalent to `fold_ok`.
+    ///
+    /// ```
+    /// use std::ops::Add;
+    /// use itertools::Itertools;
+    ///
+    /// let mut values = vec![Some(1), Some(2), Some(-2)].into_iter();
+    /// assert_eq!(values.fold_options(5, Add::add), Some(5 + 1 + 2 - 2));
+    ///
+    /// let mut more_values = vec![Some(2), None, Some(0)].into_iter();
+    /// assert!(more_values.fold_options(0, Add::add).is_none());
+    /// assert_eq!(more_values.next().unwrap(), Some(0));
+    /// ```
+    fn fold_options<A, B, F>(&mut self, mut start: B, mut f: F) -> Option<B>
+        where Self: Iterator<Item = Option<A>>,
+              F: FnMut(B, A) -> B
+    {
+        for elt in self {
+            match elt {
+                Some(v) => start = f(start, v),
+                None => return None,
+            }
+        }
+        Some(start)
+    }
+
+    /// Accumulator of the elements in the iterator.
+    ///
+    /// Like `.fold()`, without a base case. If the iterator is
+    /// empty, return `None`. With just one element, return it.
+    /// Otherwise elements are accumulated in sequence using the closure `f`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!((0..10).fold1(|x, y| x + y).unwrap_or(0), 45);
+    /// assert_eq!((0..0).fold1(|x, y| x * y), None);
+    /// ```
+    fn fold1<F>(&mut self, mut f: F) -> Option<Self::Item>
+        where F: FnMut(Self::Item, Self::Item) -> Self::Item
+    {
+        match self.next() {
+            None => None,
+            Some(mut x) => {
+                for y in self {
+                    x = f(x, y);
+                }
+                Some(x)
+            }
+        }
+    }
+
+    /// Sum the elements of the iterator, or return `None` if it is empty.
+    ///
+    /// Unlike `.sum()`, an empty iterator is told apart from one whose
+    /// elements add up to zero.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let readings: Vec<f64> = vec![];
+    /// assert_eq!(readings.iter().sum1::<f64>(), None);
+    /// assert_eq!((1..5).sum1(), Some(10));
+    /// assert_eq!(vec![-1, 1].into_iter().sum1(), Some(0));
+    /// ```
+    fn sum1<S>(mut self) -> Option<S>
+        where Self: Sized,
+              S: Sum<Self::Item>
+    {
+        self.next().map(|first| iter::once(first).chain(self).sum())
+    }
+
+    /// Multiply the elements of the iterator, or return `None` if it is
+    /// empty.
+    ///
+    /// Unlike `.product()`, an empty iterator is told apart from one whose
+    /// elements multiply to one.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!((1..1).product1::<i32>(), None);
+    /// assert_eq!((1..5).product1(), Some(24));
+    /// assert_eq!([0.5, 2.].iter().product1::<f64>(), Some(1.));
+    /// ```
+    fn product1<P>(mut self) -> Option<P>
+        where Self: Sized,
+              P: iter::Product<Self::Item>
+    {
+        self.next().map(|first| iter::once(first).chain(self).product())
+    }
+
+    /// Combine all the iterator's containers into one, in order.
+    ///
+    /// The first container is extended with the elements of all the
+    /// others. An empty iterator gives `Default::default()`.
+    ///
+    /// `String` is not `IntoIterator`, so it can't be used here; an iterator
+    /// of strings can be concatenated with `.collect::<String>()` instead.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let rows = vec![vec![1, 2], vec![], vec![3]];
+    /// assert_eq!(rows.into_iter().concat(), vec![1, 2, 3]);
+    ///
+    /// assert_eq!(Vec::<Vec<u8>>::new().into_iter().concat(), vec![]);
+    /// ```
+    fn concat(self) -> Self::Item
+        where Self: Sized,
+              Self::Item: Extend<<Self::Item as IntoIterator>::Item> + IntoIterator + Default
+    {
+        let mut iter = self;
+        iter.fold1(|mut a, b| { a.extend(b); a }).unwrap_or_else(Default::default)
+    }
+
+    /// Accumulate the elements in the iterator in a tree-like manner.
+    ///
+    /// You can think of it as, while there's more than one item, repeatedly
+    /// combining adjacent items.  It does so in bottom-up-merge-sort order,
+    /// however, so that it needs only logarithmic stack space.
+    ///
+    /// This produces a call tree like the following (where the calls under
+    /// an item are done after reading that item):
+    ///
+    /// ```text
+    /// 1 2 3 4 5 6 7
+    /// │ │ │ │ │ │ │
+    /// └─f └─f └─f │
+    ///   │   │   │ │
+    ///   └───f   └─f
+    ///       │     │
+    ///       └─────f
+    /// ```
+    ///
+    /// Which, for non-associative functions, will typically produce a different
+    /// result than the linear call tree used by `.fold1()`:
+    ///
+    /// ```text
+    /// 1 2 3 4 5 6 7
+    /// │ │ │ │ │ │ │
+    /// └─f─f─f─f─f─f
+    /// ```
+    ///
+    /// If `f` is associative, prefer the normal `.fold1()` instead.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // The same tree as above
+    /// let num_strings = (1..8).map(|x| x.to_string());
+    /// assert_eq!(num_strings.tree_reduce(|x, y| format!("f({}, {})", x, y)),
+    ///     Some(String::from("f(f(f(1, 2), f(3, 4)), f(f(5, 6), 7))")));
+    ///
+    /// // Like fold1, an empty iterator produces None
+    /// assert_eq!((0..0).tree_reduce(|x, y| x * y), None);
+    ///
+    /// // tree_reduce matches fold1 for associative operations...
+    /// assert_eq!((0..10).tree_reduce(|x, y| x + y),
+    ///     (0..10).fold1(|x, y| x + y));
+    /// // ...but not for non-associative ones
+    /// assert!((0..10).tree_reduce(|x, y| x - y) !=
+    ///     (0..10).fold1(|x, y| x - y));
+    /// ```
+    fn tree_reduce<F>(mut self, mut f: F) -> Option<Self::Item>
+        where F: FnMut(Self::Item, Self::Item) -> Self::Item,
+              Self: Sized,
+    {
+        // `Ok` is a complete tree of the requested height, `Err` means the
+        // iterator ran out, with whatever could be combined so far.
+        type State<T> = Result<T, Option<T>>;
+
+        fn inner0<T, II, FF>(it: &mut II, f: &mut FF) -> State<T>
+            where II: Iterator<Item = T>,
+                  FF: FnMut(T, T) -> T
+        {
+            // This function could be replaced with `it.next().ok_or(None)`,
+            // but half the useful tree_reduce work is combining adjacent items,
+            // so put that in a form that LLVM is more likely to optimize well.
+            let a = match it.next() {
+                Some(v) => v,
+                None => return Err(None),
+            };
+            let b = match it.next() {
+                Some(v) => v,
+                None => return Err(Some(a)),
+            };
+            Ok(f(a, b))
+        }
+
+        fn inner<T, II, FF>(stop: usize, it: &mut II, f: &mut FF) -> State<T>
+            where II: Iterator<Item = T>,
+                  FF: FnMut(T, T) -> T
+        {
+            let mut x = match inner0(it, f) {
+                Ok(x) => x,
+                Err(e) => return Err(e),
+            };
+            for height in 0..stop {
+                // Try to get another tree the same size with which to combine it,
+                // creating a new tree that's twice as big for next time around.
+                let next = if height == 0 {
+                    inner0(it, f)
+                } else {
+                    inner(height, it, f)
+                };
+                match next {
+                    Ok(y) => x = f(x, y),
+
+                    // If we ran out of items, combine whatever we did manage
+                    // to get.  It's better combined with the current value
+                    // than something in a parent frame, because the tree in
+                    // the parent is always as least as big as this one.
+                    Err(None) => return Err(Some(x)),
+                    Err(Some(y)) => return Err(Some(f(x, y))),
+                }
+            }
+            Ok(x)
+        }
+
+        match inner(::std::usize::MAX, &mut self, &mut f) {
+            Err(x) => x,
+            _ => unreachable!(),
+        }
+    }
+
+    /// An iterator method that applies a function, producing a single, final value.
+    ///
+    /// `fold_while()` is basically equivalent to `fold()` but with additional support for
+    /// early exit via short-circuiting: the closure returns `FoldWhile::Continue(acc)`
+    /// to go on with the next element, or `FoldWhile::Done(acc)` to stop.
+    ///
+    /// The result is `Done` if the closure stopped the fold, and `Continue` if
+    /// the iterator ran out first; use `.into_inner()` to get the value either
+    /// way. No elements are consumed after the one that made the closure return
+    /// `Done`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::FoldWhile::{Continue, Done};
+    ///
+    /// let numbers = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    ///
+    /// // sum until the budget of 10 would be exceeded
+    /// let result = numbers.iter().fold_while(0, |acc, x| {
+    ///     if acc + x > 10 { Done(acc) } else { Continue(acc + x) }
+    /// });
+    /// assert_eq!(result, Done(10));
+    ///
+    /// let result = numbers.iter().fold_while(0, |acc, x| {
+    ///     if acc + x > 100 { Done(acc) } else { Continue(acc + x) }
+    /// });
+    /// assert!(!result.is_done());
+    /// assert_eq!(result.into_inner(), 55);
+    /// ```
+    fn fold_while<B, F>(&mut self, init: B, mut f: F) -> FoldWhile<B>
+        where F: FnMut(B, Self::Item) -> FoldWhile<B>
+    {
+        let mut acc = init;
+        for elt in self {
+            match f(acc, elt) {
+                FoldWhile::Continue(res) => acc = res,
+                res @ FoldWhile::Done(_) => return res,
+            }
+        }
+        FoldWhile::Continue(acc)
+    }
+
+    /// Return the minimum element of an iterator of partially ordered
+    /// elements, or an error if two elements are found to be incomparable.
+    ///
+    /// If the iterator is empty, return `Ok(None)`. If several elements are
+    /// equally minimum, the first element is returned.
+    ///
+    /// If an element can't be compared with the current minimum (for
+    /// example a NaN), the operation stops and returns `Err((min, elt))`
+    /// with the minimum so far and the offending element.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!(vec![2., 1., 3.].into_iter().try_min(), Ok(Some(1.)));
+    /// assert_eq!(Vec::<f64>::new().into_iter().try_min(), Ok(None));
+    ///
+    /// let err = vec![2., 1., std::f64::NAN, 0.].into_iter().try_min().unwrap_err();
+    /// assert_eq!(err.0, 1.);
+    /// assert!(err.1.is_nan());
+    /// ```
+    fn try_min(&mut self) -> Result<Option<Self::Item>, (Self::Item, Self::Item)>
+        where Self::Item: PartialOrd
+    {
+        let mut min = match self.next() {
+            None => return Ok(None),
+            Some(elt) => elt,
+        };
+        for elt in self {
+            match elt.partial_cmp(&min) {
+                Some(Ordering::Less) => min = elt,
+                Some(_) => {}
+                None => return Err((min, elt)),
+            }
+        }
+        Ok(Some(min))
+    }
+
+    /// Return the maximum element of an iterator of partially ordered
+    /// elements, or an error if two elements are found to be incomparable.
+    ///
+    /// If the iterator is empty, return `Ok(None)`. If several elements are
+    /// equally maximum, the last element is returned.
+    ///
+    /// If an element can't be compared with the current maximum (for
+    /// example a NaN), the operation stops and returns `Err((max, elt))`
+    /// with the maximum so far and the offending element.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!(vec![2., 1., 3.].into_iter().try_max(), Ok(Some(3.)));
+    /// assert_eq!(Vec::<f64>::new().into_iter().try_max(), Ok(None));
+    ///
+    /// let err = vec![2., 1., std::f64::NAN, 4.].into_iter().try_max().unwrap_err();
+    /// assert_eq!(err.0, 2.);
+    /// assert!(err.1.is_nan());
+    /// ```
+    fn try_max(&mut self) -> Result<Option<Self::Item>, (Self::Item, Self::Item)>
+        where Self::Item: PartialOrd
+    {
+        let mut max = match self.next() {
+            None => return Ok(None),
+            Some(elt) => elt,
+        };
+        for elt in self {
+            match elt.partial_cmp(&max) {
+                Some(Ordering::Less) => {}
+                Some(_) => max = elt,
+                None => return Err((max, elt)),
+            }
+        }
+        Ok(Some(max))
+    }
+
+    /// Return the dot product of the two sequences: the sum of the products
+    /// of their elements, pairwise.
+    ///
+    /// If one sequence is longer, its extra elements are ignored.
+    ///
+    /// The products are added into four interleaved partial sums, which
+    /// lets the additions run in parallel on the CPU. This is about twice
+    /// as fast as `.zip().map().sum()` for floating point elements, whose
+    /// result may therefore round differently from a sequential sum. For
+    /// integers, which the compiler can already vectorize in the plain
+    /// chain, that chain is faster.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = [1., 2., 3.];
+    /// let b = [4., 5., 6.];
+    /// assert_eq!(a.iter().dot(&b), 32.);
+    /// assert_eq!((1..4).dot(vec![2, 2, 2]), 12);
+    /// ```
+    fn dot<J>(self, other: J) -> <Self::Item as Mul<J::Item>>::Output
+        where Self: Sized,
+              J: IntoIterator,
+              Self::Item: Mul<J::Item>,
+              <Self::Item as Mul<J::Item>>::Output: Add<Output = <Self::Item as Mul<J::Item>>::Output> + Sum
+    {
+        dot::sum_interleaved(self.zip(other).map(|(a, b)| a * b))
+    }
+
+    /// Return the sum of the squares of the elements.
+    ///
+    /// Like [*.dot()*](#method.dot), this adds into four partial sums.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!([3., 4.].iter().norm_squared(), 25.);
+    /// assert_eq!((1..4).norm_squared(), 14);
+    /// assert_eq!((0..0).norm_squared(), 0);
+    /// ```
+    fn norm_squared(self) -> <Self::Item as Mul>::Output
+        where Self: Sized,
+              Self::Item: Mul + Clone,
+              <Self::Item as Mul>::Output: Add<Output = <Self::Item as Mul>::Output> + Sum
+    {
+        dot::sum_interleaved(self.map(|x| x.clone() * x))
+    }
+
+    /// Sum the elements of each run of consecutive elements that map to the
+    /// same key, and collect the `(key, sum)` pairs into a vector.
+    ///
+    /// If the iterator is sorted by the key, the result holds one sum per
+    /// distinct key, in key order, and no hashing is needed. Otherwise each
+    /// run of equal keys gets its own entry.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // sum the values of each decade
+    /// let data = vec![1, 4, 12, 15, 17, 31];
+    /// assert_eq!(data.into_iter().sum_by_sorted_key(|x| x / 10),
+    ///            vec![(0, 5), (1, 44), (3, 31)]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn sum_by_sorted_key<K, F>(self, mut key: F) -> Vec<(K, Self::Item)>
+        where Self: Sized,
+              Self::Item: Add<Output = Self::Item>,
+              K: PartialEq,
+              F: FnMut(&Self::Item) -> K
+    {
+        let mut sums: Vec<(K, Self::Item)> = Vec::new();
+        for elt in self {
+            let elt_key = key(&elt);
+            match sums.pop() {
+                None => sums.push((elt_key, elt)),
+                Some((last_key, sum)) => {
+                    if last_key == elt_key {
+                        sums.push((last_key, sum + elt));
+                    } else {
+                        sums.push((last_key, sum));
+                        sums.push((elt_key, elt));
+                    }
+                }
+            }
+        }
+        sums
+    }
+
+    /// Tell if the iterator is empty or not according to its size hint.
+    /// Return `None` if the size hint does not tell, or return a `Some`
+    /// value with the emptiness if it's possible to tell.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!((1..1).is_empty_hint(), Some(true));
+    /// assert_eq!([1, 2, 3].iter().is_empty_hint(), Some(false));
+    /// assert_eq!((0..10).filter(|&x| x > 0).is_empty_hint(), None);
+    /// ```
+    fn is_empty_hint(&self) -> Option<bool> {
+        let (low, opt_hi) = self.size_hint();
+        // check for erronous hint
+        if let Some(hi) = opt_hi {
+            if hi < low { return None }
+        }
+
+        if opt_hi == Some(0) {
+            Some(true)
+        } else if low > 0 {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Check whether `pred` holds for any window of `n` consecutive
+    /// elements, and stop at the first one for which it does.
+    ///
+    /// The windows are passed as slices into a buffer of at most `2 * n`
+    /// elements, without an allocation per window. An iterator with fewer
+    /// than `n` elements has no windows and returns `false`.
+    ///
+    /// **Panics** if `n` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let events = vec![Ok(()), Err("timeout"), Err("timeout"), Err("refused"), Ok(())];
+    /// assert!(events.iter().any_window(3, |w| w.iter().all(|e| e.is_err())));
+    /// assert!(!events.iter().any_window(4, |w| w.iter().all(|e| e.is_err())));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn any_window<F>(&mut self, n: usize, mut pred: F) -> bool
+        where F: FnMut(&[Self::Item]) -> bool
+    {
+        assert!(n != 0, "any_window: window size must be non-zero");
+        let mut windows = Windows::new(self, n);
+        while let Some(window) = windows.next_window() {
+            if pred(window) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Check whether `pred` holds for every window of `n` consecutive
+    /// elements, and stop at the first one for which it does not.
+    ///
+    /// An iterator with fewer than `n` elements has no windows and returns
+    /// `true`. See [*.any_window()*](#method.any_window) for more information.
+    ///
+    /// **Panics** if `n` is 0.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let temps = [20i32, 21, 23, 22, 25];
+    /// assert!(temps.iter().cloned().all_windows(2, |w| (w[0] - w[1]).abs() <= 3));
+    /// assert!(!temps.iter().cloned().all_windows(2, |w| w[0] < w[1]));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn all_windows<F>(&mut self, n: usize, mut pred: F) -> bool
+        where F: FnMut(&[Self::Item]) -> bool
+    {
+        !self.any_window(n, |w| !pred(w))
+    }
+
+    /// Check whether all elements compare equal.
+    ///
+    /// Empty iterators are considered to have equal elements.
+    ///
+    /// Consumes the iterator until the first element that differs from
+    /// the first element, or until its end.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1, 1, 1, 2, 2, 3, 3, 3, 4, 5, 5];
+    /// assert!(!data.iter().all_equal());
+    /// assert!(data[0..3].iter().all_equal());
+    /// assert!(data[3..5].iter().all_equal());
+    /// assert!(data[5..8].iter().all_equal());
+    ///
+    /// let data : Option<usize> = None;
+    /// assert!(data.into_iter().all_equal());
+    /// ```
+    fn all_equal(&mut self) -> bool
+        where Self::Item: PartialEq,
+    {
+        let first = match self.next() {
+            None => return true,
+            Some(elt) => elt,
+        };
+        for elt in self {
+            if first != elt {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// If there are elements and they are all equal, return the first one.
+    /// Otherwise return the evidence for why they are not.
+    ///
+    /// - `Err(None)` if the iterator is empty.
+    /// - `Err(Some((first, other)))` with the first element and the first
+    ///   element that differs from it.
+    ///
+    /// Consumes the iterator until the first element that differs from
+    /// the first element, or until its end.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1, 1, 1, 2, 2, 3, 3, 3, 4, 5, 5];
+    /// assert_eq!(data.iter().all_equal_value(), Err(Some((&1, &2))));
+    /// assert_eq!(data[0..3].iter().all_equal_value(), Ok(&1));
+    /// assert_eq!(data[3..5].iter().all_equal_value(), Ok(&2));
+    ///
+    /// let data : Option<usize> = None;
+    /// assert_eq!(data.into_iter().all_equal_value(), Err(None));
+    /// ```
+    fn all_equal_value(&mut self) -> Result<Self::Item, Option<(Self::Item, Self::Item)>>
+        where Self::Item: PartialEq,
+    {
+        let first = match self.next() {
+            None => return Err(None),
+            Some(elt) => elt,
+        };
+        for elt in self {
+            if first != elt {
+                return Err(Some((first, elt)));
+            }
+        }
+        Ok(first)
+    }
+
+    /// Check whether all elements are unique (non equal).
+    ///
+    /// Empty iterators are considered to have unique elements.
+    ///
+    /// Consumes the iterator until the first duplicate is found, or until
+    /// its end. The elements seen so far are stored in a hash set.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1, 2, 3, 4, 1, 5];
+    /// assert!(!data.iter().all_unique());
+    /// assert!(data[0..4].iter().all_unique());
+    /// assert!(data[1..6].iter().all_unique());
+    ///
+    /// let data : Option<usize> = None;
+    /// assert!(data.into_iter().all_unique());
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn all_unique(&mut self) -> bool
+        where Self::Item: Eq + Hash
+    {
+        let mut used = HashSet::new();
+        for elt in self {
+            if !used.insert(elt) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Return `true` if the given item is present in this iterator.
+    ///
+    /// The iterator's elements only need to `Borrow` the query type, so for
+    /// example an iterator of `String` can be searched with a `&str`.
+    ///
+    /// Consumes the iterator until the item is found, or until its end.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let words = vec!["apple", "banana", "cherry"];
+    /// assert!(words.iter().contains(&"banana"));
+    /// assert!(!words.iter().contains(&"grape"));
+    ///
+    /// let owned: Vec<String> = words.iter().map(|s| s.to_string()).collect();
+    /// assert!(owned.into_iter().contains("cherry"));
+    /// ```
+    fn contains<Q: ?Sized>(&mut self, query: &Q) -> bool
+        where Self::Item: Borrow<Q>,
+              Q: PartialEq,
+    {
+        for elt in self {
+            if elt.borrow() == query {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Count the elements that are equal to `value`.
+    ///
+    /// Consumes the whole iterator.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1, 2, 1, 3, 1];
+    /// assert_eq!(data.iter().count_occurrences(&1), 3);
+    /// assert_eq!(data.iter().count_occurrences(&4), 0);
+    ///
+    /// let words = vec![String::from("a"), String::from("b"), String::from("a")];
+    /// assert_eq!(words.into_iter().count_occurrences("a"), 2);
+    /// ```
+    fn count_occurrences<Q: ?Sized>(&mut self, value: &Q) -> usize
+        where Self::Item: Borrow<Q>,
+              Q: PartialEq,
+    {
+        self.fold(0, |count, elt| if elt.borrow() == value { count + 1 } else { count })
+    }
+
+    /// Count the elements that are equal to `value`, stopping as soon as
+    /// `max` of them have been seen.
+    ///
+    /// Return the number found, which is at most `max`. The iterator is
+    /// not advanced past the `max`-th equal element.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut iter = vec![1, 2, 1, 3, 1].into_iter();
+    /// assert_eq!(iter.count_occurrences_max(&1, 2), 2);
+    /// itertools::assert_equal(iter, vec![3, 1]);
+    ///
+    /// assert_eq!((0..10).count_occurrences_max(&7, 5), 1);
+    /// ```
+    fn count_occurrences_max<Q: ?Sized>(&mut self, value: &Q, max: usize) -> usize
+        where Self: Sized,
+              Self::Item: Borrow<Q>,
+              Q: PartialEq,
+    {
+        if max == 0 {
+            return 0;
+        }
+        // `Err` carries the count out early once `max` is reached
+        let counted = self.try_fold(0, |count, elt| {
+            if elt.borrow() != value {
+                Ok(count)
+            } else if count + 1 == max {
+                Err(max)
+            } else {
+                Ok(count + 1)
+            }
+        });
+        match counted {
+            Ok(count) | Err(count) => count,
+        }
+    }
+
+    /// Collect the elements for which `pred` returns `true` into one `Vec`,
+    /// and the others into another, and return them in that order.
+    ///
+    /// The partition is stable: both vectors keep the elements in the
+    /// order the iterator yielded them. Each vector starts with room for
+    /// half of the iterator's lower size hint.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let jobs = vec![(1, "ok"), (2, "failed"), (3, "ok"), (4, "failed")];
+    /// let (ok, failed) = jobs.into_iter().partition_stable(|j| j.1 == "ok");
+    /// assert_eq!(ok, [(1, "ok"), (3, "ok")]);
+    /// assert_eq!(failed, [(2, "failed"), (4, "failed")]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn partition_stable<F>(self, mut pred: F) -> (Vec<Self::Item>, Vec<Self::Item>)
+        where Self: Sized,
+              F: FnMut(&Self::Item) -> bool
+    {
+        let (lower, _) = self.size_hint();
+        let mut left = Vec::with_capacity(lower / 2);
+        let mut right = Vec::with_capacity(lower / 2);
+        for elt in self {
+            if pred(&elt) {
+                left.push(elt);
+            } else {
+                right.push(elt);
+            }
+        }
+        (left, right)
+    }
+
+    /// Collect all iterator elements into one of two partitions, mapping
+    /// each element on the way.
+    ///
+    /// `predicate` returns `Either::Left(a)` to add `a` to the first
+    /// collection or `Either::Right(b)` to add `b` to the second. Unlike
+    /// `Iterator::partition`, the two sides may have different types.
+    ///
+    /// ```
+    /// use itertools::{Itertools, Either};
+    ///
+    /// let tokens = vec!["12", "x", "7", "yz", "3"];
+    ///
+    /// let (numbers, words): (Vec<u32>, Vec<&str>) = tokens
+    ///     .into_iter()
+    ///     .partition_map(|t| match t.parse::<u32>() {
+    ///         Ok(n) => Either::Left(n),
+    ///         Err(_) => Either::Right(t),
+    ///     });
+    ///
+    /// assert_eq!(numbers, vec![12, 7, 3]);
+    /// assert_eq!(words, vec!["x", "yz"]);
+    /// ```
+    fn partition_map<A, B, F, L, R>(self, mut predicate: F) -> (A, B)
+        where Self: Sized,
+              F: FnMut(Self::Item) -> Either<L, R>,
+              A: Default + Extend<L>,
+              B: Default + Extend<R>,
+    {
+        let mut left = A::default();
+        let mut right = B::default();
+
+        for elt in self {
+            match predicate(elt) {
+                Either::Left(v) => left.extend(Some(v)),
+                Either::Right(v) => right.extend(Some(v)),
+            }
+        }
+
+        (left, right)
+    }
+
+    /// Partition a sequence of `Result`s into one collection of all the `Ok`
+    /// values and one of all the `Err` values, in a single pass.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let results = vec![Ok(1), Err("bad"), Ok(2), Err("worse"), Ok(3)];
+    ///
+    /// let (successes, failures): (Vec<i32>, Vec<&str>) = results
+    ///     .into_iter()
+    ///     .partition_result();
+    ///
+    /// assert_eq!(successes, vec![1, 2, 3]);
+    /// assert_eq!(failures, vec!["bad", "worse"]);
+    /// ```
+    fn partition_result<A, B, T, E>(self) -> (A, B)
+        where Self: Iterator<Item = Result<T, E>> + Sized,
+              A: Default + Extend<T>,
+              B: Default + Extend<E>,
+    {
+        self.partition_map(|r| match r {
+            Ok(v) => Either::Left(v),
+            Err(v) => Either::Right(v),
+        })
+    }
+
+    /// Unzip an iterator of tuples into a tuple of collections, one per
+    /// tuple position.
+    ///
+    /// This generalizes `Iterator::unzip` to tuples of 1 to 12 elements. Each
+    /// collection can be any type that implements `Default` and `Extend`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let inputs = vec![(1, 'a', "x"), (2, 'b', "y"), (3, 'c', "z")];
+    ///
+    /// let (nums, chars, strs): (Vec<_>, String, Vec<_>) = inputs
+    ///     .into_iter()
+    ///     .multiunzip();
+    ///
+    /// assert_eq!(nums, vec![1, 2, 3]);
+    /// assert_eq!(chars, "abc");
+    /// assert_eq!(strs, vec!["x", "y", "z"]);
+    /// ```
+    fn multiunzip<FromI>(self) -> FromI
+        where Self: Sized + MultiUnzip<FromI>,
+    {
+        MultiUnzip::multiunzip(self)
+    }
+
+    /// Collect the iterator's elements into a `HashMap` of each distinct
+    /// element and the number of times it occurs.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let counts = "hello".chars().counts();
+    /// assert_eq!(counts[&'l'], 2);
+    /// assert_eq!(counts[&'h'], 1);
+    /// assert_eq!(counts.get(&'z'), None);
+    /// assert_eq!(counts.len(), 4);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn counts(self) -> HashMap<Self::Item, usize>
+        where Self: Sized,
+              Self::Item: Eq + Hash
+    {
+        let mut counts = HashMap::new();
+        for elt in self {
+            *counts.entry(elt).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Collect the keys computed by `f` from the iterator's elements into a
+    /// `HashMap` of each distinct key and the number of elements that have it.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let words = vec!["apple", "avocado", "banana", "cherry", "blueberry"];
+    /// let counts = words.into_iter().counts_by(|w| w.chars().next().unwrap());
+    /// assert_eq!(counts[&'a'], 2);
+    /// assert_eq!(counts[&'b'], 2);
+    /// assert_eq!(counts[&'c'], 1);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn counts_by<K, F>(self, mut f: F) -> HashMap<K, usize>
+        where Self: Sized,
+              K: Eq + Hash,
+              F: FnMut(Self::Item) -> K
+    {
+        let mut counts = HashMap::new();
+        for elt in self {
+            *counts.entry(f(elt)).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Return a `HashMap` of the elements that occur more than once in the
+    /// iterator, mapped to the number of times they occur.
+    ///
+    /// Elements that occur only once are tracked in a set while iterating,
+    /// without a count, and are not part of the result.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let ids = vec![4, 8, 15, 16, 23, 42, 8, 15, 8];
+    /// let report = ids.into_iter().duplicates_report();
+    /// assert_eq!(report.len(), 2);
+    /// assert_eq!(report[&8], 3);
+    /// assert_eq!(report[&15], 2);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn duplicates_report(self) -> HashMap<Self::Item, usize>
+        where Self: Sized,
+              Self::Item: Eq + Hash
+    {
+        let mut seen_once = HashSet::new();
+        let mut report = HashMap::new();
+        for elt in self {
+            if let Some(count) = report.get_mut(&elt) {
+                *count += 1;
+                continue;
+            }
+            if seen_once.remove(&elt) {
+                report.insert(elt, 2);
+            } else {
+                seen_once.insert(elt);
+            }
+        }
+        report
+    }
+
+    /// Return a `HashMap` of keys mapped to `Vec`s of values. Keys and values
+    /// are taken from `(Key, Value)` tuple pairs yielded by the input iterator.
+    ///
+    /// Unlike `.group_by()`, elements with equal keys are grouped together
+    /// wherever they occur, not only when they are consecutive. Within each
+    /// `Vec`, the values keep their original order.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![(0, 10), (2, 12), (3, 13), (0, 20), (3, 33), (2, 42)];
+    /// let lookup = data.into_iter().into_group_map();
+    ///
+    /// assert_eq!(lookup[&0], vec![10, 20]);
+    /// assert_eq!(lookup.get(&1), None);
+    /// assert_eq!(lookup[&2], vec![12, 42]);
+    /// assert_eq!(lookup[&3], vec![13, 33]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn into_group_map<K, V>(self) -> HashMap<K, Vec<V>>
+        where Self: Iterator<Item = (K, V)> + Sized,
+              K: Hash + Eq,
+    {
+        group_map::into_group_map(self)
+    }
+
+    /// Return a `HashMap` of the keys computed by `f` mapped to `Vec`s of the
+    /// elements that have them.
+    ///
+    /// See [*.into_group_map()*](#method.into_group_map) for more information.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let words = vec!["apple", "banana", "avocado", "cherry", "blueberry"];
+    /// let lookup = words.into_iter().into_group_map_by(|w| w.chars().next().unwrap());
+    ///
+    /// assert_eq!(lookup[&'a'], vec!["apple", "avocado"]);
+    /// assert_eq!(lookup[&'b'], vec!["banana", "blueberry"]);
+    /// assert_eq!(lookup[&'c'], vec!["cherry"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn into_group_map_by<K, F>(self, f: F) -> HashMap<K, Vec<Self::Item>>
+        where Self: Sized,
+              K: Hash + Eq,
+              F: FnMut(&Self::Item) -> K,
+    {
+        group_map::into_group_map_by(self, f)
+    }
+
+    /// Return a `HashMap` of keys mapped to `Vec`s of values, like
+    /// [*.into_group_map()*](#method.into_group_map), but grouping in
+    /// parallel on rayon's thread pool.
+    ///
+    /// The pairs are collected on the current thread first. Within each
+    /// `Vec`, the values keep their original order.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = (0..1000).map(|i| (i % 3, i)).par_into_group_map();
+    ///
+    /// assert_eq!(lookup.len(), 3);
+    /// itertools::assert_equal(&lookup[&1], &(0..1000).filter(|i| i % 3 == 1).collect_vec());
+    /// ```
+    #[cfg(feature = "rayon")]
+    fn par_into_group_map<K, V>(self) -> HashMap<K, Vec<V>>
+        where Self: Iterator<Item = (K, V)> + Sized,
+              K: Hash + Eq + Send,
+              V: Send,
+    {
+        par::par_into_group_map(self)
+    }
+
+    /// Return an iterator adaptor that groups all elements with equal keys,
+    /// whether they are adjacent or not.
+    ///
+    /// Unlike `.group_by_lazy()`, which only groups consecutive runs, this
+    /// buffers the whole input on the first call to `next`, and then yields
+    /// one group per distinct key, in the order each key first appeared.
+    /// Within a group, elements keep their original order.
+    ///
+    /// Iterator element type is `(K, IntoIter<Self::Item>)`: the key and an
+    /// iterator of the group's elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1, 2, 3, 4, 5, 6, 7];
+    /// let mut groups = data.into_iter().group_by_hash(|x| x % 3);
+    ///
+    /// let (key, group) = groups.next().unwrap();
+    /// assert_eq!(key, 1);
+    /// itertools::assert_equal(group, vec![1, 4, 7]);
+    ///
+    /// let sizes = groups.map(|(key, group)| (key, group.len())).collect_vec();
+    /// assert_eq!(sizes, vec![(2, 2), (0, 2)]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn group_by_hash<K, F>(self, key: F) -> GroupByHash<K, Self, F>
+        where Self: Sized,
+              K: Hash + Eq,
+              F: FnMut(&Self::Item) -> K,
+    {
+        group_map::group_by_hash(self, key)
+    }
+
+    /// Constructs a `GroupingMap` to be used later with one of the efficient
+    /// group-and-fold operations it allows to perform.
+    ///
+    /// The input iterator must yield item in the form of `(K, V)` where the
+    /// value of type `K` will be used as key to identify the groups and the
+    /// value of type `V` as value for the folding operation.
+    ///
+    /// Each group is aggregated as its elements arrive, so unlike
+    /// `.into_group_map()` the values of a group are never collected into a
+    /// `Vec` first.
+    ///
+    /// See [`GroupingMap`](struct.GroupingMap.html) for more informations
+    /// on what operations are available.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let sales = vec![("north", 10), ("south", 4), ("north", 5), ("east", 7)];
+    /// let totals = sales.into_iter().into_grouping_map().sum();
+    ///
+    /// assert_eq!(totals["north"], 15);
+    /// assert_eq!(totals["south"], 4);
+    /// assert_eq!(totals["east"], 7);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn into_grouping_map<K, V>(self) -> GroupingMap<Self>
+        where Self: Iterator<Item = (K, V)> + Sized,
+              K: Hash + Eq,
+    {
+        grouping_map::new(self)
+    }
+
+    /// Constructs a `GroupingMap` to be used later with one of the efficient
+    /// group-and-fold operations it allows to perform.
+    ///
+    /// The values from this iterator will be used as values for the folding
+    /// operation while the keys will be obtained from the values by calling
+    /// `key_mapper`.
+    ///
+    /// See [`GroupingMap`](struct.GroupingMap.html) for more informations
+    /// on what operations are available.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let words = vec!["apple", "banana", "avocado", "cherry", "blueberry"];
+    /// let longest = words.into_iter()
+    ///     .into_grouping_map_by(|w| w.chars().next().unwrap())
+    ///     .max_by_key(|_key, w| w.len());
+    ///
+    /// assert_eq!(longest[&'a'], "avocado");
+    /// assert_eq!(longest[&'b'], "blueberry");
+    /// assert_eq!(longest[&'c'], "cherry");
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn into_grouping_map_by<K, F>(self, key_mapper: F) -> GroupingMapBy<Self, F>
+        where Self: Sized,
+              K: Hash + Eq,
+              F: FnMut(&Self::Item) -> K,
+    {
+        grouping_map::new_by(self, key_mapper)
+    }
+
+    /// Return the minimum and maximum elements in the iterator.
+    ///
+    /// The return type `MinMaxResult` is an enum of three variants:
+    ///
+    /// - `NoElements` if the iterator is empty.
+    /// - `OneElement(x)` if the iterator has exactly one element.
+    /// - `MinMax(x, y)` is returned otherwise, where `x <= y`. Two
+    ///    values are equal if and only if there is more than one
+    ///    element in the iterator and all elements are equal.
+    ///
+    /// On an iterator of length `n`, `minmax` does `1.5 * n` comparisons,
+    /// and so is faster than calling `min` and `max` separately which does
+    /// `2 * n` comparisons. It also works on single-pass iterators.
+    ///
+    /// If several elements are equally minimum, the first one is returned;
+    /// if several are equally maximum, the last one is returned.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::MinMaxResult::{NoElements, OneElement, MinMax};
+    ///
+    /// let a: [i32; 0] = [];
+    /// assert_eq!(a.iter().minmax(), NoElements);
+    ///
+    /// let a = [1];
+    /// assert_eq!(a.iter().minmax(), OneElement(&1));
+    ///
+    /// let a = [1, 2, 3, 4, 5];
+    /// assert_eq!(a.iter().minmax(), MinMax(&1, &5));
+    ///
+    /// let a = [1, 1, 1, 1];
+    /// assert_eq!(a.iter().minmax(), MinMax(&1, &1));
+    /// ```
+    fn minmax(self) -> MinMaxResult<Self::Item>
+        where Self: Sized,
+              Self::Item: PartialOrd
+    {
+        minmax::minmax_impl(self, |_| (), |x, y, _, _| x < y)
+    }
+
+    /// Return the minimum and maximum element of an iterator, as determined
+    /// by the comparison function `compare`.
+    ///
+    /// See [*.minmax()*](#method.minmax) for the return value and the
+    /// handling of equal elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::MinMaxResult::MinMax;
+    ///
+    /// let a = [-3i32, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().minmax_by(|x, y| x.abs().cmp(&y.abs())), MinMax(&0, &-10));
+    /// ```
+    fn minmax_by<F>(self, mut compare: F) -> MinMaxResult<Self::Item>
+        where Self: Sized,
+              F: FnMut(&Self::Item, &Self::Item) -> Ordering
+    {
+        minmax::minmax_impl(
+            self,
+            |_| (),
+            |x, y, _, _| Ordering::Less == compare(x, y)
+        )
+    }
+
+    /// Return the minimum and maximum element of an iterator, as determined
+    /// by the specified function.
+    ///
+    /// The key is computed once per element.
+    ///
+    /// See [*.minmax()*](#method.minmax) for the return value and the
+    /// handling of equal elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::MinMaxResult::MinMax;
+    ///
+    /// let words = ["bb", "a", "cccc", "ddd", "eeee"];
+    /// assert_eq!(words.iter().minmax_by_key(|w| w.len()), MinMax(&"a", &"eeee"));
+    /// ```
+    fn minmax_by_key<K, F>(self, key: F) -> MinMaxResult<Self::Item>
+        where Self: Sized,
+              K: PartialOrd,
+              F: FnMut(&Self::Item) -> K
+    {
+        minmax::minmax_impl(self, key, |_, _, xk, yk| xk < yk)
+    }
+
+    /// Return the minimum and maximum values of the iterator together with
+    /// the positions where they first occur and the number of elements,
+    /// all computed in a single pass.
+    ///
+    /// Return `None` if the iterator is empty, otherwise an
+    /// [`Extrema`](struct.Extrema.html).
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let readings = vec![3, 9, 1, 4, 9, 1, 5];
+    /// let e = readings.into_iter().extrema().unwrap();
+    /// assert_eq!((e.min, e.min_index), (1, 2));
+    /// assert_eq!((e.max, e.max_index), (9, 1));
+    /// assert_eq!(e.count, 7);
+    ///
+    /// assert_eq!((0..0).extrema(), None);
+    /// ```
+    fn extrema(self) -> Option<Extrema<Self::Item>>
+        where Self: Sized,
+              Self::Item: PartialOrd + Clone
+    {
+        minmax::extrema_impl(self)
+    }
+
+    /// Return the position of the maximum element in the iterator.
+    ///
+    /// If several elements are equally maximum, the position of the
+    /// last of them is returned, like `Iterator::max`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a: [i32; 0] = [];
+    /// assert_eq!(a.iter().position_max(), None);
+    ///
+    /// let a = [-3, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_max(), Some(3));
+    ///
+    /// let a = [1, 1, -1, -1];
+    /// assert_eq!(a.iter().position_max(), Some(1));
+    /// ```
+    fn position_max(self) -> Option<usize>
+        where Self: Sized,
+              Self::Item: Ord
+    {
+        self.enumerate()
+            .max_by(|x, y| Ord::cmp(&x.1, &y.1))
+            .map(|x| x.0)
+    }
+
+    /// Return the position of the maximum element in the iterator, as
+    /// determined by the specified function.
+    ///
+    /// If several elements are equally maximum, the position of the
+    /// last of them is returned, like `Iterator::max_by_key`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = [-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_max_by_key(|x| x.abs()), Some(4));
+    ///
+    /// let a = [1_i32, 1, -1, -1];
+    /// assert_eq!(a.iter().position_max_by_key(|x| x.abs()), Some(3));
+    /// ```
+    fn position_max_by_key<K, F>(self, mut key: F) -> Option<usize>
+        where Self: Sized,
+              K: Ord,
+              F: FnMut(&Self::Item) -> K
+    {
+        self.enumerate()
+            .max_by_key(|x| key(&x.1))
+            .map(|x| x.0)
+    }
+
+    /// Return the position of the maximum element in the iterator, as
+    /// determined by the specified comparison function.
+    ///
+    /// If several elements are equally maximum, the position of the
+    /// last of them is returned, like `Iterator::max_by`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = [-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_max_by(|x, y| x.cmp(y)), Some(3));
+    ///
+    /// let a = [1_i32, 1, -1, -1];
+    /// assert_eq!(a.iter().position_max_by(|x, y| x.cmp(y)), Some(1));
+    /// ```
+    fn position_max_by<F>(self, mut compare: F) -> Option<usize>
+        where Self: Sized,
+              F: FnMut(&Self::Item, &Self::Item) -> Ordering
+    {
+        self.enumerate()
+            .max_by(|x, y| compare(&x.1, &y.1))
+            .map(|x| x.0)
+    }
+
+    /// Return the position of the minimum element in the iterator.
+    ///
+    /// If several elements are equally minimum, the position of the
+    /// first of them is returned, like `Iterator::min`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a: [i32; 0] = [];
+    /// assert_eq!(a.iter().position_min(), None);
+    ///
+    /// let a = [-3, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_min(), Some(4));
+    ///
+    /// let a = [1, 1, -1, -1];
+    /// assert_eq!(a.iter().position_min(), Some(2));
+    /// ```
+    fn position_min(self) -> Option<usize>
+        where Self: Sized,
+              Self::Item: Ord
+    {
+        self.enumerate()
+            .min_by(|x, y| Ord::cmp(&x.1, &y.1))
+            .map(|x| x.0)
+    }
+
+    /// Return the position of the minimum element in the iterator, as
+    /// determined by the specified function.
+    ///
+    /// If several elements are equally minimum, the position of the
+    /// first of them is returned, like `Iterator::min_by_key`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = [-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_min_by_key(|x| x.abs()), Some(1));
+    ///
+    /// let a = [1_i32, 1, -1, -1];
+    /// assert_eq!(a.iter().position_min_by_key(|x| x.abs()), Some(0));
+    /// ```
+    fn position_min_by_key<K, F>(self, mut key: F) -> Option<usize>
+        where Self: Sized,
+              K: Ord,
+              F: FnMut(&Self::Item) -> K
+    {
+        self.enumerate()
+            .min_by_key(|x| key(&x.1))
+            .map(|x| x.0)
+    }
+
+    /// Return the position of the minimum element in the iterator, as
+    /// determined by the specified comparison function.
+    ///
+    /// If several elements are equally minimum, the position of the
+    /// first of them is returned, like `Iterator::min_by`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = [-3_i32, 0, 1, 5, -10];
+    /// assert_eq!(a.iter().position_min_by(|x, y| x.cmp(y)), Some(4));
+    ///
+    /// let a = [1_i32, 1, -1, -1];
+    /// assert_eq!(a.iter().position_min_by(|x, y| x.cmp(y)), Some(2));
+    /// ```
+    fn position_min_by<F>(self, mut compare: F) -> Option<usize>
+        where Self: Sized,
+              F: FnMut(&Self::Item, &Self::Item) -> Ordering
+    {
+        self.enumerate()
+            .min_by(|x, y| compare(&x.1, &y.1))
+            .map(|x| x.0)
+    }
+
+    /// Sort all iterator elements into a new iterator in ascending order.
+    ///
+    /// **Note:** This consumes the entire iterator, uses the
+    /// `slice::sort()` method and returns the result as a new
+    /// iterator that owns its elements.
+    ///
+    /// The sorted iterator, if directly collected to a `Vec`, is converted
+    /// without any extra copying or allocation cost.
+    ///
+    /// The sort runs on the current thread; with the `rayon` feature,
+    /// [*.par_sorted()*](#method.par_sorted) sorts in parallel instead.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // sort the letters of the text in ascending order
+    /// let text = "bdacfe";
+    /// itertools::assert_equal(text.chars().sorted(),
+    ///                         "abcdef".chars());
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn sorted(self) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              Self::Item: Ord
+    {
+        let mut v: Vec<Self::Item> = self.collect();
+
+        v.sort();
+        v.into_iter()
+    }
+
+    /// Sort all iterator elements into a new iterator in ascending order.
+    ///
+    /// **Note:** This consumes the entire iterator, uses the
+    /// `slice::sort_by()` method and returns the result as a new
+    /// iterator that owns its elements.
+    ///
+    /// The sorted iterator, if directly collected to a `Vec`, is converted
+    /// without any extra copying or allocation cost.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // sort people in descending order by age
+    /// let people = vec![("Jane", 20), ("John", 18), ("Jill", 30), ("Jack", 27)];
+    ///
+    /// let oldest_people_first = people
+    ///     .into_iter()
+    ///     .sorted_by(|a, b| Ord::cmp(&b.1, &a.1))
+    ///     .map(|(person, _age)| person);
+    ///
+    /// itertools::assert_equal(oldest_people_first,
+    ///                         vec!["Jill", "Jack", "Jane", "John"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn sorted_by<F>(self, cmp: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        let mut v: Vec<Self::Item> = self.collect();
+
+        v.sort_by(cmp);
+        v.into_iter()
+    }
+
+    /// Sort all iterator elements into a new iterator in ascending order,
+    /// comparing the keys computed by `f`.
+    ///
+    /// **Note:** This consumes the entire iterator, uses the
+    /// `slice::sort_by_key()` method and returns the result as a new
+    /// iterator that owns its elements.
+    ///
+    /// The sorted iterator, if directly collected to a `Vec`, is converted
+    /// without any extra copying or allocation cost.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // sort people in descending order by age
+    /// let people = vec![("Jane", 20), ("John", 18), ("Jill", 30), ("Jack", 27)];
+    ///
+    /// let oldest_people_first = people
+    ///     .into_iter()
+    ///     .sorted_by_key(|x| -x.1)
+    ///     .map(|(person, _age)| person);
+    ///
+    /// itertools::assert_equal(oldest_people_first,
+    ///                         vec!["Jill", "Jack", "Jane", "John"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn sorted_by_key<K, F>(self, f: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              K: Ord,
+              F: FnMut(&Self::Item) -> K,
+    {
+        let mut v: Vec<Self::Item> = self.collect();
+
+        v.sort_by_key(f);
+        v.into_iter()
+    }
+
+    /// Sort the `Ok` values of an iterator of `Result`s into a new iterator
+    /// in ascending order, or return the first `Err`.
+    ///
+    /// **Note:** This consumes the iterator up to the first error, collecting
+    /// the `Ok` values into a single buffer that is then sorted with
+    /// `slice::sort()`.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let input = vec!["3", "1", "2"];
+    /// let sorted = input.iter().map(|s| s.parse::<i32>()).try_sorted();
+    /// itertools::assert_equal(sorted.unwrap(), vec![1, 2, 3]);
+    ///
+    /// let input = vec!["3", "x", "2"];
+    /// assert!(input.iter().map(|s| s.parse::<i32>()).try_sorted().is_err());
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn try_sorted<T, E>(self) -> Result<VecIntoIter<T>, E>
+        where Self: Iterator<Item = Result<T, E>> + Sized,
+              T: Ord
+    {
+        let mut v: Vec<T> = match self.collect() {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+
+        v.sort();
+        Ok(v.into_iter())
+    }
+
+    /// Sort the `Ok` values of an iterator of `Result`s into a new iterator
+    /// in ascending order of the keys computed by `f`, or return the first
+    /// `Err`.
+    ///
+    /// See [*.try_sorted()*](#method.try_sorted) for more information.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let input = vec!["ccc", "a", "bb"];
+    /// let sorted = input.into_iter()
+    ///                   .map(|s| if s.is_empty() { Err("empty") } else { Ok(s) })
+    ///                   .try_sorted_by_key(|s| s.len());
+    /// itertools::assert_equal(sorted.unwrap(), vec!["a", "bb", "ccc"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn try_sorted_by_key<T, E, K, F>(self, f: F) -> Result<VecIntoIter<T>, E>
+        where Self: Iterator<Item = Result<T, E>> + Sized,
+              K: Ord,
+              F: FnMut(&T) -> K,
+    {
+        let mut v: Vec<T> = match self.collect() {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+
+        v.sort_by_key(f);
+        Ok(v.into_iter())
+    }
+
+    /// Sort all iterator elements into a new iterator in ascending order,
+    /// using an unstable sort.
+    ///
+    /// **Note:** This consumes the entire iterator, uses the
+    /// `slice::sort_unstable()` method and returns the result as a new
+    /// iterator that owns its elements. Equal elements may be reordered.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// itertools::assert_equal(vec![3, 1, 4, 1, 5].into_iter().sorted_unstable(),
+    ///                         vec![1, 1, 3, 4, 5]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn sorted_unstable(self) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              Self::Item: Ord
+    {
+        let mut v: Vec<Self::Item> = self.collect();
+
+        v.sort_unstable();
+        v.into_iter()
+    }
+
+    /// Sort all iterator elements into a new iterator in ascending order,
+    /// using an unstable sort with the comparator `cmp`.
+    ///
+    /// **Note:** This consumes the entire iterator, uses the
+    /// `slice::sort_unstable_by()` method and returns the result as a new
+    /// iterator that owns its elements. Equal elements may be reordered.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// itertools::assert_equal((0..5).sorted_unstable_by(|a, b| b.cmp(a)),
+    ///                         vec![4, 3, 2, 1, 0]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn sorted_unstable_by<F>(self, cmp: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        let mut v: Vec<Self::Item> = self.collect();
+
+        v.sort_unstable_by(cmp);
+        v.into_iter()
+    }
+
+    /// Sort all iterator elements into a new iterator in ascending order,
+    /// using an unstable sort comparing the keys computed by `f`.
+    ///
+    /// **Note:** This consumes the entire iterator, uses the
+    /// `slice::sort_unstable_by_key()` method and returns the result as a new
+    /// iterator that owns its elements. Elements with equal keys may be
+    /// reordered.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let words = vec!["ccc", "a", "bb"];
+    /// itertools::assert_equal(words.into_iter().sorted_unstable_by_key(|w| w.len()),
+    ///                         vec!["a", "bb", "ccc"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn sorted_unstable_by_key<K, F>(self, f: F) -> VecIntoIter<Self::Item>
         where Self: Sized,
-              Self: DoubleEndedIterator
+              K: Ord,
+              F: FnMut(&Self::Item) -> K,
     {
-        self.by_ref().rev().dropn(n);
-        self
+        let mut v: Vec<Self::Item> = self.collect();
+
+        v.sort_unstable_by_key(f);
+        v.into_iter()
     }
 
-    /// Run the closure `f` eagerly on each element of the iterator.
+    /// Sort all iterator elements into a new iterator in ascending order,
+    /// sorting in parallel on rayon's thread pool.
     ///
-    /// Consumes the iterator until its end.
+    /// **Note:** This consumes the entire iterator on the current thread,
+    /// uses rayon's `par_sort()` method and returns the result as a new
+    /// iterator that owns its elements. The sort is stable.
     ///
     /// ```
-    /// use std::sync::mpsc::channel;
     /// use itertools::Itertools;
     ///
-    /// let (tx, rx) = channel();
-    ///
-    /// // use .foreach() to apply a function to each value -- sending it
-    /// (0..5).map(|x| x * 2 + 1).foreach(|x| { tx.send(x).unwrap(); } );
-    ///
-    /// drop(tx);
-    ///
-    /// itertools::assert_equal(rx.iter(), vec![1, 3, 5, 7, 9]);
+    /// itertools::assert_equal((0..1000).rev().par_sorted(), 0..1000);
     /// ```
-    fn foreach<F>(&mut self, mut f: F)
-        where F: FnMut(Self::Item)
+    #[cfg(feature = "rayon")]
+    fn par_sorted(self) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              Self::Item: Ord + Send
     {
-        for elt in self {
-            f(elt)
-        }
-    }
+        let mut v: Vec<Self::Item> = self.collect();
 
-    /// `.collect_vec()` is simply a type specialization of `.collect()`,
-    /// for convenience.
-    fn collect_vec(self) -> Vec<Self::Item>
-        where Self: Sized
-    {
-        self.collect()
+        v.par_sort();
+        v.into_iter()
     }
 
-    /// Assign to each reference in `self` from the `from` iterator,
-    /// stopping at the shortest of the two iterators.
-    ///
-    /// The `from` iterator is queried for its next element before the `self`
-    /// iterator, and if either is exhausted the method is done.
+    /// Sort all iterator elements into a new iterator with the comparison
+    /// function `cmp`, sorting in parallel on rayon's thread pool.
     ///
-    /// Return the number of elements written.
+    /// See [*.par_sorted()*](#method.par_sorted) for more information.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let mut xs = [0; 4];
-    /// xs.iter_mut().set_from(1..);
-    /// assert_eq!(xs, [1, 2, 3, 4]);
+    /// itertools::assert_equal((0..1000).par_sorted_by(|a, b| b.cmp(a)), (0..1000).rev());
     /// ```
-    #[inline]
-    fn set_from<'a, A: 'a, J>(&mut self, from: J) -> usize
-        where Self: Iterator<Item = &'a mut A>,
-              J: IntoIterator<Item = A>
+    #[cfg(feature = "rayon")]
+    fn par_sorted_by<F>(self, cmp: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              Self::Item: Send,
+              F: Fn(&Self::Item, &Self::Item) -> Ordering + Sync,
     {
-        let mut count = 0;
-        for elt in from {
-            match self.next() {
-                None => break,
-                Some(ptr) => *ptr = elt,
-            }
-            count += 1;
-        }
-        count
+        let mut v: Vec<Self::Item> = self.collect();
+
+        v.par_sort_by(cmp);
+        v.into_iter()
     }
 
-    /// Combine all iterator elements into one String, seperated by `sep`.
+    /// Sort all iterator elements into a new iterator by the key `f`,
+    /// sorting in parallel on rayon's thread pool.
     ///
-    /// Use the `Display` implementation of each element.
+    /// See [*.par_sorted()*](#method.par_sorted) for more information.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// assert_eq!(["a", "b", "c"].iter().join(", "), "a, b, c");
-    /// assert_eq!([1, 2, 3].iter().join(", "), "1, 2, 3");
+    /// let words = vec!["ccc", "a", "bb", "d"];
+    /// itertools::assert_equal(words.into_iter().par_sorted_by_key(|w| w.len()),
+    ///                         vec!["a", "d", "bb", "ccc"]);
     /// ```
-    fn join(&mut self, sep: &str) -> String
-        where Self::Item: std::fmt::Display
+    #[cfg(feature = "rayon")]
+    fn par_sorted_by_key<K, F>(self, f: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              Self::Item: Send,
+              K: Ord,
+              F: Fn(&Self::Item) -> K + Sync,
     {
-        match self.next() {
-            None => String::new(),
-            Some(first_elt) => {
-                // estimate lower bound of capacity needed
-                let (lower, _) = self.size_hint();
-                let mut result = String::with_capacity(sep.len() * lower);
-                write!(&mut result, "{}", first_elt).unwrap();
-                for elt in self {
-                    result.push_str(sep);
-                    write!(&mut result, "{}", elt).unwrap();
-                }
-                result
-            }
-        }
+        let mut v: Vec<Self::Item> = self.collect();
+
+        v.par_sort_by_key(f);
+        v.into_iter()
     }
 
-    /// Format all iterator elements, separated by `sep`.
+    /// Return the `k` smallest elements of the iterator, in ascending order.
     ///
-    /// The supplied closure `format` is called once per iterator element,
-    /// with two arguments: the element and a callback that takes a
-    /// `&Display` value, i.e. any reference to type that implements `Display`.
+    /// **Note:** This consumes the entire iterator, but keeps only `k`
+    /// elements in memory at a time, in a binary heap. It runs in
+    /// *O(n log k)* time. The order of equal elements is unspecified.
     ///
-    /// Using `&format_args!(...)` is the most versatile way to apply custom
-    /// element formatting. The callback can be called multiple times if needed.
+    /// If the iterator has fewer than `k` elements, all of them are returned.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let data = [1.1, 2.71828, -3.];
-    /// let data_formatter = data.iter().format(", ", |elt, f| f(&format_args!("{:2.2}", elt)));
-    /// assert_eq!(format!("{}", data_formatter),
-    ///            "1.10, 2.72, -3.00");
-    ///
-    /// // .format() is recursively composable
-    /// let matrix = [[1., 2., 3.],
-    ///               [4., 5., 6.]];
-    /// let matrix_formatter = matrix.iter().format("\n", |row, f| {
-    ///                                 f(&row.iter().format(", ", |elt, g| g(&elt)))
-    ///                              });
-    /// assert_eq!(format!("{}", matrix_formatter),
-    ///            "1, 2, 3\n4, 5, 6");
-    ///
-    ///
+    /// let numbers = vec![6, 3, 9, 1, 7, 2, 8];
+    /// itertools::assert_equal(numbers.into_iter().k_smallest(3), vec![1, 2, 3]);
     /// ```
-    fn format<F>(self, sep: &str, format: F) -> Format<Self, F>
+    #[cfg(feature = "use_std")]
+    fn k_smallest(self, k: usize) -> VecIntoIter<Self::Item>
         where Self: Sized,
-              F: FnMut(Self::Item, &mut FnMut(&fmt::Display) -> fmt::Result) -> fmt::Result,
+              Self::Item: Ord
     {
-        format::new_format(self, sep, format)
+        k_smallest::k_smallest_general(self, k, Ord::cmp).into_iter()
     }
 
-    /// Fold `Result` values from an iterator.
-    ///
-    /// Only `Ok` values are folded. If no error is encountered, the folded
-    /// value is returned inside `Ok`. Otherwise, the operation terminates
-    /// and returns the first `Err` value it encounters. No iterator elements are
-    /// consumed after the first error.
-    ///
-    /// The first accumulator value is the `start` parameter.
-    /// Each iteration passes the accumulator value and the next value inside `Ok`
-    /// to the fold function `f` and its return value becomes the new accumulator value.
-    ///
-    /// For example the sequence *Ok(1), Ok(2), Ok(3)* will result in a
-    /// computation like this:
+    /// Return the `k` smallest elements of the iterator according to the
+    /// comparator `cmp`, in ascending order.
     ///
-    /// ```ignore
-    /// let mut accum = start;
-    /// accum = f(accum, 1);
-    /// accum = f(accum, 2);
-    /// accum = f(accum, 3);
-    /// ```
-    ///
-    /// With a `start` value of 0 and an addition as folding function,
-    /// this effetively results in *((0 + 1) + 2) + 3*
+    /// See [*.k_smallest()*](#method.k_smallest) for more information.
     ///
     /// ```
-    /// use std::ops::Add;
     /// use itertools::Itertools;
     ///
-    /// let values = [1, 2, -2, -1, 2, 1];
-    /// assert_eq!(
-    ///     values.iter()
-    ///           .map(Ok::<_, ()>)
-    ///           .fold_results(0, Add::add),
-    ///     Ok(3)
-    /// );
-    /// assert!(
-    ///     values.iter()
-    ///           .map(|&x| if x >= 0 { Ok(x) } else { Err("Negative number") })
-    ///           .fold_results(0, Add::add)
-    ///           .is_err()
-    /// );
+    /// let numbers = vec![6, 3, 9, 1, 7, 2, 8];
+    /// itertools::assert_equal(numbers.into_iter().k_smallest_by(3, |a, b| b.cmp(a)),
+    ///                         vec![9, 8, 7]);
     /// ```
-    fn fold_results<A, E, B, F>(&mut self, mut start: B, mut f: F) -> Result<B, E>
-        where Self: Iterator<Item = Result<A, E>>,
-              F: FnMut(B, A) -> B
+    #[cfg(feature = "use_std")]
+    fn k_smallest_by<F>(self, k: usize, cmp: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              F: FnMut(&Self::Item, &Self::Item) -> Ordering,
     {
-        for elt in self {
-            match elt {
-                Ok(v) => start = f(start, v),
-                Err(u) => return Err(u),
-            }
-        }
-        Ok(start)
+        k_smallest::k_smallest_general(self, k, cmp).into_iter()
     }
 
-    /// Fold `Option` values from an iterator.
-    ///
-    /// Only `Some` values are folded. If no `None` is encountered, the folded
-    /// value is returned inside `Some`. Otherwise, the operation terminates
-    /// and returns `None`. No iterator elements are consumed after the `None`.
+    /// Return the `k` elements of the iterator with the smallest keys
+    /// computed by `f`, in ascending order of key.
     ///
-    /// This is the `Option` equivalent to `fold_results`.
+    /// See [*.k_smallest()*](#method.k_smallest) for more information.
     ///
     /// ```
-    /// use std::ops::Add;
     /// use itertools::Itertools;
     ///
-    /// let mut values = vec![Some(1), Some(2), Some(-2)].into_iter();
-    /// assert_eq!(values.fold_options(5, Add::add), Some(5 + 1 + 2 - 2));
-    ///
-    /// let mut more_values = vec![Some(2), None, Some(0)].into_iter();
-    /// assert!(more_values.fold_options(0, Add::add).is_none());
-    /// assert_eq!(more_values.next().unwrap(), Some(0));
+    /// let words = vec!["cccc", "a", "ddd", "bb"];
+    /// itertools::assert_equal(words.into_iter().k_smallest_by_key(2, |w| w.len()),
+    ///                         vec!["a", "bb"]);
     /// ```
-    fn fold_options<A, B, F>(&mut self, mut start: B, mut f: F) -> Option<B>
-        where Self: Iterator<Item = Option<A>>,
-              F: FnMut(B, A) -> B
+    #[cfg(feature = "use_std")]
+    fn k_smallest_by_key<K, F>(self, k: usize, mut f: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              K: Ord,
+              F: FnMut(&Self::Item) -> K,
     {
-        for elt in self {
-            match elt {
-                Some(v) => start = f(start, v),
-                None => return None,
-            }
-        }
-        Some(start)
+        k_smallest::k_smallest_general(self, k, |a, b| f(a).cmp(&f(b))).into_iter()
     }
 
-    /// Accumulator of the elements in the iterator.
+    /// Return the `k` largest elements of the iterator, in descending order.
     ///
-    /// Like `.fold()`, without a base case. If the iterator is
-    /// empty, return `None`. With just one element, return it.
-    /// Otherwise elements are accumulated in sequence using the closure `f`.
+    /// **Note:** This consumes the entire iterator, but keeps only `k`
+    /// elements in memory at a time, in a binary heap. It runs in
+    /// *O(n log k)* time. The order of equal elements is unspecified.
+    ///
+    /// If the iterator has fewer than `k` elements, all of them are returned.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// assert_eq!((0..10).fold1(|x, y| x + y).unwrap_or(0), 45);
-    /// assert_eq!((0..0).fold1(|x, y| x * y), None);
+    /// let numbers = vec![6, 3, 9, 1, 7, 2, 8];
+    /// itertools::assert_equal(numbers.into_iter().k_largest(3), vec![9, 8, 7]);
     /// ```
-    fn fold1<F>(&mut self, mut f: F) -> Option<Self::Item>
-        where F: FnMut(Self::Item, Self::Item) -> Self::Item
+    #[cfg(feature = "use_std")]
+    fn k_largest(self, k: usize) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              Self::Item: Ord
     {
-        match self.next() {
-            None => None,
-            Some(mut x) => {
-                for y in self {
-                    x = f(x, y);
-                }
-                Some(x)
-            }
-        }
+        self.k_largest_by(k, Ord::cmp)
     }
 
-    /// Tell if the iterator is empty or not according to its size hint.
-    /// Return `None` if the size hint does not tell, or return a `Some`
-    /// value with the emptiness if it's possible to tell.
+    /// Return the `k` largest elements of the iterator according to the
+    /// comparator `cmp`, in descending order.
+    ///
+    /// See [*.k_largest()*](#method.k_largest) for more information.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// assert_eq!((1..1).is_empty_hint(), Some(true));
-    /// assert_eq!([1, 2, 3].iter().is_empty_hint(), Some(false));
-    /// assert_eq!((0..10).filter(|&x| x > 0).is_empty_hint(), None);
+    /// let numbers = vec![6, 3, 9, 1, 7, 2, 8];
+    /// itertools::assert_equal(numbers.into_iter().k_largest_by(3, |a, b| b.cmp(a)),
+    ///                         vec![1, 2, 3]);
     /// ```
-    fn is_empty_hint(&self) -> Option<bool> {
-        let (low, opt_hi) = self.size_hint();
-        // check for erronous hint
-        if let Some(hi) = opt_hi {
-            if hi < low { return None }
-        }
-
-        if opt_hi == Some(0) {
-            Some(true)
-        } else if low > 0 {
-            Some(false)
-        } else {
-            None
-        }
+    #[cfg(feature = "use_std")]
+    fn k_largest_by<F>(self, k: usize, mut cmp: F) -> VecIntoIter<Self::Item>
+        where Self: Sized,
+              F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        self.k_smallest_by(k, move |a, b| cmp(b, a))
     }
 
-    /// Collect all iterator elements into a sorted vector in ascending order.
+    /// Return the `k` elements of the iterator with the largest keys
+    /// computed by `f`, in descending order of key.
     ///
-    /// **Note:** This consumes the entire iterator, uses the
-    /// `slice::sort_by()` method and returns the sorted vector.
+    /// See [*.k_largest()*](#method.k_largest) for more information.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// // sort the letters of the text in ascending order
-    /// let text = "bdacfe";
-    /// itertools::assert_equal(text.chars().sorted(),
-    ///                         "abcdef".chars());
+    /// let words = vec!["cccc", "a", "ddd", "bb"];
+    /// itertools::assert_equal(words.into_iter().k_largest_by_key(2, |w| w.len()),
+    ///                         vec!["cccc", "ddd"]);
     /// ```
-    fn sorted(self) -> Vec<Self::Item>
+    #[cfg(feature = "use_std")]
+    fn k_largest_by_key<K, F>(self, k: usize, mut f: F) -> VecIntoIter<Self::Item>
         where Self: Sized,
-              Self::Item: Ord
+              K: Ord,
+              F: FnMut(&Self::Item) -> K,
     {
-        self.sorted_by(Ord::cmp)
+        self.k_smallest_by(k, move |a, b| f(b).cmp(&f(a)))
     }
 
-    /// Collect all iterator elements into a sorted vector.
+    /// Return the last `n` elements of the iterator, in order.
     ///
-    /// **Note:** This consumes the entire iterator, uses the
-    /// `slice::sort_by()` method and returns the sorted vector.
+    /// **Note:** This consumes the entire iterator, but keeps only `n`
+    /// elements in memory at a time, in a ring buffer. When the lower bound
+    /// of `.size_hint()` shows that more than `n` elements are left, the
+    /// ones that can't be among the last `n` are skipped with `.nth()`,
+    /// which is fast for many `ExactSizeIterator`s such as slice iterators
+    /// and ranges. For a `DoubleEndedIterator`, `.rev().take(n)` gives the
+    /// same elements in reverse without consuming the rest.
+    ///
+    /// If the iterator has fewer than `n` elements, all of them are returned.
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// // sort people in descending order by age
-    /// let people = vec![("Jane", 20), ("John", 18), ("Jill", 30), ("Jack", 27)];
-    ///
-    /// let oldest_people_first = people
-    ///     .into_iter()
-    ///     .sorted_by(|a, b| Ord::cmp(&b.1, &a.1))
-    ///     .into_iter()
-    ///     .map(|(person, _age)| person);
-    ///
-    /// itertools::assert_equal(oldest_people_first,
-    ///                         vec!["Jill", "Jack", "Jane", "John"]);
+    /// let log = "boot\nmount /\nstart sshd\nlogin root\nlogout";
+    /// itertools::assert_equal(log.lines().tail(2), vec!["login root", "logout"]);
+    /// itertools::assert_equal((0..1000).tail(3), vec![997, 998, 999]);
+    /// itertools::assert_equal((0..2).tail(3), vec![0, 1]);
     /// ```
-    fn sorted_by<F>(self, cmp: F) -> Vec<Self::Item>
-        where Self: Sized,
-              F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    #[cfg(feature = "use_std")]
+    fn tail(mut self, n: usize) -> VecIntoIter<Self::Item>
+        where Self: Sized
     {
-        let mut v: Vec<Self::Item> = self.collect();
-
-        v.sort_by(cmp);
-        v
+        if n == 0 {
+            self.last();
+            return Vec::new().into_iter();
+        }
+        let (low, _) = self.size_hint();
+        if low > n {
+            self.nth(low - n - 1);
+        }
+        let mut buf = VecDeque::with_capacity(cmp::min(n, low));
+        self.for_each(|elt| {
+            if buf.len() == n {
+                buf.pop_front();
+            }
+            buf.push_back(elt);
+        });
+        Vec::from(buf).into_iter()
     }
 
     /// **Deprecated:** renamed to `.sorted_by()`
+    #[cfg(feature = "use_std")]
     fn sort_by<F>(self, cmp: F) -> Vec<Self::Item>
         where Self: Sized,
               F: FnMut(&Self::Item, &Self::Item) -> Ordering,
     {
-        self.sorted_by(cmp)
+        self.sorted_by(cmp).collect()
     }
 }
 
 impl<T: ?Sized> Itertools for T where T: Iterator { }
 
+/// An enum used for controlling the execution of `.fold_while()`.
+///
+/// See [*.fold_while()*](trait.Itertools.html#method.fold_while) for more information.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FoldWhile<T> {
+    /// Continue folding with this value
+    Continue(T),
+    /// Fold is complete and will return this value
+    Done(T),
+}
+
+impl<T> FoldWhile<T> {
+    /// Return the inner value.
+    pub fn into_inner(self) -> T {
+        match self {
+            FoldWhile::Continue(x) | FoldWhile::Done(x) => x,
+        }
+    }
+
+    /// Return true if `self` is `Done`, false if it is `Continue`.
+    pub fn is_done(&self) -> bool {
+        match *self {
+            FoldWhile::Continue(_) => false,
+            FoldWhile::Done(_) => true,
+        }
+    }
+}
+
+/// The result of `.one_or_more()`: an iterator classified by how many
+/// elements it has.
+///
+/// See [*.one_or_more()*](trait.Itertools.html#method.one_or_more) for more information.
+pub enum OneOrMore<I>
+    where I: Iterator
+{
+    /// The iterator had no elements.
+    Empty,
+    /// The iterator had exactly one element.
+    Single(I::Item),
+    /// The iterator had at least two elements: the first one, and an
+    /// iterator of all the rest.
+    More(I::Item, PutBack<I>),
+}
+
 /// Return `true` if both iterators produce equal sequences
 /// (elements pairwise equal and sequences of the same length),
 /// `false` otherwise.
@@ -1366,12 +5347,16 @@ pub fn equal<I, J>(a: I, b: J) -> bool
 /// Assert that two iterators produce equal sequences, with the same
 /// semantics as *equal(a, b)*.
 ///
-/// **Panics** on assertion failure with a message that shows the
-/// two iteration elements.
+/// **Panics** on assertion failure with a message that shows the index
+/// and the two iteration elements, followed by up to three of the elements
+/// before the mismatch and up to three of the elements after it in each
+/// sequence (the surrounding elements need the `use_std` feature).
 ///
 /// ```ignore
 /// assert_equal("exceed".split('c'), "excess".split('c'));
-/// // ^PANIC: panicked at 'Failed assertion Some("eed") == Some("ess") for iteration 1',
+/// // ^PANIC: panicked at 'Failed assertion Some("eed") == Some("ess") for iteration 1
+/// //   preceding: ["ex"]
+/// //   following: [] and []',
 /// ```
 pub fn assert_equal<I, J>(a: I, b: J)
     where I: IntoIterator,
@@ -1379,8 +5364,12 @@ pub fn assert_equal<I, J>(a: I, b: J)
           I::Item: fmt::Debug + PartialEq<J::Item>,
           J::Item: fmt::Debug,
 {
+    #[cfg(feature = "use_std")]
+    const CONTEXT: usize = 3;
     let mut ia = a.into_iter();
     let mut ib = b.into_iter();
+    #[cfg(feature = "use_std")]
+    let mut preceding = VecDeque::with_capacity(CONTEXT);
     let mut i = 0;
     loop {
         match (ia.next(), ib.next()) {
@@ -1390,6 +5379,24 @@ pub fn assert_equal<I, J>(a: I, b: J)
                     (&Some(ref a), &Some(ref b)) => a == b,
                     _ => false,
                 };
+                #[cfg(feature = "use_std")]
+                {
+                    if !equal {
+                        let following_a = ia.take(CONTEXT).collect::<Vec<_>>();
+                        let following_b = ib.take(CONTEXT).collect::<Vec<_>>();
+                        panic!("Failed assertion {a:?} == {b:?} for iteration {i}\n  \
+                                preceding: [{preceding}]\n  \
+                                following: {fa:?} and {fb:?}",
+                               i=i, a=a, b=b,
+                               preceding=preceding.iter().map(|x| format!("{:?}", x)).join(", "),
+                               fa=following_a, fb=following_b);
+                    }
+                    if preceding.len() == CONTEXT {
+                        preceding.pop_front();
+                    }
+                    preceding.push_back(a.unwrap());
+                }
+                #[cfg(not(feature = "use_std"))]
                 assert!(equal, "Failed assertion {a:?} == {b:?} for iteration {i}",
                         i=i, a=a, b=b);
                 i += 1;
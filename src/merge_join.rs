@@ -0,0 +1,541 @@
+use std::cmp::Ordering;
+use std::iter::{Fuse, FusedIterator};
+
+use super::adaptors::PutBack;
+use super::size_hint;
+use super::EitherOrBoth;
+use super::EitherOrBoth::{Left, Right, Both};
+
+/// Create a `MergeJoinBy` iterator.
+pub fn merge_join_by<I, J, F>(left: I, right: J, cmp_fn: F)
+    -> MergeJoinBy<I::IntoIter, J::IntoIter, F>
+    where I: IntoIterator,
+          J: IntoIterator,
+          F: FnMut(&I::Item, &J::Item) -> Ordering
+{
+    MergeJoinBy {
+        left: PutBack::new(left.into_iter().fuse()),
+        right: PutBack::new(right.into_iter().fuse()),
+        cmp_fn: cmp_fn,
+    }
+}
+
+/// An iterator adaptor that merge-joins items from the two base iterators in ascending order.
+///
+/// This iterator is *fused*.
+///
+/// See [*.merge_join_by()*](trait.Itertools.html#method.merge_join_by) for more information.
+pub struct MergeJoinBy<I, J, F>
+    where I: Iterator,
+          J: Iterator
+{
+    left: PutBack<Fuse<I>>,
+    right: PutBack<Fuse<J>>,
+    cmp_fn: F,
+}
+
+impl<I, J, F> Clone for MergeJoinBy<I, J, F>
+    where I: Iterator + Clone,
+          J: Iterator + Clone,
+          I::Item: Clone,
+          J::Item: Clone,
+          F: Clone
+{
+    fn clone(&self) -> Self {
+        MergeJoinBy {
+            left: self.left.clone(),
+            right: self.right.clone(),
+            cmp_fn: self.cmp_fn.clone(),
+        }
+    }
+}
+
+impl<I, J, F> Iterator for MergeJoinBy<I, J, F>
+    where I: Iterator,
+          J: Iterator,
+          F: FnMut(&I::Item, &J::Item) -> Ordering
+{
+    type Item = EitherOrBoth<I::Item, J::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.left.next(), self.right.next()) {
+            (None, None) => None,
+            (Some(left), None) => Some(Left(left)),
+            (None, Some(right)) => Some(Right(right)),
+            (Some(left), Some(right)) => {
+                match (self.cmp_fn)(&left, &right) {
+                    Ordering::Equal => Some(Both(left, right)),
+                    Ordering::Less => {
+                        self.right.put_back(right);
+                        Some(Left(left))
+                    }
+                    Ordering::Greater => {
+                        self.left.put_back(left);
+                        Some(Right(right))
+                    }
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // at least the longer of the two, at most both of them unpaired
+        let (a_lower, a_upper) = self.left.size_hint();
+        let (b_lower, b_upper) = self.right.size_hint();
+        let lower = ::std::cmp::max(a_lower, b_lower);
+        let (_, upper) = size_hint::add((0, a_upper), (0, b_upper));
+        (lower, upper)
+    }
+}
+
+impl<I, J, F> FusedIterator for MergeJoinBy<I, J, F>
+    where I: Iterator,
+          J: Iterator,
+          F: FnMut(&I::Item, &J::Item) -> Ordering
+{}
+
+/// The `MergeJoinBy` that the key joins use: it compares the keys of
+/// `(key, value)` pairs.
+type KeyMergeJoin<I, J> =
+    MergeJoinBy<I, J, fn(&<I as Iterator>::Item, &<J as Iterator>::Item) -> Ordering>;
+
+fn cmp_keys<K: Ord, V, W>(a: &(K, V), b: &(K, W)) -> Ordering {
+    a.0.cmp(&b.0)
+}
+
+fn key_merge_join<I, J, K, V, W>(left: I, right: J) -> KeyMergeJoin<I::IntoIter, J::IntoIter>
+    where I: IntoIterator<Item = (K, V)>,
+          J: IntoIterator<Item = (K, W)>,
+          K: Ord,
+{
+    merge_join_by(left, right, cmp_keys::<K, V, W>)
+}
+
+/// The size hint of the elements that a join finds in both iterators: at
+/// most the shorter of the two.
+fn min_upper<I, J, F>(join: &MergeJoinBy<I, J, F>) -> (usize, Option<usize>)
+    where I: Iterator,
+          J: Iterator
+{
+    let (_, upper) = size_hint::min(join.left.size_hint(), join.right.size_hint());
+    (0, upper)
+}
+
+/// An iterator that joins two key-sorted iterators of `(key, value)` pairs,
+/// yielding the keys present in both.
+///
+/// This iterator is *fused*.
+///
+/// See [`free::inner_join()`](free/fn.inner_join.html) for more information.
+pub struct InnerJoin<I, J>
+    where I: Iterator,
+          J: Iterator
+{
+    inner: KeyMergeJoin<I, J>,
+}
+
+/// Create an `InnerJoin` iterator.
+pub fn inner_join<I, J, K, V, W>(left: I, right: J) -> InnerJoin<I::IntoIter, J::IntoIter>
+    where I: IntoIterator<Item = (K, V)>,
+          J: IntoIterator<Item = (K, W)>,
+          K: Ord,
+{
+    InnerJoin { inner: key_merge_join(left, right) }
+}
+
+impl<I, J, K, V, W> Iterator for InnerJoin<I, J>
+    where I: Iterator<Item = (K, V)>,
+          J: Iterator<Item = (K, W)>,
+          K: Ord,
+{
+    type Item = (K, V, W);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.inner.next() {
+                None => return None,
+                Some(Both((k, v), (_, w))) => return Some((k, v, w)),
+                Some(_) => {}
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        min_upper(&self.inner)
+    }
+}
+
+impl<I, J, K, V, W> FusedIterator for InnerJoin<I, J>
+    where I: Iterator<Item = (K, V)>,
+          J: Iterator<Item = (K, W)>,
+          K: Ord,
+{}
+
+/// An iterator that joins two key-sorted iterators of `(key, value)` pairs,
+/// yielding every key of the left iterator.
+///
+/// This iterator is *fused*.
+///
+/// See [`free::left_join()`](free/fn.left_join.html) for more information.
+pub struct LeftJoin<I, J>
+    where I: Iterator,
+          J: Iterator
+{
+    inner: KeyMergeJoin<I, J>,
+}
+
+/// Create a `LeftJoin` iterator.
+pub fn left_join<I, J, K, V, W>(left: I, right: J) -> LeftJoin<I::IntoIter, J::IntoIter>
+    where I: IntoIterator<Item = (K, V)>,
+          J: IntoIterator<Item = (K, W)>,
+          K: Ord,
+{
+    LeftJoin { inner: key_merge_join(left, right) }
+}
+
+impl<I, J, K, V, W> Iterator for LeftJoin<I, J>
+    where I: Iterator<Item = (K, V)>,
+          J: Iterator<Item = (K, W)>,
+          K: Ord,
+{
+    type Item = (K, V, Option<W>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.inner.next() {
+                None => return None,
+                Some(Both((k, v), (_, w))) => return Some((k, v, Some(w))),
+                Some(Left((k, v))) => return Some((k, v, None)),
+                Some(Right(_)) => {}
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // one element for each element of the left iterator
+        self.inner.left.size_hint()
+    }
+}
+
+impl<I, J, K, V, W> FusedIterator for LeftJoin<I, J>
+    where I: Iterator<Item = (K, V)>,
+          J: Iterator<Item = (K, W)>,
+          K: Ord,
+{}
+
+impl<I, J, K, V, W> ExactSizeIterator for LeftJoin<I, J>
+    where I: ExactSizeIterator<Item = (K, V)>,
+          J: Iterator<Item = (K, W)>,
+          K: Ord,
+{}
+
+/// An iterator that joins two key-sorted iterators of `(key, value)` pairs,
+/// yielding every key of either iterator.
+///
+/// This iterator is *fused*.
+///
+/// See [`free::outer_join()`](free/fn.outer_join.html) for more information.
+pub struct OuterJoin<I, J>
+    where I: Iterator,
+          J: Iterator
+{
+    inner: KeyMergeJoin<I, J>,
+}
+
+/// Create an `OuterJoin` iterator.
+pub fn outer_join<I, J, K, V, W>(left: I, right: J) -> OuterJoin<I::IntoIter, J::IntoIter>
+    where I: IntoIterator<Item = (K, V)>,
+          J: IntoIterator<Item = (K, W)>,
+          K: Ord,
+{
+    OuterJoin { inner: key_merge_join(left, right) }
+}
+
+impl<I, J, K, V, W> Iterator for OuterJoin<I, J>
+    where I: Iterator<Item = (K, V)>,
+          J: Iterator<Item = (K, W)>,
+          K: Ord,
+{
+    type Item = (K, EitherOrBoth<V, W>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|either| match either {
+            Both((k, v), (_, w)) => (k, Both(v, w)),
+            Left((k, v)) => (k, Left(v)),
+            Right((k, w)) => (k, Right(w)),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I, J, K, V, W> FusedIterator for OuterJoin<I, J>
+    where I: Iterator<Item = (K, V)>,
+          J: Iterator<Item = (K, W)>,
+          K: Ord,
+{}
+
+/// The `MergeJoinBy` that the sorted set operations use: it compares
+/// elements with `Ord`.
+type SetMergeJoin<I, J> =
+    MergeJoinBy<I, J, fn(&<I as Iterator>::Item, &<I as Iterator>::Item) -> Ordering>;
+
+fn set_merge_join<I, J>(left: I, right: J) -> SetMergeJoin<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Ord,
+{
+    merge_join_by(left, right, <I::Item as Ord>::cmp as fn(&_, &_) -> Ordering)
+}
+
+/// An iterator adaptor that yields the union of two sorted iterators.
+///
+/// Iterator element type is `I::Item`.
+///
+/// This iterator is *fused*.
+///
+/// See [*.union()*](trait.Itertools.html#method.union) for more information.
+pub struct Union<I, J>
+    where I: Iterator,
+          J: Iterator
+{
+    inner: SetMergeJoin<I, J>,
+}
+
+/// Create a `Union` iterator.
+pub fn union<I, J>(left: I, right: J) -> Union<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Ord,
+{
+    Union { inner: set_merge_join(left, right) }
+}
+
+impl<I, J> Clone for Union<I, J>
+    where I: Iterator + Clone,
+          J: Iterator<Item = I::Item> + Clone,
+          I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Union { inner: self.inner.clone() }
+    }
+}
+
+impl<I, J> Iterator for Union<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Ord,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|either| match either {
+            Left(x) | Right(x) | Both(x, _) => x,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I, J> FusedIterator for Union<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Ord,
+{}
+
+/// An iterator adaptor that yields the intersection of two sorted iterators.
+///
+/// Iterator element type is `I::Item`.
+///
+/// This iterator is *fused*.
+///
+/// See [*.intersection()*](trait.Itertools.html#method.intersection) for more information.
+pub struct Intersection<I, J>
+    where I: Iterator,
+          J: Iterator
+{
+    inner: SetMergeJoin<I, J>,
+}
+
+/// Create an `Intersection` iterator.
+pub fn intersection<I, J>(left: I, right: J) -> Intersection<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Ord,
+{
+    Intersection { inner: set_merge_join(left, right) }
+}
+
+impl<I, J> Clone for Intersection<I, J>
+    where I: Iterator + Clone,
+          J: Iterator<Item = I::Item> + Clone,
+          I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Intersection { inner: self.inner.clone() }
+    }
+}
+
+impl<I, J> Iterator for Intersection<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Ord,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.inner.next() {
+                None => return None,
+                Some(Both(x, _)) => return Some(x),
+                Some(_) => {}
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        min_upper(&self.inner)
+    }
+}
+
+impl<I, J> FusedIterator for Intersection<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Ord,
+{}
+
+/// An iterator adaptor that yields the elements of a sorted iterator that
+/// are not in another.
+///
+/// Iterator element type is `I::Item`.
+///
+/// This iterator is *fused*.
+///
+/// See [*.difference()*](trait.Itertools.html#method.difference) for more information.
+pub struct Difference<I, J>
+    where I: Iterator,
+          J: Iterator
+{
+    inner: SetMergeJoin<I, J>,
+}
+
+/// Create a `Difference` iterator.
+pub fn difference<I, J>(left: I, right: J) -> Difference<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Ord,
+{
+    Difference { inner: set_merge_join(left, right) }
+}
+
+impl<I, J> Clone for Difference<I, J>
+    where I: Iterator + Clone,
+          J: Iterator<Item = I::Item> + Clone,
+          I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Difference { inner: self.inner.clone() }
+    }
+}
+
+impl<I, J> Iterator for Difference<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Ord,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.inner.next() {
+                None => return None,
+                Some(Left(x)) => return Some(x),
+                Some(_) => {}
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // each element of the right iterator removes at most one
+        let (a_lower, a_upper) = self.inner.left.size_hint();
+        let lower = match self.inner.right.size_hint() {
+            (_, Some(b_upper)) => a_lower.saturating_sub(b_upper),
+            (_, None) => 0,
+        };
+        (lower, a_upper)
+    }
+}
+
+impl<I, J> FusedIterator for Difference<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Ord,
+{}
+
+/// An iterator adaptor that yields the elements that are in exactly one of
+/// two sorted iterators.
+///
+/// Iterator element type is `I::Item`.
+///
+/// This iterator is *fused*.
+///
+/// See [*.symmetric_difference()*](trait.Itertools.html#method.symmetric_difference)
+/// for more information.
+pub struct SymmetricDifference<I, J>
+    where I: Iterator,
+          J: Iterator
+{
+    inner: SetMergeJoin<I, J>,
+}
+
+/// Create a `SymmetricDifference` iterator.
+pub fn symmetric_difference<I, J>(left: I, right: J) -> SymmetricDifference<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Ord,
+{
+    SymmetricDifference { inner: set_merge_join(left, right) }
+}
+
+impl<I, J> Clone for SymmetricDifference<I, J>
+    where I: Iterator + Clone,
+          J: Iterator<Item = I::Item> + Clone,
+          I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        SymmetricDifference { inner: self.inner.clone() }
+    }
+}
+
+impl<I, J> Iterator for SymmetricDifference<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Ord,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.inner.next() {
+                None => return None,
+                Some(Left(x)) | Some(Right(x)) => return Some(x),
+                Some(Both(..)) => {}
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.inner.size_hint();
+        (0, upper)
+    }
+}
+
+impl<I, J> FusedIterator for SymmetricDifference<I, J>
+    where I: Iterator,
+          J: Iterator<Item = I::Item>,
+          I::Item: Ord,
+{}
@@ -0,0 +1,165 @@
+/// `MinMaxResult` is an enum returned by `minmax`. See `Itertools::minmax()` for
+/// more detail.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum MinMaxResult<T> {
+    /// Empty iterator
+    NoElements,
+
+    /// Iterator with one element, so the minimum and maximum are the same
+    OneElement(T),
+
+    /// More than one element in the iterator, the first element is not larger
+    /// than the second
+    MinMax(T, T),
+}
+
+impl<T: Clone> MinMaxResult<T> {
+    /// `into_option` creates an `Option` of type `(T, T)`. The returned `Option`
+    /// has variant `None` if and only if the `MinMaxResult` has variant
+    /// `NoElements`. Otherwise `Some((x, y))` is returned where `x <= y`.
+    /// If the `MinMaxResult` has variant `OneElement(x)`, performing this
+    /// operation will make one clone of `x`.
+    ///
+    /// ```
+    /// use itertools::MinMaxResult::{self, NoElements, OneElement, MinMax};
+    ///
+    /// let r: MinMaxResult<i32> = NoElements;
+    /// assert_eq!(r.into_option(), None);
+    ///
+    /// let r = OneElement(1);
+    /// assert_eq!(r.into_option(), Some((1, 1)));
+    ///
+    /// let r = MinMax(1, 2);
+    /// assert_eq!(r.into_option(), Some((1, 2)));
+    /// ```
+    pub fn into_option(self) -> Option<(T, T)> {
+        match self {
+            MinMaxResult::NoElements => None,
+            MinMaxResult::OneElement(x) => Some((x.clone(), x)),
+            MinMaxResult::MinMax(x, y) => Some((x, y)),
+        }
+    }
+}
+
+/// Implementation guts for `minmax`, `minmax_by` and `minmax_by_key`.
+///
+/// Elements are taken in pairs: the two are compared to each other first,
+/// then the smaller against the current minimum and the larger against the
+/// current maximum, for 3 comparisons per 2 elements.
+///
+/// `lt(a, b, key_a, key_b)` must return whether `a` is less than `b`.
+pub fn minmax_impl<I, K, F, L>(mut it: I, mut key_for: F, mut lt: L) -> MinMaxResult<I::Item>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          L: FnMut(&I::Item, &I::Item, &K, &K) -> bool,
+{
+    let (mut min, mut max, mut min_key, mut max_key) = match it.next() {
+        None => return MinMaxResult::NoElements,
+        Some(x) => {
+            match it.next() {
+                None => return MinMaxResult::OneElement(x),
+                Some(y) => {
+                    let xk = key_for(&x);
+                    let yk = key_for(&y);
+                    if !lt(&y, &x, &yk, &xk) {
+                        (x, y, xk, yk)
+                    } else {
+                        (y, x, yk, xk)
+                    }
+                }
+            }
+        }
+    };
+
+    loop {
+        // `first` and `second` are the two next elements we want to look
+        // at.  We first compare `first` and `second` (#1). The smaller one
+        // is then compared to current minimum (#2). The larger one is
+        // compared to current maximum (#3). This way we do 3 comparisons
+        // for 2 elements.
+        let first = match it.next() {
+            None => break,
+            Some(x) => x,
+        };
+        let first_key = key_for(&first);
+        let second = match it.next() {
+            None => {
+                if lt(&first, &min, &first_key, &min_key) {
+                    min = first;
+                } else if !lt(&first, &max, &first_key, &max_key) {
+                    max = first;
+                }
+                break;
+            }
+            Some(x) => x,
+        };
+        let second_key = key_for(&second);
+        if !lt(&second, &first, &second_key, &first_key) {
+            if lt(&first, &min, &first_key, &min_key) {
+                min = first;
+                min_key = first_key;
+            }
+            if !lt(&second, &max, &second_key, &max_key) {
+                max = second;
+                max_key = second_key;
+            }
+        } else {
+            if lt(&second, &min, &second_key, &min_key) {
+                min = second;
+                min_key = second_key;
+            }
+            if !lt(&first, &max, &first_key, &max_key) {
+                max = first;
+                max_key = first_key;
+            }
+        }
+    }
+
+    MinMaxResult::MinMax(min, max)
+}
+
+/// The result of `Itertools::extrema()`: the minimum and maximum values of an
+/// iterator, the positions where each first occurs, and the number of
+/// elements.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Extrema<T> {
+    /// The minimum value.
+    pub min: T,
+    /// The position of the first occurrence of the minimum value.
+    pub min_index: usize,
+    /// The maximum value.
+    pub max: T,
+    /// The position of the first occurrence of the maximum value.
+    pub max_index: usize,
+    /// The number of elements in the iterator.
+    pub count: usize,
+}
+
+/// Implementation guts for `extrema`.
+pub fn extrema_impl<I>(mut it: I) -> Option<Extrema<I::Item>>
+    where I: Iterator,
+          I::Item: PartialOrd + Clone,
+{
+    let first = match it.next() {
+        None => return None,
+        Some(x) => x,
+    };
+    let mut result = Extrema {
+        min: first.clone(),
+        min_index: 0,
+        max: first,
+        max_index: 0,
+        count: 1,
+    };
+    for elt in it {
+        if elt < result.min {
+            result.min = elt;
+            result.min_index = result.count;
+        } else if elt > result.max {
+            result.max = elt;
+            result.max_index = result.count;
+        }
+        result.count += 1;
+    }
+    Some(result)
+}
@@ -234,6 +234,6 @@ unsafe impl<'a, T> Slice for &'a mut [T] {
     unsafe fn get_unchecked(&mut self, i: usize) -> &'a mut T {
         debug_assert!(i < self.len());
         // override the lifetime constraints of &mut &'a mut [T]
-        (*(*self as *mut [T])).get_unchecked_mut(i)
+        (&mut *(*self as *mut [T])).get_unchecked_mut(i)
     }
 }
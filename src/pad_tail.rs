@@ -1,4 +1,5 @@
-use std::iter::Fuse;
+use std::iter::{Fuse, FusedIterator};
+use std::usize;
 use size_hint;
 
 /// An iterator adaptor that pads a sequence to a minimum length by filling
@@ -61,6 +62,11 @@ impl<I, F> Iterator for PadUsing<I, F>
     }
 }
 
+impl<I, F> FusedIterator for PadUsing<I, F>
+    where I: Iterator,
+          F: FnMut(usize) -> I::Item
+{}
+
 impl<I, F> DoubleEndedIterator for PadUsing<I, F>
     where I: DoubleEndedIterator + ExactSizeIterator,
           F: FnMut(usize) -> I::Item
@@ -68,7 +74,7 @@ impl<I, F> DoubleEndedIterator for PadUsing<I, F>
     fn next_back(&mut self) -> Option<I::Item> {
         if self.min == 0 {
             self.iter.next_back()
-        } else if self.iter.len() >= self.min {
+        } else if self.iter.len() + self.pos >= self.min {
             self.min -= 1;
             self.iter.next_back()
         } else {
@@ -79,6 +85,83 @@ impl<I, F> DoubleEndedIterator for PadUsing<I, F>
 }
 
 impl<I, F> ExactSizeIterator for PadUsing<I, F>
+    where I: ExactSizeIterator,
+          F: FnMut(usize) -> I::Item
+{}
+
+/// An iterator adaptor that pads a sequence to a multiple of *n* elements
+/// by filling missing elements using a closure.
+///
+/// Iterator element type is **I::Item**.
+///
+/// See [*.pad_to_multiple()*](trait.Itertools.html#method.pad_to_multiple) for more information.
+#[derive(Clone)]
+pub struct PadToMultiple<I, F> {
+    iter: Fuse<I>,
+    n: usize,
+    pos: usize,
+    filler: F,
+}
+
+impl<I, F> PadToMultiple<I, F>
+    where I: Iterator,
+          F: FnMut(usize) -> I::Item
+{
+    /// Create a new **PadToMultiple** iterator.
+    ///
+    /// **Panics** if `n` is 0.
+    pub fn new(iter: I, n: usize, filler: F) -> PadToMultiple<I, F> {
+        assert!(n != 0);
+        PadToMultiple {
+            iter: iter.fuse(),
+            n: n,
+            pos: 0,
+            filler: filler,
+        }
+    }
+}
+
+impl<I, F> Iterator for PadToMultiple<I, F>
     where I: Iterator,
           F: FnMut(usize) -> I::Item
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        match self.iter.next() {
+            None => {
+                if self.pos % self.n != 0 {
+                    let e = Some((self.filler)(self.pos));
+                    self.pos += 1;
+                    e
+                } else {
+                    None
+                }
+            },
+            e => {
+                self.pos += 1;
+                e
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // round the total up to a multiple of n, minus what was already produced
+        let n = self.n;
+        let pos = self.pos;
+        let padded = |x: usize| {
+            pos.checked_add(x)
+               .and_then(|total| total.checked_add((n - total % n) % n))
+               .map(|total| total - pos)
+        };
+        let (low, hi) = self.iter.size_hint();
+        let low = padded(low).unwrap_or(usize::MAX);
+        (low, hi.and_then(padded))
+    }
+}
+
+impl<I, F> ExactSizeIterator for PadToMultiple<I, F>
+    where I: ExactSizeIterator,
+          F: FnMut(usize) -> I::Item
 {}
@@ -0,0 +1,32 @@
+//! Parallel helpers behind the `rayon` feature.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use rayon::prelude::*;
+
+/// Return a `HashMap` of keys mapped to a list of their corresponding values,
+/// grouping the collected pairs in parallel.
+///
+/// See [`.par_into_group_map()`](trait.Itertools.html#method.par_into_group_map)
+/// for more information.
+pub fn par_into_group_map<I, K, V>(iter: I) -> HashMap<K, Vec<V>>
+    where I: Iterator<Item = (K, V)>,
+          K: Hash + Eq + Send,
+          V: Send,
+{
+    let pairs: Vec<(K, V)> = iter.collect();
+    // each task groups a contiguous run of pairs; `reduce` merges the runs
+    // left to right, so every list keeps the original order of its values
+    pairs.into_par_iter()
+         .fold(HashMap::new, |mut lookup, (key, val)| {
+             lookup.entry(key).or_insert_with(Vec::new).push(val);
+             lookup
+         })
+         .reduce(HashMap::new, |mut lookup, right| {
+             for (key, vals) in right {
+                 lookup.entry(key).or_insert_with(Vec::new).extend(vals);
+             }
+             lookup
+         })
+}
@@ -19,6 +19,23 @@ impl<A> RepeatN<A> {
     }
 }
 
+/// Create an iterator that produces `n` repetitions of `element`.
+///
+/// The element is cloned for every repetition but the last, which moves
+/// the original value out instead.
+///
+/// ```
+/// use itertools::repeat_n;
+///
+/// itertools::assert_equal(repeat_n("a".to_string(), 3), vec!["a", "a", "a"]);
+/// assert_eq!(repeat_n(0, 0).next(), None);
+/// ```
+pub fn repeat_n<A>(element: A, n: usize) -> RepeatN<A>
+    where A: Clone
+{
+    RepeatN::new(element, n)
+}
+
 impl<A> Iterator for RepeatN<A>
     where A: Clone
 {
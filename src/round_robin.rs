@@ -0,0 +1,53 @@
+use size_hint;
+
+/// An iterator adaptor that takes one element from each of its base
+/// iterators in turn, dropping base iterators as they run out.
+///
+/// See [*.round_robin()*](trait.Itertools.html#method.round_robin) for more information.
+#[derive(Clone)]
+pub struct RoundRobin<I> {
+    iters: Vec<I>,
+    index: usize,
+}
+
+/// Create a new `RoundRobin` iterator.
+pub fn round_robin_new<I>(iter: I) -> RoundRobin<<I::Item as IntoIterator>::IntoIter>
+    where I: Iterator,
+          I::Item: IntoIterator
+{
+    RoundRobin {
+        iters: iter.map(IntoIterator::into_iter).collect(),
+        index: 0,
+    }
+}
+
+impl<I> Iterator for RoundRobin<I>
+    where I: Iterator
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        while !self.iters.is_empty() {
+            if self.index >= self.iters.len() {
+                self.index = 0;
+            }
+            match self.iters[self.index].next() {
+                Some(elt) => {
+                    self.index += 1;
+                    return Some(elt);
+                }
+                None => {
+                    // keep the order of the remaining iterators
+                    self.iters.remove(self.index);
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iters.iter()
+                  .map(|it| it.size_hint())
+                  .fold((0, Some(0)), size_hint::add)
+    }
+}
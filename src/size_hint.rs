@@ -87,6 +87,66 @@ pub fn mul(a: SizeHint, b: SizeHint) -> SizeHint {
     (low, hi)
 }
 
+/// Raise a **SizeHint** correctly to the power **exp**.
+///
+/// The lower bound saturates at `usize::MAX` and the upper bound becomes
+/// `None` on overflow.
+///
+/// ```
+/// use std::usize;
+/// use itertools::size_hint;
+///
+/// assert_eq!(size_hint::pow((3, Some(4)), 3),
+///            (27, Some(64)));
+///
+/// assert_eq!(size_hint::pow((2, Some(usize::MAX)), 2),
+///            (4, None));
+///
+/// assert_eq!(size_hint::pow((3, None), 0),
+///            (1, Some(1)));
+/// ```
+#[inline]
+pub fn pow(sh: SizeHint, exp: u32) -> SizeHint {
+    let (low, hi) = sh;
+    let low = low.checked_pow(exp).unwrap_or(usize::MAX);
+    let hi = match hi {
+        Some(x) => x.checked_pow(exp),
+        None if exp == 0 => Some(1),
+        None => None,
+    };
+    (low, hi)
+}
+
+/// Count the unordered pairs, *x (x - 1) / 2*, of **SizeHint** elements.
+///
+/// The even factor is halved first, so only a count that does not fit in
+/// `usize` overflows: the lower bound saturates at `usize::MAX` and the upper
+/// bound becomes `None`.
+///
+/// ```
+/// use std::usize;
+/// use itertools::size_hint;
+///
+/// assert_eq!(size_hint::pairs((4, Some(5))),
+///            (6, Some(10)));
+///
+/// assert_eq!(size_hint::pairs((0, None)),
+///            (0, None));
+///
+/// assert_eq!(size_hint::pairs((usize::MAX, Some(usize::MAX))),
+///            (usize::MAX, None));
+/// ```
+#[inline]
+pub fn pairs(sh: SizeHint) -> SizeHint {
+    let count = |x: usize| if x % 2 == 0 {
+        (x / 2).checked_mul(x.saturating_sub(1))
+    } else {
+        x.checked_mul((x - 1) / 2)
+    };
+    let (low, hi) = sh;
+    (count(low).unwrap_or(usize::MAX), hi.and_then(count))
+}
+
 /// Return the maximum
 #[inline]
 pub fn max(a: SizeHint, b: SizeHint) -> SizeHint {
@@ -33,6 +33,23 @@ impl<F> RepeatCall<F> {
     }
 }
 
+/// An iterator source that produces elements indefinitely by calling
+/// a given closure.
+///
+/// See [`RepeatCall`](struct.RepeatCall.html) for more information.
+///
+/// ```
+/// use itertools::repeat_call;
+///
+/// let mut n = 0;
+/// itertools::assert_equal(repeat_call(|| { n += 2; n }).take(3), vec![2, 4, 6]);
+/// ```
+pub fn repeat_call<A, F>(func: F) -> RepeatCall<F>
+    where F: FnMut() -> A
+{
+    RepeatCall::new(func)
+}
+
 impl<A, F> Iterator for RepeatCall<F>
     where F: FnMut() -> A
 {
@@ -114,6 +131,32 @@ impl<A, St, F> Unfold<St, F>
     }
 }
 
+/// Creates a new unfold source with the specified closure as the "iterator
+/// function" and an initial state to eventually pass to the closure.
+///
+/// See [`Unfold`](struct.Unfold.html) for more information.
+///
+/// ```
+/// use itertools::unfold;
+///
+/// // the powers of two that fit in a `u8`
+/// let powers = unfold(1_u8, |x| {
+///     let ret = *x;
+///     if ret == 0 {
+///         return None;
+///     }
+///     *x = x.wrapping_mul(2);
+///     Some(ret)
+/// });
+///
+/// itertools::assert_equal(powers, vec![1, 2, 4, 8, 16, 32, 64, 128]);
+/// ```
+pub fn unfold<A, St, F>(initial_state: St, f: F) -> Unfold<St, F>
+    where F: FnMut(&mut St) -> Option<A>
+{
+    Unfold::new(initial_state, f)
+}
+
 impl<A, St, F> Iterator for Unfold<St, F>
     where F: FnMut(&mut St) -> Option<A>
 {
@@ -130,3 +173,260 @@ impl<A, St, F> Iterator for Unfold<St, F>
         (0, None)
     }
 }
+
+/// An iterator that infinitely applies a function to the previous value,
+/// starting from an initial one.
+///
+/// See [`iterate()`](fn.iterate.html) for more information.
+#[derive(Clone)]
+pub struct Iterate<St, F> {
+    state: St,
+    f: F,
+}
+
+impl<St, F> Iterator for Iterate<St, F>
+    where F: FnMut(&St) -> St
+{
+    type Item = St;
+
+    #[inline]
+    fn next(&mut self) -> Option<St> {
+        let next_state = (self.f)(&self.state);
+        Some(::std::mem::replace(&mut self.state, next_state))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::max_value(), None)
+    }
+}
+
+/// Creates a new iterator that infinitely applies `f` to the previous
+/// value, starting with `initial_value`: it yields `initial_value`,
+/// `f(initial_value)`, `f(f(initial_value))`, and so on.
+///
+/// `f` is called once before each element is produced, so it has already
+/// been applied to an element by the time that element is returned.
+///
+/// ```
+/// use itertools::iterate;
+///
+/// itertools::assert_equal(iterate(1, |&i| i * 3).take(5), vec![1, 3, 9, 27, 81]);
+/// ```
+pub fn iterate<St, F>(initial_value: St, f: F) -> Iterate<St, F>
+    where F: FnMut(&St) -> St
+{
+    Iterate {
+        state: initial_value,
+        f: f,
+    }
+}
+
+/// `UnfoldMany` is like `Unfold`, but each call of the closure may produce
+/// any number of elements.
+///
+/// The closure receives the state and an output buffer, pushes the elements
+/// of the next burst onto the buffer and returns whether to keep going.
+/// The buffered elements are produced in order before the closure is called
+/// again. When the closure returns `false`, the elements it pushed during
+/// that call are still produced, and then the iterator is done.
+///
+/// The output buffer is reused between calls.
+///
+/// ```
+/// use itertools::unfold_many;
+///
+/// // decode run-length encoded pairs of (count, value)
+/// let encoded = vec![(3, 'a'), (0, 'x'), (1, 'b'), (2, 'c')];
+/// let decoded = unfold_many(encoded.into_iter(), |input, out| {
+///     match input.next() {
+///         Some((n, ch)) => {
+///             for _ in 0..n {
+///                 out.push(ch);
+///             }
+///             true
+///         }
+///         None => false,
+///     }
+/// });
+///
+/// itertools::assert_equal(decoded, "aaabcc".chars());
+/// ```
+#[derive(Clone)]
+#[cfg(feature = "use_std")]
+pub struct UnfoldMany<St, F, A> {
+    f: F,
+    /// Internal state that will be passed to the closure on the next iteration
+    pub state: St,
+    // the current burst, in reverse order
+    buf: Vec<A>,
+    done: bool,
+}
+
+#[cfg(feature = "use_std")]
+impl<A, St, F> UnfoldMany<St, F, A>
+    where F: FnMut(&mut St, &mut Vec<A>) -> bool
+{
+    /// Creates a new iterator with the specified closure as the "iterator
+    /// function" and an initial state to eventually pass to the closure
+    pub fn new(initial_state: St, f: F) -> UnfoldMany<St, F, A> {
+        UnfoldMany {
+            f: f,
+            state: initial_state,
+            buf: Vec::new(),
+            done: false,
+        }
+    }
+}
+
+/// Create a new `UnfoldMany` iterator.
+///
+/// See [`UnfoldMany`](struct.UnfoldMany.html) for more information.
+#[cfg(feature = "use_std")]
+pub fn unfold_many<A, St, F>(initial_state: St, f: F) -> UnfoldMany<St, F, A>
+    where F: FnMut(&mut St, &mut Vec<A>) -> bool
+{
+    UnfoldMany::new(initial_state, f)
+}
+
+#[cfg(feature = "use_std")]
+impl<A, St, F> Iterator for UnfoldMany<St, F, A>
+    where F: FnMut(&mut St, &mut Vec<A>) -> bool
+{
+    type Item = A;
+
+    fn next(&mut self) -> Option<A> {
+        loop {
+            if let Some(elt) = self.buf.pop() {
+                return Some(elt);
+            }
+            if self.done {
+                return None;
+            }
+            if !(self.f)(&mut self.state, &mut self.buf) {
+                self.done = true;
+            }
+            self.buf.reverse();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (self.buf.len(), Some(self.buf.len()))
+        } else {
+            (self.buf.len(), None)
+        }
+    }
+}
+
+/// An iterator source over every index of a multi-dimensional shape, in
+/// row-major order.
+///
+/// See [`indices()`](fn.indices.html) for more information.
+#[derive(Clone, Debug)]
+#[cfg(feature = "use_std")]
+pub struct Indices {
+    shape: Vec<usize>,
+    /// Linear position of the next index from the front
+    front: usize,
+    /// Linear position one past the next index from the back
+    back: usize,
+}
+
+/// Iterate over every index of the multi-dimensional `shape`, in row-major
+/// order (the last axis varies fastest).
+///
+/// Each index is a `Vec` with one coordinate per axis. The iterator has an
+/// exact size, is double ended, and `nth` jumps directly to its element.
+/// A shape with no axes has one, empty, index; a shape with an axis of
+/// length 0 has none.
+///
+/// Iterator element type is `Vec<usize>`.
+///
+/// **Panics** if the number of indices overflows `usize`.
+///
+/// ```
+/// use itertools::indices;
+///
+/// itertools::assert_equal(indices(&[2, 3]), vec![
+///     vec![0, 0], vec![0, 1], vec![0, 2],
+///     vec![1, 0], vec![1, 1], vec![1, 2],
+/// ]);
+///
+/// let mut it = indices(&[10, 10, 10]);
+/// assert_eq!(it.len(), 1000);
+/// assert_eq!(it.nth(123), Some(vec![1, 2, 3]));
+/// assert_eq!(it.next_back(), Some(vec![9, 9, 9]));
+/// ```
+#[cfg(feature = "use_std")]
+pub fn indices(shape: &[usize]) -> Indices {
+    let mut len: usize = 1;
+    for &axis in shape {
+        len = match len.checked_mul(axis) {
+            Some(len) => len,
+            None => panic!("indices: the number of indices overflows usize"),
+        };
+    }
+    Indices {
+        shape: shape.to_vec(),
+        front: 0,
+        back: len,
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl Indices {
+    /// Return the multi-dimensional index at linear position `pos`.
+    fn index_at(&self, mut pos: usize) -> Vec<usize> {
+        let mut index = vec![0; self.shape.len()];
+        for (coord, &axis) in index.iter_mut().zip(&self.shape).rev() {
+            *coord = pos % axis;
+            pos /= axis;
+        }
+        index
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl Iterator for Indices {
+    type Item = Vec<usize>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Vec<usize>> {
+        if self.front == self.back {
+            return None;
+        }
+        let index = self.index_at(self.front);
+        self.front += 1;
+        Some(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Vec<usize>> {
+        if n >= self.back - self.front {
+            self.front = self.back;
+            return None;
+        }
+        self.front += n;
+        self.next()
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl DoubleEndedIterator for Indices {
+    #[inline]
+    fn next_back(&mut self) -> Option<Vec<usize>> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.index_at(self.back))
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl ExactSizeIterator for Indices {}
@@ -10,6 +10,8 @@ struct TeeBuffer<A, I> {
     iter: I,
     /// The owner field indicates which id should read from the backlog
     owner: bool,
+    /// Maximum length of the backlog, usize::MAX == unbounded
+    max_backlog: usize,
 }
 
 /// One half of an iterator pair where both return the same elements.
@@ -25,7 +27,14 @@ pub struct Tee<I>
 pub fn new<I>(iter: I) -> (Tee<I>, Tee<I>)
     where I: Iterator
 {
-    let buffer = TeeBuffer{backlog: VecDeque::new(), iter: iter, owner: false};
+    new_bounded(iter, !0)
+}
+
+pub fn new_bounded<I>(iter: I, max_backlog: usize) -> (Tee<I>, Tee<I>)
+    where I: Iterator
+{
+    let buffer = TeeBuffer{backlog: VecDeque::new(), iter: iter, owner: false,
+                           max_backlog: max_backlog};
     let t1 = Tee{rcbuffer: Rc::new(RefCell::new(buffer)), id: true};
     let t2 = Tee{rcbuffer: t1.rcbuffer.clone(), id: false};
     (t1, t2)
@@ -49,8 +58,15 @@ impl<I> Iterator for Tee<I>
         match buffer.iter.next() {
             None => None,
             Some(elt) => {
-                buffer.backlog.push_back(elt.clone());
-                buffer.owner = !self.id;
+                // once the other half is dropped, nobody reads the backlog
+                if Rc::strong_count(&self.rcbuffer) > 1 {
+                    if buffer.backlog.len() >= buffer.max_backlog {
+                        panic!("Tee: backlog would exceed the bound of {} elements",
+                               buffer.max_backlog);
+                    }
+                    buffer.backlog.push_back(elt.clone());
+                    buffer.owner = !self.id;
+                }
                 Some(elt)
             }
         }
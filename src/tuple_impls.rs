@@ -0,0 +1,151 @@
+#[cfg(feature = "use_std")]
+use std::collections::VecDeque;
+#[cfg(feature = "use_std")]
+use std::marker::PhantomData;
+
+/// A tuple of elements that all have the same type, which can be filled
+/// from an iterator.
+///
+/// Implemented for tuples of 1 to 12 elements. Used by
+/// [*.next_tuple()*](trait.Itertools.html#method.next_tuple),
+/// [*.collect_tuple()*](trait.Itertools.html#method.collect_tuple) and
+/// [*.ref_tuple_windows()*](trait.Itertools.html#method.ref_tuple_windows).
+pub trait TupleCollect: Sized {
+    /// The type of each of the tuple's elements.
+    type Item;
+
+    /// Take the next elements of `iter` to fill a tuple, or return `None`
+    /// if it runs out first.
+    fn collect_from_iter<I>(iter: I) -> Option<Self>
+        where I: IntoIterator<Item = Self::Item>;
+
+    /// The number of elements in the tuple.
+    fn num_items() -> usize;
+}
+
+/// An iterator over all windows of consecutive elements of an iterator of
+/// references, as tuples of references.
+///
+/// See [*.ref_tuple_windows()*](trait.Itertools.html#method.ref_tuple_windows) for more information.
+#[cfg(feature = "use_std")]
+pub struct RefTupleWindows<I, T>
+    where I: Iterator,
+{
+    iter: I,
+    /// The elements of the last window, oldest first
+    window: VecDeque<I::Item>,
+    _tuple: PhantomData<T>,
+}
+
+/// Create a new `RefTupleWindows`.
+#[cfg(feature = "use_std")]
+pub fn ref_tuple_windows<I, T>(iter: I) -> RefTupleWindows<I, T>
+    where I: Iterator,
+          T: TupleCollect<Item = I::Item>,
+{
+    RefTupleWindows {
+        iter: iter,
+        window: VecDeque::with_capacity(T::num_items()),
+        _tuple: PhantomData,
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<I, T> Iterator for RefTupleWindows<I, T>
+    where I: Iterator,
+          I::Item: Copy,
+          T: TupleCollect<Item = I::Item>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let n = T::num_items();
+        if self.window.len() < n {
+            // first window
+            while self.window.len() < n {
+                match self.iter.next() {
+                    None => return None,
+                    Some(elt) => self.window.push_back(elt),
+                }
+            }
+        } else {
+            match self.iter.next() {
+                None => return None,
+                Some(elt) => {
+                    self.window.pop_front();
+                    self.window.push_back(elt);
+                }
+            }
+        }
+        T::collect_from_iter(self.window.iter().cloned())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = T::num_items();
+        let sh = self.iter.size_hint();
+        if self.window.len() < n {
+            // the first window still needs `missing` elements, every
+            // element after that is one more window
+            let missing = n - self.window.len();
+            let windows = |len: usize| if len >= missing { len - missing + 1 } else { 0 };
+            (windows(sh.0), sh.1.map(windows))
+        } else {
+            sh
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<I, T> ExactSizeIterator for RefTupleWindows<I, T>
+    where I: ExactSizeIterator,
+          I::Item: Copy,
+          T: TupleCollect<Item = I::Item>,
+{}
+
+macro_rules! ignore_ident {
+    ($id:ident, $($t:tt)*) => { $($t)* };
+}
+
+macro_rules! count_ident {
+    () => { 0 };
+    ($i0:ident $($i:ident)*) => { 1 + count_ident!($($i)*) };
+}
+
+macro_rules! impl_tuple_collect {
+    ($($B:ident),*) => (
+        #[allow(non_snake_case)]
+        impl<A> TupleCollect for ($(ignore_ident!($B, A),)*) {
+            type Item = A;
+
+            fn collect_from_iter<I>(iter: I) -> Option<Self>
+                where I: IntoIterator<Item = A>
+            {
+                let mut iter = iter.into_iter();
+                $(
+                    let $B = match iter.next() {
+                        None => return None,
+                        Some(elt) => elt,
+                    };
+                )*
+                Some(($($B,)*))
+            }
+
+            fn num_items() -> usize {
+                count_ident!($($B)*)
+            }
+        }
+    );
+}
+
+impl_tuple_collect!(a);
+impl_tuple_collect!(a, b);
+impl_tuple_collect!(a, b, c);
+impl_tuple_collect!(a, b, c, d);
+impl_tuple_collect!(a, b, c, d, e);
+impl_tuple_collect!(a, b, c, d, e, f);
+impl_tuple_collect!(a, b, c, d, e, f, g);
+impl_tuple_collect!(a, b, c, d, e, f, g, h);
+impl_tuple_collect!(a, b, c, d, e, f, g, h, i);
+impl_tuple_collect!(a, b, c, d, e, f, g, h, i, j);
+impl_tuple_collect!(a, b, c, d, e, f, g, h, i, j, k);
+impl_tuple_collect!(a, b, c, d, e, f, g, h, i, j, k, l);
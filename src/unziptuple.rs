@@ -0,0 +1,49 @@
+/// Unzip an iterator of tuples into a tuple of containers.
+///
+/// See [*.multiunzip()*](trait.Itertools.html#method.multiunzip) for more information.
+pub trait MultiUnzip<FromI>: Iterator {
+    /// Unzip this iterator into a tuple of collections.
+    fn multiunzip(self) -> FromI;
+}
+
+macro_rules! impl_unzip_iter {
+    ($($T:ident => $FromT:ident),*) => (
+        #[allow(non_snake_case)]
+        impl<IT, $($T, $FromT),*> MultiUnzip<($($FromT,)*)> for IT
+            where IT: Iterator<Item = ($($T,)*)>,
+                  $($FromT: Default + Extend<$T>,)*
+        {
+            fn multiunzip(self) -> ($($FromT,)*) {
+                let mut res = ($($FromT::default(),)*);
+                {
+                    let ($(ref mut $FromT,)*) = res;
+                    for ($($T,)*) in self {
+                        $(
+                            $FromT.extend(Some($T));
+                        )*
+                    }
+                }
+                res
+            }
+        }
+    );
+}
+
+impl_unzip_iter!(A => FromA);
+impl_unzip_iter!(A => FromA, B => FromB);
+impl_unzip_iter!(A => FromA, B => FromB, C => FromC);
+impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD);
+impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD, E => FromE);
+impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD, E => FromE, F => FromF);
+impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD, E => FromE, F => FromF,
+                 G => FromG);
+impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD, E => FromE, F => FromF,
+                 G => FromG, H => FromH);
+impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD, E => FromE, F => FromF,
+                 G => FromG, H => FromH, I => FromI);
+impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD, E => FromE, F => FromF,
+                 G => FromG, H => FromH, I => FromI, J => FromJ);
+impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD, E => FromE, F => FromF,
+                 G => FromG, H => FromH, I => FromI, J => FromJ, K => FromK);
+impl_unzip_iter!(A => FromA, B => FromB, C => FromC, D => FromD, E => FromE, F => FromF,
+                 G => FromG, H => FromH, I => FromI, J => FromJ, K => FromK, L => FromL);
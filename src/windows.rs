@@ -0,0 +1,59 @@
+use std::iter::Fuse;
+
+/// A sliding window of `n` consecutive elements over an iterator.
+///
+/// `Windows` is not an `Iterator`: each window borrows the internal buffer,
+/// so it is taken with `.next_window()` and must be released before the
+/// next one is taken.
+///
+/// See [*.windows()*](trait.Itertools.html#method.windows) for more information.
+#[derive(Clone, Debug)]
+pub struct Windows<I>
+    where I: Iterator
+{
+    iter: Fuse<I>,
+    buf: Vec<I::Item>,
+    n: usize,
+}
+
+impl<I> Windows<I>
+    where I: Iterator
+{
+    /// Create a new `Windows`.
+    ///
+    /// **Panics** if `n` is 0.
+    pub fn new(iter: I, n: usize) -> Self {
+        assert!(n != 0, "windows: window size must be non-zero");
+        Windows {
+            iter: iter.fuse(),
+            buf: Vec::new(),
+            n: n,
+        }
+    }
+
+    /// Return the next window, or `None` when the iterator is exhausted.
+    ///
+    /// The buffer holds at most `2 * n` elements; every `n` windows, the
+    /// older half is dropped, so each element is moved at most once.
+    pub fn next_window(&mut self) -> Option<&[I::Item]> {
+        let n = self.n;
+        if self.buf.len() < n {
+            while self.buf.len() < n {
+                match self.iter.next() {
+                    Some(elt) => self.buf.push(elt),
+                    None => return None,
+                }
+            }
+        } else {
+            let elt = match self.iter.next() {
+                Some(elt) => elt,
+                None => return None,
+            };
+            if self.buf.len() == 2 * n {
+                self.buf.drain(..n);
+            }
+            self.buf.push(elt);
+        }
+        Some(&self.buf[self.buf.len() - n..])
+    }
+}
@@ -0,0 +1,202 @@
+use std::iter::{Fuse, FusedIterator, Peekable};
+use std::mem::replace;
+
+/// An iterator adaptor that wraps each element in a [`Position`](enum.Position.html).
+///
+/// Iterator element type is `Position<I::Item>`.
+///
+/// This iterator is *fused*.
+///
+/// See [*.with_position()*](trait.Itertools.html#method.with_position) for more information.
+pub struct WithPosition<I>
+    where I: Iterator
+{
+    handled_first: bool,
+    peekable: Peekable<Fuse<I>>,
+}
+
+impl<I> Clone for WithPosition<I>
+    where I: Clone + Iterator,
+          I::Item: Clone
+{
+    fn clone(&self) -> Self {
+        WithPosition {
+            handled_first: self.handled_first,
+            peekable: self.peekable.clone(),
+        }
+    }
+}
+
+/// Create a new `WithPosition` iterator.
+pub fn with_position<I>(iter: I) -> WithPosition<I>
+    where I: Iterator
+{
+    WithPosition {
+        handled_first: false,
+        peekable: iter.fuse().peekable(),
+    }
+}
+
+/// A value yielded by `WithPosition`.
+/// Indicates the position of this element in the iterator results.
+///
+/// See [*.with_position()*](trait.Itertools.html#method.with_position) for more information.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Position<T> {
+    /// This is the first element.
+    First(T),
+    /// This is neither the first nor the last element.
+    Middle(T),
+    /// This is the last element.
+    Last(T),
+    /// This is the only element.
+    Only(T),
+}
+
+impl<T> Position<T> {
+    /// Return the inner value.
+    pub fn into_inner(self) -> T {
+        match self {
+            Position::First(x) |
+            Position::Middle(x) |
+            Position::Last(x) |
+            Position::Only(x) => x,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for WithPosition<I> {
+    type Item = Position<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.peekable.next() {
+            Some(item) => {
+                if !self.handled_first {
+                    // Haven't seen the first item yet, and there is one to give.
+                    self.handled_first = true;
+                    // Peek to see if this is also the last item,
+                    // in which case tag it as `Only`.
+                    match self.peekable.peek() {
+                        Some(_) => Some(Position::First(item)),
+                        None => Some(Position::Only(item)),
+                    }
+                } else {
+                    // Have seen the first item, and there's something left.
+                    // Peek to see if this is the last item.
+                    match self.peekable.peek() {
+                        Some(_) => Some(Position::Middle(item)),
+                        None => Some(Position::Last(item)),
+                    }
+                }
+            }
+            // Iterator is finished.
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.peekable.size_hint()
+    }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+        where F: FnMut(B, Self::Item) -> B
+    {
+        let mut accum = init;
+        if let Some(mut head) = self.peekable.next() {
+            if !self.handled_first {
+                match self.peekable.next() {
+                    Some(second) => {
+                        let first = replace(&mut head, second);
+                        accum = f(accum, Position::First(first));
+                    }
+                    None => return f(accum, Position::Only(head)),
+                }
+            }
+            // keep one element in hand so the last one can be told apart
+            accum = self.peekable.fold(accum, |accum, item| {
+                let middle = replace(&mut head, item);
+                f(accum, Position::Middle(middle))
+            });
+            accum = f(accum, Position::Last(head));
+        }
+        accum
+    }
+}
+
+impl<I: Iterator> FusedIterator for WithPosition<I> {}
+
+impl<I> ExactSizeIterator for WithPosition<I>
+    where I: ExactSizeIterator,
+{ }
+
+/// An iterator adaptor that maps the first element, the last element and
+/// the elements in between with different closures.
+///
+/// See [*.map_ends()*](trait.Itertools.html#method.map_ends) for more information.
+pub struct MapEnds<I, F, G, H>
+    where I: Iterator
+{
+    iter: WithPosition<I>,
+    first: F,
+    mid: G,
+    last: H,
+}
+
+impl<I, F, G, H> Clone for MapEnds<I, F, G, H>
+    where I: Clone + Iterator,
+          I::Item: Clone,
+          F: Clone,
+          G: Clone,
+          H: Clone
+{
+    fn clone(&self) -> Self {
+        MapEnds {
+            iter: self.iter.clone(),
+            first: self.first.clone(),
+            mid: self.mid.clone(),
+            last: self.last.clone(),
+        }
+    }
+}
+
+/// Create a new `MapEnds` iterator.
+pub fn map_ends<I, F, G, H>(iter: I, first: F, mid: G, last: H) -> MapEnds<I, F, G, H>
+    where I: Iterator
+{
+    MapEnds {
+        iter: with_position(iter),
+        first: first,
+        mid: mid,
+        last: last,
+    }
+}
+
+impl<B, I, F, G, H> Iterator for MapEnds<I, F, G, H>
+    where I: Iterator,
+          F: FnMut(I::Item) -> B,
+          G: FnMut(I::Item) -> B,
+          H: FnMut(I::Item) -> B
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        match self.iter.next() {
+            Some(Position::First(x)) |
+            Some(Position::Only(x)) => Some((self.first)(x)),
+            Some(Position::Middle(x)) => Some((self.mid)(x)),
+            Some(Position::Last(x)) => Some((self.last)(x)),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<B, I, F, G, H> ExactSizeIterator for MapEnds<I, F, G, H>
+    where I: ExactSizeIterator,
+          F: FnMut(I::Item) -> B,
+          G: FnMut(I::Item) -> B,
+          H: FnMut(I::Item) -> B
+{}
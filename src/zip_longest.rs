@@ -1,6 +1,6 @@
 use std::cmp::Ordering::{Equal, Greater, Less};
 use super::size_hint;
-use std::iter::Fuse;
+use std::iter::{Fuse, FusedIterator};
 use self::EitherOrBoth::{Right, Left, Both};
 
 // ZipLongest originally written by SimonSapin,
@@ -53,6 +53,11 @@ impl<T, U> Iterator for ZipLongest<T, U>
     }
 }
 
+impl<T, U> FusedIterator for ZipLongest<T, U>
+    where T: Iterator,
+          U: Iterator
+{}
+
 impl<T, U> DoubleEndedIterator for ZipLongest<T, U>
     where T: DoubleEndedIterator + ExactSizeIterator,
           U: DoubleEndedIterator + ExactSizeIterator
@@ -79,6 +84,106 @@ impl<T, U> ExactSizeIterator for ZipLongest<T, U>
 {}
 
 
+/// An iterator which iterates two other iterators simultaneously and maps
+/// each pair, or lone element, with one of three closures.
+///
+/// This iterator is *fused*.
+///
+/// See [*.zip_longest_map()*](trait.Itertools.html#method.zip_longest_map) for more information.
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ZipLongestMap<T, U, FB, FL, FR> {
+    iter: ZipLongest<T, U>,
+    on_both: FB,
+    on_left: FL,
+    on_right: FR,
+}
+
+/// Create a new `ZipLongestMap` iterator.
+pub fn zip_longest_map<T, U, FB, FL, FR>(a: T, b: U, on_both: FB, on_left: FL, on_right: FR)
+    -> ZipLongestMap<T, U, FB, FL, FR>
+    where T: Iterator,
+          U: Iterator
+{
+    ZipLongestMap {
+        iter: ZipLongest::new(a, b),
+        on_both: on_both,
+        on_left: on_left,
+        on_right: on_right,
+    }
+}
+
+impl<T, U, FB, FL, FR> ZipLongestMap<T, U, FB, FL, FR> {
+    fn map<R>(&mut self, elt: EitherOrBoth<T::Item, U::Item>) -> R
+        where T: Iterator,
+              U: Iterator,
+              FB: FnMut(T::Item, U::Item) -> R,
+              FL: FnMut(T::Item) -> R,
+              FR: FnMut(U::Item) -> R
+    {
+        match elt {
+            Both(a, b) => (self.on_both)(a, b),
+            Left(a) => (self.on_left)(a),
+            Right(b) => (self.on_right)(b),
+        }
+    }
+}
+
+impl<T, U, FB, FL, FR, R> Iterator for ZipLongestMap<T, U, FB, FL, FR>
+    where T: Iterator,
+          U: Iterator,
+          FB: FnMut(T::Item, U::Item) -> R,
+          FL: FnMut(T::Item) -> R,
+          FR: FnMut(U::Item) -> R
+{
+    type Item = R;
+
+    #[inline]
+    fn next(&mut self) -> Option<R> {
+        match self.iter.next() {
+            Some(elt) => Some(self.map(elt)),
+            None => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, U, FB, FL, FR, R> FusedIterator for ZipLongestMap<T, U, FB, FL, FR>
+    where T: Iterator,
+          U: Iterator,
+          FB: FnMut(T::Item, U::Item) -> R,
+          FL: FnMut(T::Item) -> R,
+          FR: FnMut(U::Item) -> R
+{}
+
+impl<T, U, FB, FL, FR, R> DoubleEndedIterator for ZipLongestMap<T, U, FB, FL, FR>
+    where T: DoubleEndedIterator + ExactSizeIterator,
+          U: DoubleEndedIterator + ExactSizeIterator,
+          FB: FnMut(T::Item, U::Item) -> R,
+          FL: FnMut(T::Item) -> R,
+          FR: FnMut(U::Item) -> R
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<R> {
+        match self.iter.next_back() {
+            Some(elt) => Some(self.map(elt)),
+            None => None,
+        }
+    }
+}
+
+impl<T, U, FB, FL, FR, R> ExactSizeIterator for ZipLongestMap<T, U, FB, FL, FR>
+    where T: ExactSizeIterator,
+          U: ExactSizeIterator,
+          FB: FnMut(T::Item, U::Item) -> R,
+          FL: FnMut(T::Item) -> R,
+          FR: FnMut(U::Item) -> R
+{}
+
 /// A value yielded by `ZipLongest`.
 /// Contains one or two values, depending on which of the input iterators are exhausted.
 ///
@@ -38,6 +38,33 @@ impl<T> Zip<T>
     }
 }
 
+/// An iterator that generalizes *.zip()* and allows running multiple iterators in lockstep.
+///
+/// `multizip((a, b, c))` is the same as `Zip::new((a, b, c))`: it takes a
+/// tuple of up to 9 values that implement `IntoIterator`, and yields flat
+/// tuples of their elements until any of them is exhausted.
+///
+/// ```
+/// use itertools::multizip;
+///
+/// let xs = [1, 2, 3];
+/// let ys = ['a', 'b', 'c', 'd'];
+/// let zs = vec![true, false, true];
+///
+/// let mut it = multizip((&xs, &ys, zs));
+/// assert_eq!(it.len(), 3);
+/// assert_eq!(it.next(), Some((&1, &'a', true)));
+/// assert_eq!(it.next_back(), Some((&3, &'c', true)));
+/// assert_eq!(it.next(), Some((&2, &'b', false)));
+/// assert_eq!(it.next(), None);
+/// ```
+pub fn multizip<T, U>(t: U) -> Zip<T>
+    where Zip<T>: Iterator,
+          U: IntoIteratorTuple<Output = T>,
+{
+    Zip::new(t)
+}
+
 macro_rules! impl_zip_iter {
     ($($B:ident),*) => (
         #[allow(non_snake_case)]
@@ -94,6 +121,31 @@ macro_rules! impl_zip_iter {
                 $B: ExactSizeIterator,
             )*
         { }
+
+        #[allow(non_snake_case)]
+        impl<$($B),*> DoubleEndedIterator for Zip<($($B,)*)> where
+            $(
+                $B: DoubleEndedIterator + ExactSizeIterator,
+            )*
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let ($(ref mut $B,)*) = self.t;
+                let size = *[$($B.len(),)*].iter().min().unwrap();
+
+                // trim the longer iterators so that all end at the same place
+                $(
+                    for _ in size..$B.len() {
+                        $B.next_back();
+                    }
+                )*
+
+                match ($($B.next_back(),)*) {
+                    ($(Some($B),)*) => Some(($($B,)*)),
+                    _ => None,
+                }
+            }
+        }
     );
 }
 
@@ -220,6 +220,24 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    exact_take_exact(2),
+    fn prop(a: Vec<i16>, n: usize) -> bool {
+        let n = if a.is_empty() { 0 } else { n % a.len() };
+        exact_size(a.iter().take_exact(n)) &&
+            itertools::equal(a.iter().take_exact(n), a.iter().take(n))
+    }
+}
+
+quickcheck! {
+    equal_step(2),
+    fn prop(a: Vec<i16>, s: u8) -> bool {
+        let s = s as usize % 16 + 1;
+        itertools::equal(a.iter().step(s),
+                         a.iter().enumerate().filter(|&(i, _)| i % s == 0).map(|(_, x)| x))
+    }
+}
+
 quickcheck! {
     size_step(2),
     fn prop(a: Iter<i16>, mut s: usize) -> bool {
@@ -232,6 +250,59 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    size_cycled_to_len(2),
+    fn prop(a: Iter<i16>, total: u8) -> bool {
+        exact_size(a.cycled_to_len(total as usize))
+    }
+}
+
+quickcheck! {
+    size_merge_join_by(2),
+    fn prop(a: Iter<i16>, b: Iter<i16>) -> bool {
+        correct_size_hint(a.merge_join_by(b, |x, y| x.cmp(y)))
+    }
+}
+
+quickcheck! {
+    equal_merge_join_by(2),
+    fn prop(a: Vec<u8>, b: Vec<u8>) -> bool {
+        use itertools::EitherOrBoth::{Left, Right, Both};
+        let mut sa = a.clone();
+        let mut sb = b.clone();
+        sa.sort();
+        sb.sort();
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        for elt in sa.iter().merge_join_by(&sb, |x, y| x.cmp(y)) {
+            match elt {
+                Left(x) => left.push(*x),
+                Right(y) => right.push(*y),
+                Both(x, y) => { left.push(*x); right.push(*y); }
+            }
+        }
+        left == sa && right == sb
+    }
+}
+
+quickcheck! {
+    equal_kmerge_by_ge(3),
+    fn prop(a: Vec<i16>, b: Vec<i16>, c: Vec<i16>) -> bool {
+        let mut sa = a.clone();
+        let mut sb = b.clone();
+        let mut sc = c.clone();
+        sa.sort_by(|a, b| b.cmp(a));
+        sb.sort_by(|a, b| b.cmp(a));
+        sc.sort_by(|a, b| b.cmp(a));
+        let mut merged = sa.clone();
+        merged.extend(sb.iter().cloned());
+        merged.extend(sc.iter().cloned());
+        merged.sort_by(|a, b| b.cmp(a));
+        itertools::equal(merged.into_iter(),
+                         vec![sa, sb, sc].into_iter().kmerge_by(|x, y| x >= y))
+    }
+}
+
 quickcheck! {
     size_multipeek(2),
     fn prop(a: Iter<u16>, s: u8) -> bool {
@@ -283,6 +354,15 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    equal_multizip_rev(3),
+    fn prop(a: Vec<u8>, b: Vec<u8>, c: Vec<u8>) -> bool {
+        let mut forward = itertools::multizip((&a, &b, &c)).collect::<Vec<_>>();
+        forward.reverse();
+        itertools::equal(itertools::multizip((&a, &b, &c)).rev(), forward)
+    }
+}
+
 quickcheck! {
     equal_kmerge(3),
     fn prop(a: Vec<i16>, b: Vec<i16>, c: Vec<i16>) -> bool {
@@ -374,6 +454,178 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    size_buffered(2),
+    fn prop(a: Iter<i16>, n: u8) -> bool {
+        let n = n as usize % 8 + 1;
+        correct_size_hint(a.buffered(n))
+    }
+}
+
+quickcheck! {
+    equal_buffered(2),
+    fn prop(a: Vec<i16>, n: u8) -> bool {
+        let n = n as usize % 8 + 1;
+        let mut it = a.iter().buffered(n);
+        let mut i = 0;
+        while let Some(elt) = it.next() {
+            if elt != &a[i] || it.fill_level() != ::std::cmp::min(n - 1, a.len() - i - 1) {
+                return false;
+            }
+            i += 1;
+        }
+        i == a.len()
+    }
+}
+
+quickcheck! {
+    size_with_position(1),
+    fn prop(a: Iter<i16>) -> bool {
+        correct_size_hint(a.with_position())
+    }
+}
+
+quickcheck! {
+    exact_with_position(1),
+    fn prop(a: Vec<i16>) -> bool {
+        exact_size(a.iter().with_position())
+    }
+}
+
+quickcheck! {
+    size_zip_offset(2),
+    fn prop(a: Iter<i16>, lag: u8) -> bool {
+        correct_size_hint(a.zip_offset(lag as usize))
+    }
+}
+
+quickcheck! {
+    equal_zip_offset(2),
+    fn prop(a: Vec<i16>, lag: u8) -> bool {
+        let lag = lag as usize;
+        itertools::equal(a.iter().zip_offset(lag),
+                         a.iter().zip(a.iter().skip(lag)))
+    }
+}
+
+quickcheck! {
+    size_positions(1),
+    fn prop(a: Iter<i16>) -> bool {
+        correct_size_hint(a.positions(|x| x % 3 == 0))
+    }
+}
+
+quickcheck! {
+    equal_positions_rev(1),
+    fn prop(a: Vec<i16>) -> bool {
+        let mut fwd = a.iter().positions(|x| x % 3 == 0).collect_vec();
+        fwd.reverse();
+        itertools::equal(fwd, a.iter().positions(|x| x % 3 == 0).rev())
+    }
+}
+
+quickcheck! {
+    size_round_robin(3),
+    fn prop(a: Iter<i16>, b: Iter<i16>, c: Iter<i16>) -> bool {
+        correct_size_hint(vec![a, b, c].into_iter().round_robin())
+    }
+}
+
+quickcheck! {
+    equal_round_robin(1),
+    fn prop(a: Vec<Vec<i16>>) -> bool {
+        let n = a.iter().map(|v| v.len()).max().unwrap_or(0);
+        let mut b = Vec::new();
+        for i in 0..n {
+            for v in &a {
+                if let Some(x) = v.get(i) {
+                    b.push(x);
+                }
+            }
+        }
+        itertools::equal(b, a.iter().round_robin())
+    }
+}
+
+quickcheck! {
+    equal_with_chunk_index(2),
+    fn prop(a: Vec<i16>, n: u8) -> bool {
+        let n = n as usize % 8 + 1;
+        let mut b = Vec::new();
+        for (i, chunk) in (&a.iter().chunks_lazy(n)).into_iter().enumerate() {
+            for x in chunk {
+                b.push((i, x));
+            }
+        }
+        itertools::equal(b, a.iter().with_chunk_index(n))
+    }
+}
+
+quickcheck! {
+    equal_while_some(1),
+    fn prop(a: Vec<Option<i16>>) -> bool {
+        itertools::equal(a.iter().cloned().while_some(),
+                         a.iter().take_while(|x| x.is_some()).map(|x| x.unwrap())) &&
+            correct_size_hint(a.into_iter().while_some())
+    }
+}
+
+quickcheck! {
+    equal_fold_chunks_exact(2),
+    fn prop(a: Vec<i16>, n: u8) -> bool {
+        let n = n as usize % 8 + 1;
+        let full = a.len() / n * n;
+        let mut it = a.iter().fold_chunks_exact(n, Vec::new, |mut v, x| { v.push(*x); v });
+        exact_size(it.clone()) &&
+            itertools::equal(it.by_ref(), a[..full].chunks(n)) &&
+            itertools::equal(it.into_remainder(), &a[full..]) && {
+            let mut it = a.iter().filter(|_| true)
+                          .fold_chunks_exact(n, Vec::new, |mut v, x| { v.push(*x); v });
+            itertools::equal(it.by_ref(), a[..full].chunks(n)) &&
+                itertools::equal(it.into_remainder(), &a[full..])
+        }
+    }
+}
+
+quickcheck! {
+    equal_flatten_ok(1),
+    fn prop(a: Vec<Result<Vec<i16>, u8>>) -> bool {
+        let mut b = Vec::new();
+        for r in &a {
+            match *r {
+                Ok(ref v) => b.extend(v.iter().cloned().map(Ok)),
+                Err(e) => b.push(Err(e)),
+            }
+        }
+        let fwd = itertools::equal(&b, &a.iter().cloned().flatten_ok().collect_vec());
+        b.reverse();
+        fwd && itertools::equal(b, a.iter().cloned().flatten_ok().rev()) &&
+            correct_size_hint(a.into_iter().flatten_ok())
+    }
+}
+
+quickcheck! {
+    equal_filter_ok(1),
+    fn prop(a: Vec<Result<i16, u8>>) -> bool {
+        let b = a.iter().cloned().filter(|r| match *r {
+            Ok(x) => x % 3 == 0,
+            Err(_) => true,
+        });
+        itertools::equal(a.iter().cloned().filter_ok(|x| x % 3 == 0), b) &&
+            correct_size_hint(a.iter().cloned().filter_ok(|x| x % 3 == 0)) &&
+            itertools::equal(a.iter().cloned().map_ok(|x| x % 3 == 0),
+                             a.iter().cloned().map(|r| r.map(|x| x % 3 == 0)))
+    }
+}
+
+quickcheck! {
+    equal_tree_reduce(1),
+    fn prop(a: Vec<u8>) -> bool {
+        let a = a.into_iter().map(|x| x as u64).collect_vec();
+        a.iter().cloned().tree_reduce(|x, y| x + y) == a.iter().cloned().fold1(|x, y| x + y)
+    }
+}
+
 quickcheck! {
     size_intersperse(2),
     fn prop(a: Iter<i16>, x: i16) -> bool {
@@ -399,6 +651,51 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    size_intersperse_with(2),
+    fn prop(a: Iter<i16>, x: i16) -> bool {
+        correct_size_hint(a.intersperse_with(|| x))
+    }
+}
+
+quickcheck! {
+    equal_intersperse_with(2),
+    fn prop(a: Vec<i32>, x: i32) -> bool {
+        itertools::equal(a.iter().cloned().intersperse_with(|| x),
+                         a.iter().cloned().intersperse(x))
+    }
+}
+
+quickcheck! {
+    size_collapse_runs(1),
+    fn prop(a: Iter<i16>) -> bool {
+        correct_size_hint(a.map(|x| x % 2).collapse_runs(0))
+    }
+}
+
+quickcheck! {
+    equal_collapse_runs(1),
+    fn prop(a: Vec<i8>) -> bool {
+        let a = a.into_iter().map(|x| x % 2).collect_vec();
+        let mut b = Vec::new();
+        for &x in &a {
+            if x != 0 || b.last() != Some(&0) {
+                b.push(x);
+            }
+        }
+        itertools::equal(b, a.into_iter().collapse_runs(0))
+    }
+}
+
+quickcheck! {
+    equal_dedup_by_key(1),
+    fn prop(a: Vec<i32>) -> bool {
+        let mut b = a.clone();
+        b.dedup_by_key(|x| *x / 4);
+        itertools::equal(&b, a.iter().dedup_by_key(|x| **x / 4))
+    }
+}
+
 quickcheck! {
     equal_dedup(1),
     fn prop(a: Vec<i32>) -> bool {
@@ -415,6 +712,25 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    equal_group_by(1),
+    fn prop(a: Vec<i8>) -> bool {
+        let mut groups: Vec<(i8, Vec<i8>)> = Vec::new();
+        for &x in &a {
+            let key = x / 8;
+            match groups.last_mut() {
+                Some(&mut (k, ref mut v)) if k == key => {
+                    v.push(x);
+                    continue;
+                }
+                _ => {}
+            }
+            groups.push((key, vec![x]));
+        }
+        itertools::equal(groups, a.iter().cloned().group_by(|x| x / 8))
+    }
+}
+
 quickcheck! {
     size_group_by(1),
     fn prop(a: Vec<i8>) -> bool {
@@ -592,6 +908,41 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    equal_pad_tail_rev(2),
+    fn prop(a: Vec<i8>, pad: u8) -> bool {
+        let mut fwd = a.iter().cloned().pad_using(pad as usize, |_| 0).collect_vec();
+        fwd.reverse();
+        itertools::equal(fwd, a.iter().cloned().pad_using(pad as usize, |_| 0).rev())
+    }
+}
+
+quickcheck! {
+    size_pad_to_multiple(2),
+    fn prop(it: Iter<i8>, n: u8) -> bool {
+        let n = n as usize % 8 + 1;
+        correct_size_hint(it.pad_to_multiple(n, |_| 0))
+    }
+}
+
+quickcheck! {
+    equal_pad_to_multiple(2),
+    fn prop(a: Vec<i8>, n: u8) -> bool {
+        let n = n as usize % 8 + 1;
+        let b = a.iter().cloned().pad_to_multiple(n, |_| 0).collect_vec();
+        b.len() % n == 0 && b.len() < a.len() + n && b.starts_with(&a) &&
+            exact_size(a.into_iter().pad_to_multiple(n, |_| 0))
+    }
+}
+
+quickcheck! {
+    size_intersperse_every(2),
+    fn prop(it: Iter<i16>, n: u8) -> bool {
+        let n = n as usize % 8 + 1;
+        correct_size_hint(it.intersperse_every(n, 0))
+    }
+}
+
 quickcheck! {
     size_unique(1),
     fn prop(it: Iter<i8>) -> bool {
@@ -599,6 +950,44 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    equal_count_occurrences(3),
+    fn prop(a: Vec<i8>, x: i8, max: u8) -> bool {
+        let count = a.iter().filter(|&&y| y == x).count();
+        a.iter().count_occurrences(&x) == count &&
+            a.iter().count_occurrences_max(&x, max as usize) == ::std::cmp::min(count, max as usize)
+    }
+}
+
+quickcheck! {
+    equal_unique(1),
+    fn prop(a: Vec<i8>) -> bool {
+        let mut seen = Vec::new();
+        for &x in &a {
+            if !seen.contains(&x) {
+                seen.push(x);
+            }
+        }
+        itertools::equal(a.iter().cloned().unique(), seen)
+    }
+}
+
+quickcheck! {
+    equal_unique_by(1),
+    fn prop(a: Vec<i8>) -> bool {
+        let mut seen = Vec::new();
+        let mut keys = Vec::new();
+        for &x in &a {
+            if !keys.contains(&(x / 4)) {
+                keys.push(x / 4);
+                seen.push(x);
+            }
+        }
+        itertools::equal(a.iter().cloned().unique_by(|x| *x / 4), seen) &&
+            correct_size_hint(a.iter().unique_by(|x| **x / 4))
+    }
+}
+
 quickcheck! {
     fuzz_group_by_lazy_1(1),
     fn prop(it: Iter<u8>) -> bool {
@@ -735,3 +1124,715 @@ quickcheck! {
                                           Stride::from_slice(&b, s2 as isize)))
     }
 }
+
+quickcheck! {
+    sorted_by_key_is_stable(1),
+    fn prop(a: Vec<(u8, u8)>) -> bool {
+        let mut v = a.clone();
+        v.sort_by(|x, y| x.0.cmp(&y.0));
+        itertools::equal(a.into_iter().sorted_by_key(|x| x.0), v)
+    }
+}
+
+quickcheck! {
+    sorted_unstable_agrees_with_sorted(1),
+    fn prop(a: Vec<u8>) -> bool {
+        itertools::equal(a.iter().sorted_unstable(), a.iter().sorted()) &&
+            itertools::equal(a.iter().sorted_unstable_by(|x, y| y.cmp(x)),
+                             a.iter().sorted_by(|x, y| y.cmp(x))) &&
+            itertools::equal(a.iter().sorted_unstable_by_key(|&&x| x / 2).map(|&x| x / 2),
+                             a.iter().sorted_by_key(|&&x| x / 2).map(|&x| x / 2))
+    }
+}
+
+quickcheck! {
+    find_subsequence_agrees_with_windows(2),
+    fn prop(a: Vec<u8>, b: Vec<u8>) -> bool {
+        // keep the alphabet small so that matches actually occur
+        let a = a.into_iter().map(|x| x % 4).collect_vec();
+        let b = b.into_iter().map(|x| x % 4).collect_vec();
+        let needle = &b[..::std::cmp::min(b.len(), 3)];
+        let expected = if needle.is_empty() {
+            Some(0)
+        } else {
+            a.windows(needle.len()).position(|w| w == needle)
+        };
+        a.iter().cloned().find_subsequence(needle) == expected
+    }
+}
+
+quickcheck! {
+    k_smallest_agrees_with_sorted(2),
+    fn prop(a: Vec<u8>, k: u8) -> bool {
+        let k = k as usize % 16;
+        itertools::equal(a.iter().k_smallest(k), a.iter().sorted().take(k)) &&
+            itertools::equal(a.iter().k_largest(k),
+                             a.iter().sorted_by(|x, y| y.cmp(x)).take(k))
+    }
+}
+
+quickcheck! {
+    minmax_agrees_with_min_max(1),
+    fn prop(a: Vec<(u8, u8)>) -> bool {
+        use itertools::MinMaxResult;
+        let key = |x: &&(u8, u8)| x.0;
+        // `min_by_key` returns the first minimum, `max_by_key` the last maximum
+        let expected = match (a.iter().min_by_key(key), a.iter().max_by_key(key)) {
+            (None, None) => MinMaxResult::NoElements,
+            (Some(x), Some(_)) if a.len() == 1 => MinMaxResult::OneElement(x),
+            (Some(x), Some(y)) => MinMaxResult::MinMax(x, y),
+            _ => unreachable!(),
+        };
+        a.iter().minmax_by_key(key) == expected &&
+            a.iter().minmax_by(|x, y| x.0.cmp(&y.0)) == expected &&
+            a.iter().map(|x| x.0).minmax() == match expected {
+                MinMaxResult::NoElements => MinMaxResult::NoElements,
+                MinMaxResult::OneElement(x) => MinMaxResult::OneElement(x.0),
+                MinMaxResult::MinMax(x, y) => MinMaxResult::MinMax(x.0, y.0),
+            }
+    }
+}
+
+quickcheck! {
+    insert_sorted_agrees_with_sorted(2),
+    fn prop(a: Vec<u8>, x: u8) -> bool {
+        let mut b = a.clone();
+        b.push(x);
+        let it = a.into_iter().sorted().insert_sorted(x);
+        exact_size(it.clone()) && itertools::equal(it, b.into_iter().sorted())
+    }
+}
+
+quickcheck! {
+    position_max_min_agree_with_enumerate(1),
+    fn prop(a: Vec<(u8, u8)>) -> bool {
+        let key = |x: &(u8, u8)| x.0;
+        let max = a.iter().enumerate().max_by_key(|x| key(x.1)).map(|x| x.0);
+        let min = a.iter().enumerate().min_by_key(|x| key(x.1)).map(|x| x.0);
+        a.iter().map(|x| x.0).position_max() == max &&
+            a.iter().position_max_by_key(|x| key(x)) == max &&
+            a.iter().position_max_by(|x, y| key(x).cmp(&key(y))) == max &&
+            a.iter().map(|x| x.0).position_min() == min &&
+            a.iter().position_min_by_key(|x| key(x)) == min &&
+            a.iter().position_min_by(|x, y| key(x).cmp(&key(y))) == min
+    }
+}
+
+quickcheck! {
+    counts_agree_with_count_occurrences(1),
+    fn prop(a: Vec<u8>) -> bool {
+        let counts = a.iter().counts();
+        counts.values().sum::<usize>() == a.len() &&
+            counts.iter().all(|(&&x, &n)| a.iter().count_occurrences(&x) == n) &&
+            a.iter().counts_by(|&x| x % 3).values().sum::<usize>() == a.len()
+    }
+}
+
+quickcheck! {
+    extrema_agrees_with_position_min_max(1),
+    fn prop(a: Vec<u8>) -> bool {
+        match a.iter().extrema() {
+            None => a.is_empty(),
+            Some(e) => {
+                e.count == a.len() &&
+                    Some(e.min) == a.iter().min() &&
+                    Some(e.max) == a.iter().max() &&
+                    Some(e.min_index) == a.iter().position_min() &&
+                    Some(e.max_index) == a.iter().position(|x| x == e.max)
+            }
+        }
+    }
+}
+
+quickcheck! {
+    into_group_map_by_agrees_with_filter(1),
+    fn prop(a: Vec<u8>) -> bool {
+        let lookup = a.iter().cloned().into_group_map_by(|&x| x % 5);
+        lookup.values().map(|v| v.len()).sum::<usize>() == a.len() &&
+            lookup.iter().all(|(&k, v)| {
+                itertools::equal(v, a.iter().filter(|&&x| x % 5 == k))
+            })
+    }
+}
+
+quickcheck! {
+    group_by_hash_agrees_with_filter(1),
+    fn prop(a: Vec<u8>) -> bool {
+        let keys = a.iter().map(|&x| x % 5).unique().collect_vec();
+        let groups = a.iter().cloned().group_by_hash(|&x| x % 5);
+        correct_size_hint(a.iter().group_by_hash(|&&x| x % 5)) &&
+            itertools::equal(groups.map(|(k, _)| k), keys.iter().cloned()) &&
+            a.iter().cloned().group_by_hash(|&x| x % 5).all(|(k, v)| {
+                itertools::equal(v, a.iter().cloned().filter(|&x| x % 5 == k))
+            })
+    }
+}
+
+quickcheck! {
+    duplicates_report_agrees_with_counts(1),
+    fn prop(a: Vec<u8>) -> bool {
+        let mut counts = a.iter().counts();
+        counts.retain(|_, &mut n| n > 1);
+        a.iter().duplicates_report() == counts
+    }
+}
+
+quickcheck! {
+    grouping_map_agrees_with_into_group_map(1),
+    fn prop(a: Vec<u8>) -> bool {
+        use std::collections::HashMap;
+        let key = |&x: &u16| x % 7;
+        let a = a.into_iter().map(u16::from).collect_vec();
+        let groups = a.iter().cloned().into_group_map_by(key);
+        let expect = |f: &dyn Fn(&Vec<u16>) -> u16| -> HashMap<u16, u16> {
+            groups.iter().map(|(&k, v)| (k, f(v))).collect()
+        };
+        let grouping = || a.iter().cloned().into_grouping_map_by(key);
+        grouping().sum() == expect(&|v| v.iter().sum()) &&
+            grouping().max() == expect(&|v| *v.iter().max().unwrap()) &&
+            grouping().min() == expect(&|v| *v.iter().min().unwrap()) &&
+            grouping().fold(1u16, |acc, _, x| acc.wrapping_add(x)) ==
+                expect(&|v| v.iter().fold(1, |acc: u16, &x| acc.wrapping_add(x))) &&
+            grouping().collect::<Vec<_>>() == groups &&
+            grouping().minmax().into_iter().all(|(k, mm)| {
+                mm == groups[&k].iter().cloned().minmax()
+            })
+    }
+}
+
+quickcheck! {
+    merge3_agrees_with_merge(3),
+    fn prop(a: Vec<u8>, b: Vec<u8>, c: Vec<u8>) -> bool {
+        use itertools::free::{merge, merge3};
+        // tag elements by source to check the tie-breaking order
+        let a = a.into_iter().sorted().map(|x| (x, 0)).collect_vec();
+        let b = b.into_iter().sorted().map(|x| (x, 1)).collect_vec();
+        let c = c.into_iter().sorted().map(|x| (x, 2)).collect_vec();
+        let it = merge3(a.clone(), b.clone(), c.clone());
+        correct_size_hint(it.clone()) &&
+            itertools::equal(it, merge(merge(a, b), c))
+    }
+}
+
+quickcheck! {
+    chain3_agrees_with_chain(3),
+    fn prop(a: Iter<u8>, b: Iter<u8>, c: Iter<u8>) -> bool {
+        use itertools::free::chain3;
+        let it = chain3(a.clone(), b.clone(), c.clone());
+        correct_size_hint(it.clone()) &&
+            itertools::equal(it, a.chain(b).chain(c))
+    }
+}
+
+quickcheck! {
+    partition_map_agrees_with_filter(1),
+    fn prop(a: Vec<u8>) -> bool {
+        use itertools::Either;
+        let (evens, odds): (Vec<u8>, Vec<char>) = a.iter().partition_map(|&x| {
+            if x % 2 == 0 { Either::Left(x) } else { Either::Right(x as char) }
+        });
+        itertools::equal(evens, a.iter().cloned().filter(|x| x % 2 == 0)) &&
+            itertools::equal(odds, a.iter().filter(|&x| x % 2 != 0).map(|&x| x as char))
+    }
+}
+
+#[cfg(feature = "bytes")]
+quickcheck! {
+    chunk_windows_bytes_agree_with_slice(2),
+    fn prop(a: Vec<u8>, n: u8) -> bool {
+        use itertools::ByteItertools;
+        let n = n as usize % 8 + 1;
+        let chunks = a.iter().cloned().chunk_bytes(n);
+        let windows = a.iter().cloned().windows_bytes(n);
+        exact_size(chunks.clone()) && exact_size(windows.clone()) &&
+            itertools::equal(chunks, a.chunks(n).map(|c| c.to_vec())) &&
+            itertools::equal(windows, a.windows(n).map(|w| w.to_vec()))
+    }
+}
+
+quickcheck! {
+    interleave_weighted_one_one_agrees_with_interleave(2),
+    fn prop(a: Iter<i16>, b: Iter<i16>) -> bool {
+        let it = a.clone().interleave_weighted(b.clone(), 1, 1);
+        correct_size_hint(it.clone()) && itertools::equal(it, a.interleave(b))
+    }
+}
+
+quickcheck! {
+    interleave_weighted_keeps_all_elements(4),
+    fn prop(a: Vec<u8>, b: Vec<u8>, wa: u8, wb: u8) -> bool {
+        let wa = wa as usize % 5 + 1;
+        let wb = wb as usize % 5 + 1;
+        let (left, right): (Vec<_>, Vec<_>) = a.iter().map(|&x| (0, x))
+            .interleave_weighted(b.iter().map(|&x| (1, x)), wa, wb)
+            .partition(|&(side, _)| side == 0);
+        itertools::equal(left.into_iter().map(|x| x.1), a) &&
+            itertools::equal(right.into_iter().map(|x| x.1), b)
+    }
+}
+
+quickcheck! {
+    all_equal_value_agrees_with_all_equal(1),
+    fn prop(a: Vec<u8>) -> bool {
+        let a = a.into_iter().map(|x| x % 2).collect_vec();
+        match a.iter().all_equal_value() {
+            Ok(&x) => a.iter().all_equal() && a[0] == x,
+            Err(None) => a.is_empty(),
+            Err(Some((&x, &y))) => {
+                !a.iter().all_equal() && x == a[0] &&
+                    Some(&y) == a.iter().find(|&&z| z != x)
+            }
+        }
+    }
+}
+
+quickcheck! {
+    try_sorted_agrees_with_sorted(1),
+    fn prop(a: Vec<u8>) -> bool {
+        let results = a.iter().map(|&x| if x == 200 { Err(x) } else { Ok(x) });
+        match results.clone().try_sorted() {
+            Ok(it) => !a.contains(&200) && itertools::equal(it, a.iter().cloned().sorted()),
+            Err(x) => x == 200 && a.contains(&200),
+        }
+    }
+}
+
+quickcheck! {
+    chunks_overlapping_agrees_with_slice(3),
+    fn prop(a: Vec<u8>, size: u8, overlap: u8) -> bool {
+        let size = size as usize % 6 + 1;
+        let overlap = overlap as usize % size;
+        let step = size - overlap;
+        // chunk starts are 0, step, 2 * step, ... as long as they bring new elements
+        let mut expected = Vec::new();
+        let mut start = 0;
+        while start < a.len() && (start == 0 || start + overlap < a.len()) {
+            expected.push(a[start..::std::cmp::min(start + size, a.len())].to_vec());
+            start += step;
+        }
+        let it = a.iter().cloned().chunks_overlapping(size, overlap);
+        correct_size_hint(it.clone()) && itertools::equal(it, expected)
+    }
+}
+
+quickcheck! {
+    zip_longest_map_agrees_with_zip_longest(2),
+    fn prop(a: Vec<u8>, b: Vec<u16>) -> bool {
+        use itertools::EitherOrBoth::{Both, Left, Right};
+        let mapped = || a.iter().zip_longest_map(&b, |&x, &y| (x as u32) * (y as u32),
+                                                 |&x| x as u32 + 1000,
+                                                 |&y| y as u32 + 100000);
+        let expected = a.iter().zip_longest(&b).map(|e| match e {
+            Both(&x, &y) => (x as u32) * (y as u32),
+            Left(&x) => x as u32 + 1000,
+            Right(&y) => y as u32 + 100000,
+        }).collect_vec();
+        exact_size(mapped()) &&
+            itertools::equal(mapped(), expected.iter().cloned()) &&
+            itertools::equal(mapped().rev(), expected.into_iter().rev())
+    }
+}
+
+quickcheck! {
+    any_window_agrees_with_slice_windows(2),
+    fn prop(a: Vec<u8>, n: u8) -> bool {
+        let n = n as usize % 5 + 1;
+        let pred = |w: &[u8]| w.iter().fold(0u32, |s, &x| s + x as u32) % 7 == 0;
+        a.iter().cloned().any_window(n, &pred) == a.windows(n).any(&pred) &&
+            a.iter().cloned().all_windows(n, &pred) == a.windows(n).all(&pred)
+    }
+}
+
+quickcheck! {
+    ref_tuple_windows_agrees_with_slice_windows(1),
+    fn prop(a: Vec<u8>) -> bool {
+        correct_size_hint(a.iter().ref_tuple_windows::<_, (_, _, _)>()) &&
+            itertools::equal(a.iter().ref_tuple_windows::<_, (_, _, _)>(),
+                             a.windows(3).map(|w| (&w[0], &w[1], &w[2])))
+    }
+}
+
+#[cfg(feature = "arrays")]
+quickcheck! {
+    array_chunks_agrees_with_slice_chunks(1),
+    fn prop(a: Iter<u8>) -> bool {
+        let v = a.clone().collect_vec();
+        let mut chunks = a.clone().array_chunks::<3>();
+        exact_size(a.array_chunks::<3>()) &&
+            itertools::equal(&mut chunks, v.chunks_exact(3).map(|c| [c[0], c[1], c[2]])) &&
+            itertools::equal(chunks.into_remainder(), v.chunks_exact(3).remainder().iter().cloned())
+    }
+}
+
+quickcheck! {
+    merge_by_key_is_stable_sort(2),
+    fn prop(a: Vec<(u8, u8)>, b: Vec<(u8, u8)>) -> bool {
+        // few distinct keys, so that there are many equal keys
+        let mut sa = a.iter().map(|&(k, v)| (k % 8, v)).collect_vec();
+        let mut sb = b.iter().map(|&(k, v)| (k % 8, v)).collect_vec();
+        sa.sort_by_key(|x| x.0);
+        sb.sort_by_key(|x| x.0);
+        let mut merged = sa.iter().chain(&sb).cloned().collect_vec();
+        merged.sort_by_key(|x| x.0);
+        itertools::equal(sa.into_iter().merge_by_key(sb, |x| x.0), merged)
+    }
+}
+
+#[cfg(feature = "arrays")]
+quickcheck! {
+    array_combinations_agrees_with_combinations_n(1),
+    fn prop(a: Vec<u8>) -> bool {
+        let a = &a[..::std::cmp::min(a.len(), 12)];
+        itertools::equal(a.iter().array_combinations::<3>().map(|c| c.to_vec()),
+                         a.iter().combinations_n(3))
+    }
+}
+
+quickcheck! {
+    update_agrees_with_map(1),
+    fn prop(a: Iter<u8>) -> bool {
+        exact_size(a.clone().update(|x| *x = x.wrapping_mul(3))) &&
+            itertools::equal(a.clone().update(|x| *x = x.wrapping_mul(3)).rev(),
+                             a.map(|x| x.wrapping_mul(3)).rev())
+    }
+}
+
+quickcheck! {
+    indices_agrees_with_nested_loops(3),
+    fn prop(a: u8, b: u8, c: u8) -> bool {
+        let (a, b, c) = (a as usize % 5, b as usize % 5, c as usize % 5);
+        let nested = (0..a).flat_map(|i| (0..b).flat_map(move |j| {
+            (0..c).map(move |k| vec![i, j, k])
+        }));
+        exact_size(itertools::indices(&[a, b, c])) &&
+            itertools::equal(itertools::indices(&[a, b, c]), nested)
+    }
+}
+
+quickcheck! {
+    partition_stable_agrees_with_filter(1),
+    fn prop(a: Vec<(u8, u8)>) -> bool {
+        let (left, right) = a.iter().cloned().partition_stable(|x| x.0 % 3 == 0);
+        itertools::equal(left, a.iter().cloned().filter(|x| x.0 % 3 == 0)) &&
+            itertools::equal(right, a.iter().cloned().filter(|x| x.0 % 3 != 0))
+    }
+}
+
+quickcheck! {
+    size_delimited(1),
+    fn prop(it: Iter<i16>) -> bool {
+        correct_size_hint(it.delimited(-1, 0, 1))
+    }
+}
+
+quickcheck! {
+    equal_delimited(1),
+    fn prop(a: Vec<i16>) -> bool {
+        let mut expected = vec![-1];
+        for (i, &x) in a.iter().enumerate() {
+            if i != 0 {
+                expected.push(0);
+            }
+            expected.push(x);
+        }
+        expected.push(1);
+        itertools::equal(a.iter().cloned().delimited(-1, 0, 1), expected)
+    }
+}
+
+quickcheck! {
+    size_retry_ok(2),
+    fn prop(it: Iter<i16>, max: u8) -> bool {
+        let max = max as usize % 4 + 1;
+        correct_size_hint(it.map(|x| if x % 3 == 0 { Err(x) } else { Ok(x) })
+                            .retry_ok(max, |&e| e % 2 == 0))
+    }
+}
+
+quickcheck! {
+    equal_step_back(3),
+    fn prop(a: Vec<u8>, step: u8, fronts: u8) -> bool {
+        let step = step as usize % 5 + 1;
+        let expected = a.iter().rev().step(step).collect::<Vec<_>>();
+        let mut it = a.iter().step_back(step);
+        if !exact_size(it.clone()) {
+            return false;
+        }
+        // interleave taking from the back with taking from the front
+        let mut result = Vec::new();
+        let mut tail = Vec::new();
+        for i in 0.. {
+            let elt = if i % (fronts as usize % 3 + 1) == 0 {
+                it.next_back().map(|x| tail.push(x))
+            } else {
+                it.next().map(|x| result.push(x))
+            };
+            if elt.is_none() {
+                break;
+            }
+        }
+        result.extend(tail.into_iter().rev());
+        result == expected
+    }
+}
+
+quickcheck! {
+    size_key_joins(2),
+    fn prop(a: Vec<(u8, i8)>, b: Vec<(u8, i16)>) -> bool {
+        let mut a = a;
+        let mut b = b;
+        a.sort_by_key(|x| x.0);
+        a.dedup_by_key(|x| x.0);
+        b.sort_by_key(|x| x.0);
+        b.dedup_by_key(|x| x.0);
+        let inner = itertools::free::inner_join(a.clone(), b.clone()).collect::<Vec<_>>();
+        let expected = a.iter()
+            .filter_map(|&(k, v)| b.iter().find(|x| x.0 == k).map(|x| (k, v, x.1)))
+            .collect::<Vec<_>>();
+        let left = itertools::free::left_join(a.clone(), b.clone());
+        inner == expected &&
+            left.size_hint() == (a.len(), Some(a.len())) && correct_size_hint(left) &&
+            correct_size_hint(itertools::free::inner_join(a.clone(), b.clone())) &&
+            correct_size_hint(itertools::free::outer_join(a, b))
+    }
+}
+
+/// Take elements from both ends of `it`, choosing the end by the bits of
+/// `pattern`, and check that they match the elements of the forward order.
+fn double_ended_agrees<I>(it: I, pattern: u8) -> bool
+    where I: DoubleEndedIterator + Clone,
+          I::Item: PartialEq
+{
+    let expected = it.clone().collect::<Vec<_>>();
+    let mut it = it;
+    let mut front = Vec::new();
+    let mut back = Vec::new();
+    for i in 0.. {
+        let elt = if pattern & (1 << (i % 8)) != 0 {
+            it.next_back().map(|x| back.push(x))
+        } else {
+            it.next().map(|x| front.push(x))
+        };
+        if elt.is_none() {
+            break;
+        }
+    }
+    front.extend(back.into_iter().rev());
+    it.next().is_none() && front == expected
+}
+
+quickcheck! {
+    double_ended_interleave(3),
+    fn prop(a: Vec<i8>, b: Vec<i8>, pattern: u8) -> bool {
+        double_ended_agrees(a.iter().interleave(&b), pattern) &&
+            correct_size_hint(a.iter().interleave(&b))
+    }
+}
+
+quickcheck! {
+    double_ended_product(3),
+    fn prop(a: Vec<u8>, b: Vec<u8>, pattern: u8) -> bool {
+        let a = &a[..a.len() % 6];
+        let b = &b[..b.len() % 6];
+        double_ended_agrees(a.iter().cartesian_product(b.iter()), pattern) &&
+            correct_size_hint(a.iter().cartesian_product(b.iter()).rev())
+    }
+}
+
+quickcheck! {
+    double_ended_merge(3),
+    fn prop(a: Vec<(u8, bool)>, b: Vec<(u8, bool)>, pattern: u8) -> bool {
+        let mut a = a.into_iter().map(|(k, _)| (k % 8, 0)).collect::<Vec<_>>();
+        let mut b = b.into_iter().map(|(k, _)| (k % 8, 1)).collect::<Vec<_>>();
+        a.sort();
+        b.sort();
+        double_ended_agrees(a.iter().merge(&b), pattern) &&
+            double_ended_agrees(a.iter().merge_by(&b, |x, y| x.0 <= y.0), pattern) &&
+            double_ended_agrees(a.iter().merge_by_key(&b, |x| x.0), pattern) &&
+            correct_size_hint(a.iter().merge(&b).rev())
+    }
+}
+
+quickcheck! {
+    exact_size_ref_tuple_windows(1),
+    fn prop(a: Vec<u8>) -> bool {
+        exact_size(a.iter().ref_tuple_windows::<_, (_, _, _)>())
+    }
+}
+
+/// Check that `fold` and `count` of `it` see the same elements as repeated
+/// calls to `next`, after first stepping `it` forward `skip` times.
+fn fold_agrees<I>(it: I, skip: u8) -> bool
+    where I: Iterator + Clone,
+          I::Item: PartialEq
+{
+    let mut it = it;
+    for _ in 0..skip % 4 {
+        it.next();
+    }
+    let mut expected = Vec::new();
+    let mut by_next = it.clone();
+    while let Some(elt) = by_next.next() {
+        expected.push(elt);
+    }
+    let folded = it.clone().fold(Vec::new(), |mut v, elt| { v.push(elt); v });
+    folded == expected && it.count() == expected.len()
+}
+
+quickcheck! {
+    fold_interleave(3),
+    fn prop(a: Iter<i16>, b: Iter<i16>, skip: u8) -> bool {
+        fold_agrees(a.interleave(b), skip)
+    }
+}
+
+quickcheck! {
+    fold_merge(3),
+    fn prop(a: Vec<u8>, b: Vec<u8>, skip: u8) -> bool {
+        let mut a = a;
+        let mut b = b;
+        a.sort();
+        b.sort();
+        let mut back = a.iter().merge(&b);
+        back.next_back();
+        fold_agrees(a.iter().merge(&b), skip) &&
+            fold_agrees(back, skip) &&
+            fold_agrees(a.iter().merge_by(&b, |x, y| x >= y), skip) &&
+            fold_agrees(a.iter().merge_by_key(&b, |x| *x / 4), skip)
+    }
+}
+
+quickcheck! {
+    fold_kmerge(2),
+    fn prop(a: Vec<Vec<u8>>, skip: u8) -> bool {
+        let mut a = a;
+        for v in &mut a {
+            v.sort();
+        }
+        fold_agrees(a.iter().kmerge(), skip) &&
+            fold_agrees(a.iter().kmerge_by(|x, y| x > y), skip)
+    }
+}
+
+quickcheck! {
+    fold_coalesce(2),
+    fn prop(a: Iter<u8>, skip: u8) -> bool {
+        let a = a.map(|x| x % 4);
+        fold_agrees(a.clone().dedup(), skip) &&
+            fold_agrees(a.clone().dedup_by_key(|x| *x / 2), skip) &&
+            fold_agrees(a.coalesce(|x, y| if x < y { Ok(y) } else { Err((x, y)) }), skip)
+    }
+}
+
+quickcheck! {
+    fold_with_position(2),
+    fn prop(a: Iter<i16>, skip: u8) -> bool {
+        fold_agrees(a.with_position(), skip)
+    }
+}
+
+quickcheck! {
+    fold_intersperse(2),
+    fn prop(a: Iter<i16>, skip: u8) -> bool {
+        let mut n = 0;
+        fold_agrees(a.clone().intersperse(-1), skip) &&
+            fold_agrees(a.intersperse_with(move || { n += 1; n }), skip)
+    }
+}
+
+quickcheck! {
+    sorted_set_ops(2),
+    fn prop(a: Vec<u8>, b: Vec<u8>) -> bool {
+        use std::cmp::{max, min};
+        let mut a = a.into_iter().map(|x| x % 16).collect::<Vec<_>>();
+        let mut b = b.into_iter().map(|x| x % 16).collect::<Vec<_>>();
+        a.sort();
+        b.sort();
+        // count the occurrences of each value on both sides
+        let counts = (0..16).map(|x| {
+            (x, a.iter().filter(|&&y| y == x).count(), b.iter().filter(|&&y| y == x).count())
+        }).collect::<Vec<_>>();
+        let expected = |f: &Fn(usize, usize) -> usize| {
+            counts.iter().flat_map(|&(x, m, n)| std::iter::repeat(x).take(f(m, n)))
+                .collect::<Vec<_>>()
+        };
+        let union = a.iter().cloned().union(b.iter().cloned());
+        let intersection = a.iter().cloned().intersection(b.iter().cloned());
+        let difference = a.iter().cloned().difference(b.iter().cloned());
+        let symmetric = a.iter().cloned().symmetric_difference(b.iter().cloned());
+        correct_size_hint(union.clone()) && correct_size_hint(intersection.clone()) &&
+            correct_size_hint(difference.clone()) && correct_size_hint(symmetric.clone()) &&
+            union.collect::<Vec<_>>() == expected(&|m, n| max(m, n)) &&
+            intersection.collect::<Vec<_>>() == expected(&|m, n| min(m, n)) &&
+            difference.collect::<Vec<_>>() == expected(&|m, n| m.saturating_sub(n)) &&
+            symmetric.collect::<Vec<_>>() ==
+                expected(&|m, n| max(m, n) - min(m, n))
+    }
+}
+
+quickcheck! {
+    run_length_round_trip(1),
+    fn prop(a: Iter<u8>) -> bool {
+        let a = a.map(|x| x % 3);
+        let runs = a.clone().run_length_encode().collect::<Vec<_>>();
+        let dedup = a.clone().dedup().collect::<Vec<_>>();
+        correct_size_hint(a.clone().run_length_encode()) &&
+            correct_size_hint(runs.iter().cloned().run_length_decode()) &&
+            runs.iter().map(|run| run.0).collect::<Vec<_>>() == dedup &&
+            runs.iter().all(|run| run.1 > 0) &&
+            itertools::equal(runs.into_iter().run_length_decode(), a)
+    }
+}
+
+quickcheck! {
+    tail_agrees_with_collect(2),
+    fn prop(a: Vec<u8>, n: u8) -> bool {
+        let n = n as usize % 8;
+        let expected = a[a.len().saturating_sub(n)..].to_vec();
+        let mut one_pass = a.iter().cloned().filter(|_| true);
+        itertools::equal(a.iter().cloned().tail(n), expected.iter().cloned()) &&
+            itertools::equal(one_pass.by_ref().tail(n), expected) &&
+            one_pass.next().is_none()
+    }
+}
+
+/// Check that once `it` has returned `None`, it keeps returning `None`,
+/// even though the `Iter` sources it was built from are not fused.
+fn stays_done<I: Iterator>(mut it: I) -> bool {
+    while let Some(_) = it.next() {}
+    (0..3).all(|_| it.next().is_none())
+}
+
+quickcheck! {
+    fused_adaptors_stay_done(2),
+    fn prop(a: Iter<i16>, b: Iter<i16>) -> bool {
+        use itertools::WhileStep;
+        stays_done(a.clone().interleave(b.clone())) &&
+            stays_done(a.clone().interleave_shortest(b.clone())) &&
+            stays_done(a.clone().interleave_weighted(b.clone(), 2, 1)) &&
+            stays_done(a.clone().intersperse(0)) &&
+            stays_done(a.clone().intersperse_with(|| 0)) &&
+            stays_done(a.clone().intersperse_every(2, 0)) &&
+            stays_done(a.clone().delimited(0, 1, 2)) &&
+            stays_done(a.clone().zip_longest(b.clone())) &&
+            stays_done(a.clone().pad_using(5, |_| 0)) &&
+            stays_done(a.clone().step(2)) &&
+            stays_done(a.clone().with_position()) &&
+            stays_done(a.clone().run_length_encode()) &&
+            stays_done(a.clone().buffered(2)) &&
+            stays_done(a.clone().group_by(|x| *x / 4)) &&
+            stays_done(a.clone().scan1(|&x, y| Some(x.wrapping_add(y)))) &&
+            stays_done(a.clone().map(|x| if x != 7 { Some(x) } else { None }).while_some()) &&
+            stays_done(a.clone().filter_map_while(|x| if x % 5 == 0 {
+                WhileStep::Skip
+            } else {
+                WhileStep::Yield(x)
+            })) &&
+            stays_done(a.clone().merge_join_by(b.clone(), |x, y| x.cmp(y))) &&
+            stays_done(a.clone().union(b.clone())) &&
+            stays_done(a.clone().symmetric_difference(b.clone())) &&
+            stays_done(a.clone().map(|x| if x % 3 == 0 { Err(x) } else { Ok(x) })
+                       .retry_ok(2, |_| true)) &&
+            stays_done(itertools::free::left_join(a.map(|x| (x, ())), b.map(|x| (x, ()))))
+    }
+}
@@ -40,6 +40,20 @@ fn product3() {
     }
 }
 
+#[test]
+fn multizip3() {
+    let mut zip = it::multizip((0..3, 0..2, 0..2i8));
+    assert_eq!(zip.len(), 2);
+    assert_eq!(zip.next_back(), Some((1, 1, 1)));
+    assert_eq!(zip.next(), Some((0, 0, 0)));
+    assert_eq!(zip.next(), None);
+    assert_eq!(zip.next_back(), None);
+
+    let xs = [1, 2, 3, 4];
+    let rev = it::multizip((&xs, xs.iter().skip(1), (0..10).rev())).rev();
+    it::assert_equal(rev, vec![(&3, &4, 7), (&2, &3, 8), (&1, &2, 9)]);
+}
+
 #[test]
 fn product_temporary() {
     for (_x, _y, _z) in iproduct!(
@@ -141,6 +155,211 @@ fn foreach() {
     assert!(sum == 6);
 }
 
+#[test]
+fn zip_eq_checked() {
+    let xs = [1, 2, 3];
+    it::assert_equal(xs.iter().zip_eq_checked(xs.iter().rev()).unwrap(),
+                     vec![(&1, &3), (&2, &2), (&3, &1)]);
+    assert_eq!((0..0).zip_eq_checked(0..0).unwrap().next(), None);
+
+    let mut left = xs.iter();
+    let err = left.by_ref().zip_eq_checked(0..5).unwrap_err();
+    assert_eq!(err, it::LengthMismatch { left: 3, right: 5 });
+    assert_eq!(left.len(), 3);
+}
+
+#[test]
+fn fold_by_boundaries() {
+    let sums = (0..10).fold_by_boundaries(vec![0, 3, 3, 5], || 0, |a, b| a + b);
+    it::assert_equal(sums, vec![0, 3, 0, 7, 35]);
+
+    // no elements left for a last bucket
+    let counts = (0..4).fold_by_boundaries(vec![2, 10, 20], || 0, |n, _| n + 1);
+    it::assert_equal(counts, vec![2, 2, 0]);
+
+    // unbounded elements, bucketed per 100
+    let mut buckets = (0..).fold_by_boundaries((1..).map(|i| i * 100), Vec::new,
+                                               |mut v, x| { v.push(x); v });
+    assert_eq!(buckets.nth(2).map(|v| (v[0], v.len())), Some((200, 100)));
+
+    let buckets = (0..0).fold_by_boundaries(0..3, || 1, |a, b| a * b);
+    assert_eq!(buckets.size_hint(), (3, Some(4)));
+    it::assert_equal(buckets, vec![1, 1, 1]);
+}
+
+#[test]
+fn concat() {
+    use std::collections::BTreeSet;
+    let sets = vec![vec![3, 1].into_iter().collect::<BTreeSet<_>>(),
+                    vec![2, 3].into_iter().collect()];
+    it::assert_equal(&it::free::concat(sets), &[1, 2, 3]);
+    assert_eq!(Some(vec!["one"]).into_iter().concat(), ["one"]);
+    assert_eq!(it::free::concat(Vec::<Vec<u8>>::new()), []);
+}
+
+#[test]
+fn map_into() {
+    let mut it = vec![1u16, 2, 3].into_iter().map_into::<i64>();
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.next_back(), Some(3i64));
+    let rest = it.clone().collect_vec();
+    assert_eq!(rest, vec![1i64, 2]);
+    it::assert_equal(it, rest);
+}
+
+#[test]
+fn drain_into() {
+    use std::collections::BTreeSet;
+    let mut set = BTreeSet::new();
+    let mut iter = vec![3, 1, 3, 2].into_iter();
+    assert_eq!(iter.drain_into(&mut set), 4);
+    assert_eq!(iter.next(), None);
+    it::assert_equal(&set, &[1, 2, 3]);
+
+    let mut s = String::from("ab");
+    assert_eq!("".chars().drain_into(&mut s), 0);
+    assert_eq!(s, "ab");
+}
+
+#[test]
+fn apply() {
+    fn dedup<I>(iter: I) -> it::Dedup<I>
+        where I: Iterator, I::Item: PartialEq
+    {
+        iter.dedup()
+    }
+    let xs = [1, 1, 2, 3, 3];
+    it::assert_equal(xs.iter().cloned().apply(dedup), vec![1, 2, 3]);
+    assert_eq!(xs.iter().apply(|it| it.count()), 5);
+}
+
+#[test]
+fn next_tuple() {
+    let mut iter = 0..7;
+    assert_eq!(iter.next_tuple(), Some((0,)));
+    assert_eq!(iter.next_tuple(), Some((1, 2, 3)));
+    assert_eq!(iter.next_tuple::<(_, _, _, _)>(), None);
+    assert_eq!(iter.next(), None);
+
+    let t: Option<(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8)> = (0..12).next_tuple();
+    assert_eq!(t.map(|t| t.11), Some(11));
+}
+
+#[test]
+fn ref_tuple_windows() {
+    let xs = ["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()];
+    let mut windows = xs.iter().ref_tuple_windows::<_, (_, _, _)>();
+    assert_eq!(windows.size_hint(), (2, Some(2)));
+    assert_eq!(windows.next(), Some((&xs[0], &xs[1], &xs[2])));
+    assert_eq!(windows.size_hint(), (1, Some(1)));
+    assert_eq!(windows.next(), Some((&xs[1], &xs[2], &xs[3])));
+    assert_eq!(windows.next(), None);
+
+    let mut windows = xs[..2].iter().ref_tuple_windows::<_, (_, _, _)>();
+    assert_eq!(windows.size_hint(), (0, Some(0)));
+    assert_eq!(windows.next(), None);
+    it::assert_equal(xs.iter().ref_tuple_windows::<_, (_,)>(), xs.iter().map(|x| (x,)));
+}
+
+#[test]
+fn collect_tuple() {
+    assert_eq!((0..3).collect_tuple(), Some((0, 1, 2)));
+    assert_eq!((0..2).collect_tuple::<(_, _, _)>(), None);
+    assert_eq!((0..4).collect_tuple::<(_, _, _)>(), None);
+    assert_eq!(Some(5).into_iter().collect_tuple(), Some((5,)));
+}
+
+#[cfg(feature = "arrays")]
+#[test]
+fn next_array() {
+    let mut iter = 0..7;
+    assert_eq!(iter.next_array(), Some([0]));
+    assert_eq!(iter.next_array(), Some([1, 2, 3]));
+    assert_eq!(iter.next_array::<4>(), None);
+    assert_eq!(iter.next(), None);
+
+    let empty: Option<[String; 0]> = (0..0).map(|x: u8| x.to_string()).next_array();
+    assert_eq!(empty, Some([]));
+}
+
+#[cfg(feature = "arrays")]
+#[test]
+fn array_chunks() {
+    let mut chunks = (0..8).array_chunks::<3>();
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks.next(), Some([0, 1, 2]));
+    assert_eq!(chunks.next(), Some([3, 4, 5]));
+    assert_eq!(chunks.next(), None);
+    assert_eq!(chunks.next(), None);
+    it::assert_equal(chunks.into_remainder(), vec![6, 7]);
+
+    let mut chunks = (0..4).map(|x| x.to_string()).array_chunks::<2>();
+    assert_eq!(chunks.by_ref().count(), 2);
+    assert_eq!(chunks.into_remainder().count(), 0);
+}
+
+#[cfg(feature = "arrays")]
+#[test]
+#[should_panic]
+fn array_chunks_zero() {
+    (0..8).array_chunks::<0>();
+}
+
+#[cfg(feature = "arrays")]
+#[test]
+fn collect_array() {
+    let words = "not a header".split(' ').collect_array();
+    assert_eq!(words, Some(["not", "a", "header"]));
+    assert_eq!((0..2).collect_array::<3>(), None);
+    assert_eq!((0..4).collect_array::<3>(), None);
+}
+
+#[test]
+fn any_window() {
+    let mut iter = 0..10;
+    assert!(iter.any_window(3, |w| w == [2, 3, 4]));
+    assert_eq!(iter.next(), Some(5));
+    assert!(!(0..2).any_window(3, |_| true));
+    assert!((0..2).all_windows(3, |_| false));
+    assert!((0..10).all_windows(4, |w| w[3] - w[0] == 3));
+}
+
+#[test]
+#[should_panic]
+fn any_window_zero() {
+    (0..10).any_window(0, |_| true);
+}
+
+#[test]
+fn windows() {
+    for n in 1..6 {
+        let mut windows = (0..12).windows(n);
+        let expected = (0..12).collect::<Vec<_>>();
+        for w in expected.windows(n) {
+            assert_eq!(windows.next_window(), Some(w));
+        }
+        assert_eq!(windows.next_window(), None);
+        assert_eq!(windows.next_window(), None);
+    }
+    assert_eq!((0..2).windows(3).next_window(), None);
+}
+
+#[test]
+fn try_collect() {
+    let xs: Vec<Result<u8, char>> = vec![Ok(1), Ok(2)];
+    let v: Result<Vec<_>, _> = xs.into_iter().try_collect();
+    assert_eq!(v, Ok(vec![1, 2]));
+
+    let mut calls = 0;
+    let xs = vec![Ok(1), Err('a'), Ok(2), Err('b')];
+    let v: Result<String, _> = xs.into_iter()
+                                 .inspect(|_| calls += 1)
+                                 .map(|r| r.map(|x: u8| (b'0' + x) as char))
+                                 .try_collect();
+    assert_eq!(v, Err('a'));
+    assert_eq!(calls, 2);
+}
+
 #[test]
 fn dropn() {
     let xs = [1, 2, 3];
@@ -163,6 +382,451 @@ fn dropping() {
     assert!(it.next().is_none());
 }
 
+#[test]
+fn dropping_back() {
+    let xs = [1, 2, 3];
+    let mut it = xs.iter().dropping_back(2);
+    assert_eq!(it.next(), Some(&1));
+    assert!(it.next().is_none());
+    it::assert_equal(xs.iter().dropping_back(0), &xs);
+    assert_eq!(xs.iter().dropping_back(5).len(), 0);
+    it::assert_equal((0..10).dropping(3).dropping_back(3), 3..7);
+}
+
+#[test]
+fn buffered() {
+    let mut pulled = 0;
+    {
+        let mut it = (0..4).inspect(|_| pulled += 1).buffered(2);
+        assert_eq!(it.capacity(), 2);
+        assert_eq!(it.size_hint(), (4, Some(4)));
+        assert_eq!(it.next(), Some(0));
+        assert_eq!(it.fill_level(), 1);
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        it::assert_equal(it, 1..4);
+    }
+    assert_eq!(pulled, 4);
+}
+
+#[test]
+fn with_position() {
+    use it::Position;
+    assert_eq!((0..0).with_position().next(), None);
+    it::assert_equal((0..2).with_position(), vec![Position::First(0), Position::Last(1)]);
+    it::assert_equal((0..3).with_position().map(Position::into_inner), 0..3);
+
+    let mut it = (0..3).with_position();
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    it.next();
+    assert_eq!(it.len(), 2);
+}
+
+#[test]
+fn zip_offset() {
+    it::assert_equal((0..5).zip_offset(0), (0..5).zip(0..5));
+    it::assert_equal((0..5).zip_offset(3), vec![(0, 3), (1, 4)]);
+    assert_eq!((0..5).zip_offset(5).next(), None);
+    assert_eq!((0..5).zip_offset(7).next(), None);
+
+    let it = (0..5).zip_offset(2);
+    assert_eq!(it.len(), 3);
+}
+
+#[test]
+fn positions() {
+    let data = [3, 0, 3, 3, 1];
+    it::assert_equal(data.iter().positions(|&x| x == 3), vec![0, 2, 3]);
+    assert_eq!((0..0).positions(|_| true).next(), None);
+
+    // mixing both ends
+    let mut it = data.iter().positions(|&x| x == 3);
+    assert_eq!(it.next_back(), Some(3));
+    assert_eq!(it.next(), Some(0));
+    assert_eq!(it.next_back(), Some(2));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+}
+
+#[test]
+fn collapse_runs() {
+    it::assert_equal("".chars().collapse_runs(' '), "".chars());
+    it::assert_equal("   ".chars().collapse_runs(' '), " ".chars());
+    it::assert_equal(" aa  b ".chars().collapse_runs(' '), " aa b ".chars());
+    it::assert_equal(vec![1, 2, 2, 1, 1].into_iter().collapse_runs(2), vec![1, 2, 1, 1]);
+}
+
+#[test]
+fn collapse_runs_by() {
+    let xs = [1, 3, 5, 2, 4, 7, 9];
+    it::assert_equal(xs.iter().collapse_runs_by(|x| *x % 2 == 1), &[1, 2, 4, 7]);
+    it::assert_equal(xs.iter().collapse_runs_by(|_| false), &xs);
+}
+
+#[test]
+fn round_robin() {
+    let empty: Vec<Vec<i32>> = vec![];
+    assert_eq!(empty.into_iter().round_robin().next(), None);
+    it::assert_equal(it::free::round_robin(vec![0..2, 5..5, 2..4, 9..10]), vec![0, 2, 9, 1, 3]);
+
+    let xs = [vec![0, 1], vec![2]];
+    let it = it::free::round_robin(&xs);
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    it::assert_equal(it, &[0, 2, 1]);
+}
+
+#[test]
+fn with_chunk_index() {
+    let data = vec![1, 1, 2, -2, 6, 0, 3, 1];
+    let mut sums = vec![0; 3];
+    for (i, x) in data.iter().with_chunk_index(3) {
+        sums[i] += *x;
+    }
+    assert_eq!(sums, vec![4, 4, 4]);
+
+    it::assert_equal((0..4).with_chunk_index(1).map(|(i, _)| i), 0..4);
+    assert_eq!((0..5).with_chunk_index(2).len(), 5);
+}
+
+#[test]
+fn fold_chunks_exact() {
+    let mut it = (0..7).fold_chunks_exact(2, Vec::new, |mut v, x| { v.push(x); v });
+    assert_eq!(it.len(), 3);
+    it::assert_equal(it.by_ref(), vec![vec![0, 1], vec![2, 3], vec![4, 5]]);
+    it::assert_equal(it.into_remainder(), 6..7);
+
+    let mut it = (0..6).fold_chunks_exact(3, || 0, |a, b| a + b);
+    it::assert_equal(it.by_ref(), vec![3, 12]);
+    assert_eq!(it.into_remainder().next(), None);
+
+    let mut it = (0..2).fold_chunks_exact(3, || 0, |a, b| a + b);
+    assert_eq!(it.next(), None);
+    it::assert_equal(it.into_remainder(), 0..2);
+
+    let mut it = (0..12).filter(|x| x % 2 == 0).fold_chunks_exact(4, || 0, |a, b| a + b);
+    it::assert_equal(it.by_ref(), vec![12]);
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    it::assert_equal(it.into_remainder(), vec![8, 10]);
+}
+
+#[test]
+fn flatten_ok() {
+    let input: Vec<Result<Vec<i32>, &str>> = vec![Ok(vec![]), Ok(vec![1, 2]), Err("e"), Ok(vec![3])];
+    it::assert_equal(input.clone().into_iter().flatten_ok(), vec![Ok(1), Ok(2), Err("e"), Ok(3)]);
+
+    // mixing both ends
+    let mut it = input.into_iter().flatten_ok();
+    assert_eq!(it.next(), Some(Ok(1)));
+    assert_eq!(it.next_back(), Some(Ok(3)));
+    assert_eq!(it.next_back(), Some(Err("e")));
+    assert_eq!(it.next_back(), Some(Ok(2)));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+}
+
+#[test]
+fn process_results() {
+    let xs: Vec<Result<i32, &str>> = vec![Ok(3), Ok(1), Ok(2)];
+    assert_eq!(it::process_results(xs.iter().cloned(), |it| it.max()), Ok(Some(3)));
+    assert_eq!(it::free::process_results(xs, |it| it.sorted().collect_vec()), Ok(vec![1, 2, 3]));
+
+    // nothing is consumed after the first error
+    let mut iter = vec![Ok(1), Err("a"), Ok(2), Err("b")].into_iter();
+    assert_eq!(iter.by_ref().process_results(|it| it.count()), Err("a"));
+    assert_eq!(iter.next(), Some(Ok(2)));
+
+    // polling after the error neither leaks values nor replaces the error
+    let xs = vec![Ok(1), Err("a"), Ok(2), Err("b")];
+    let polled = it::process_results(xs, |mut it| {
+        let polled = vec![it.next(), it.next(), it.next(), it.next()];
+        assert_eq!(it.size_hint(), (0, Some(0)));
+        polled
+    });
+    assert_eq!(polled, Err("a"));
+    let xs = vec![Ok(1), Err("a"), Ok(2), Err("b")];
+    let mut seen = Vec::new();
+    let _ = it::process_results(xs, |mut it| {
+        for _ in 0..4 {
+            seen.push(it.next());
+        }
+    });
+    assert_eq!(seen, vec![Some(1), None, None, None]);
+
+    let empty: Vec<Result<i32, ()>> = vec![];
+    assert_eq!(empty.into_iter().process_results(|it| it.count()), Ok(0));
+}
+
+#[test]
+fn map_ok() {
+    let input: Vec<Result<i32, &str>> = vec![Ok(1), Err("e"), Ok(2)];
+    it::assert_equal(input.iter().cloned().map_ok(|x| x * 10).rev(),
+                     vec![Ok(20), Err("e"), Ok(10)]);
+    assert_eq!(input.iter().cloned().map_ok(|x| x * 10).len(), 3);
+}
+
+#[test]
+fn inspect_ok() {
+    let input: Vec<Result<i32, &str>> = vec![Ok(1), Err("e"), Ok(2)];
+    let mut seen = Vec::new();
+    it::assert_equal(input.iter().cloned().inspect_ok(|&x| seen.push(x)).rev(),
+                     vec![Ok(2), Err("e"), Ok(1)]);
+    assert_eq!(seen, vec![2, 1]);
+    assert_eq!(input.iter().cloned().inspect_ok(|_| ()).len(), 3);
+}
+
+#[test]
+fn filter_ok() {
+    let input: Vec<Result<i32, &str>> = vec![Ok(1), Err("e"), Ok(2), Ok(3)];
+    it::assert_equal(input.iter().cloned().filter_ok(|x| x % 2 == 1),
+                     vec![Ok(1), Err("e"), Ok(3)]);
+    it::assert_equal(input.iter().cloned().filter_ok(|x| x % 2 == 1).rev(),
+                     vec![Ok(3), Err("e"), Ok(1)]);
+}
+
+#[test]
+fn filter_map_ok() {
+    let input: Vec<Result<i32, &str>> = vec![Ok(1), Err("e"), Ok(2), Ok(3)];
+    it::assert_equal(input.iter().cloned().filter_map_ok(|x| if x > 1 { Some(-x) } else { None }),
+                     vec![Err("e"), Ok(-2), Ok(-3)]);
+    it::assert_equal(input.iter().cloned().filter_map_ok(|x| if x > 1 { Some(-x) } else { None }).rev(),
+                     vec![Ok(-3), Ok(-2), Err("e")]);
+}
+
+#[test]
+fn and_then_ok() {
+    let input: Vec<Result<i32, &str>> = vec![Ok(1), Err("e"), Ok(0)];
+    let it = input.into_iter().and_then_ok(|x| if x != 0 { Ok(10 / x) } else { Err("zero") });
+    it::assert_equal(it, vec![Ok(10), Err("e"), Err("zero")]);
+}
+
+#[test]
+fn dedup_by_key() {
+    let xs = [(0, 'a'), (0, 'b'), (1, 'c'), (0, 'd'), (0, 'e')];
+    it::assert_equal(xs.iter().dedup_by_key(|x| x.0), &[(0, 'a'), (1, 'c'), (0, 'd')]);
+    it::assert_equal(it::free::dedup_by_key(&xs, |x| x.1), xs.iter());
+    assert_eq!(it::free::dedup_by_key(Vec::<i32>::new(), |x| *x).next(), None);
+}
+
+#[test]
+fn fold_while() {
+    use it::FoldWhile::{Continue, Done};
+
+    let mut iter = 1..10;
+    let res = iter.fold_while(0, |acc, x| if x == 4 { Done(acc) } else { Continue(acc + x) });
+    assert_eq!(res, Done(6));
+    assert!(res.is_done());
+    // stops right after the element that ended the fold
+    assert_eq!(iter.next(), Some(5));
+
+    let res = (0..0).fold_while(7, |acc, x| Continue(acc + x));
+    assert_eq!(res.into_inner(), 7);
+}
+
+#[test]
+fn one_or_more() {
+    use it::OneOrMore::{Empty, Single, More};
+
+    assert!(match (0..0).one_or_more() { Empty => true, _ => false });
+    assert!(match (0..1).one_or_more() { Single(0) => true, _ => false });
+    match (0..4).one_or_more() {
+        More(first, rest) => {
+            assert_eq!(first, 0);
+            it::assert_equal(rest, 1..4);
+        }
+        _ => panic!("expected More"),
+    }
+
+    // pulls at most two elements
+    let mut iter = 0..10;
+    match iter.by_ref().one_or_more() {
+        More(..) => {}
+        _ => panic!("expected More"),
+    }
+    assert_eq!(iter.next(), Some(2));
+}
+
+#[test]
+fn contains() {
+    let words = vec!["foo", "bar"];
+    assert!(words.iter().contains(&"foo"));
+    assert!(!words.iter().contains(&"baz"));
+    assert!(it::free::contains(&words, &"bar"));
+
+    let owned = vec![String::from("foo"), String::from("bar")];
+    assert!(owned.iter().cloned().contains("bar"));
+    assert!(it::free::contains(owned, "foo"));
+
+    // stops at the first match
+    let mut it = vec![1, 2, 3, 4, 5].into_iter();
+    assert!(it.contains(&3));
+    assert_eq!(it.next(), Some(4));
+}
+
+#[test]
+fn partition_result() {
+    let xs: Vec<Result<u8, char>> = vec![];
+    let (oks, errs): (Vec<_>, Vec<_>) = xs.into_iter().partition_result();
+    assert!(oks.is_empty() && errs.is_empty());
+
+    let xs = vec![Err('a'), Ok(1), Ok(2), Err('b')];
+    let (oks, errs): (Vec<_>, String) = xs.into_iter().partition_result();
+    assert_eq!(oks, vec![1, 2]);
+    assert_eq!(errs, "ab");
+}
+
+#[test]
+fn format_truncated() {
+    assert_eq!(format!("{}", (0..0).format_truncated(", ", 2)), "");
+    assert_eq!(format!("{}", (0..5).format_truncated(", ", 0)), "\u{2026} and 5 more");
+    assert_eq!(format!("{}", (0..2).format_truncated(", ", 2)), "0, 1");
+    assert_eq!(format!("{}", (0..3).format_truncated("", 2)), "01\u{2026} and 1 more");
+    assert_eq!(format!("{:?}", ["a", "b"].iter().format_truncated(" ", 1)),
+               "\"a\" \u{2026} and 1 more");
+}
+
+#[test]
+fn multiunzip() {
+    let (a, b): (Vec<_>, Vec<_>) = vec![(1, 'x'), (2, 'y')].into_iter().multiunzip();
+    assert_eq!(a, vec![1, 2]);
+    assert_eq!(b, vec!['x', 'y']);
+
+    let (a,): (Vec<u8>,) = Vec::<(u8,)>::new().into_iter().multiunzip();
+    assert!(a.is_empty());
+
+    let t = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+    let (a, b, c, d, e, f, g, h, i, j, k, l):
+        (Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>,
+         Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, ::std::collections::HashSet<_>) =
+        vec![t, t].into_iter().multiunzip();
+    assert_eq!(a, vec![0, 0]);
+    assert_eq!((b[1], c[1], d[1], e[1], f[1], g[1], h[1], i[1], j[1], k[1]),
+               (1, 2, 3, 4, 5, 6, 7, 8, 9, 10));
+    assert_eq!(l.len(), 1);
+}
+
+#[test]
+fn fold_ok() {
+    let mut iter = vec![Ok(1), Ok(2), Err("e"), Ok(3)].into_iter();
+    assert_eq!(iter.fold_ok(0, |a, b| a + b), Err("e"));
+    // no elements are consumed after the error
+    assert_eq!(iter.next(), Some(Ok(3)));
+
+    let xs: Vec<Result<i32, ()>> = vec![Ok(1), Ok(2), Ok(3)];
+    assert_eq!(xs.iter().cloned().fold_ok(Vec::new(), |mut v, x| { v.push(x); v }),
+               Ok(vec![1, 2, 3]));
+    assert_eq!(xs.into_iter().fold_results(0, |a, b| a + b), Ok(6));
+}
+
+#[test]
+fn fold_options() {
+    let mut iter = vec![Some(1), None, Some(3)].into_iter();
+    assert_eq!(iter.fold_options(0, |a, b| a + b), None);
+    assert_eq!(iter.next(), Some(Some(3)));
+    assert_eq!((1..4).map(Some).fold_options(1, |a, b| a * b), Some(6));
+}
+
+#[test]
+fn split_nth() {
+    let (a, rest) = (0..5).split_nth(2);
+    assert_eq!(a, vec![0, 1]);
+    it::assert_equal(rest, 2..5);
+
+    let (a, mut rest) = (0..3).split_nth(5);
+    assert_eq!(a, vec![0, 1, 2]);
+    assert_eq!(rest.next(), None);
+
+    let (a, rest) = (0..3).split_nth(0);
+    assert!(a.is_empty());
+    it::assert_equal(rest, 0..3);
+}
+
+#[test]
+fn tree_reduce() {
+    let x = [
+        "",
+        "0",
+        "0 1 x",
+        "0 1 x 2 x",
+        "0 1 x 2 3 x x",
+        "0 1 x 2 3 x x 4 x",
+        "0 1 x 2 3 x x 4 5 x x",
+        "0 1 x 2 3 x x 4 5 x 6 x x",
+        "0 1 x 2 3 x x 4 5 x 6 7 x x x",
+        "0 1 x 2 3 x x 4 5 x 6 7 x x x 8 x",
+        "0 1 x 2 3 x x 4 5 x 6 7 x x x 8 9 x x",
+        "0 1 x 2 3 x x 4 5 x 6 7 x x x 8 9 x 10 x x",
+        "0 1 x 2 3 x x 4 5 x 6 7 x x x 8 9 x 10 11 x x x",
+        "0 1 x 2 3 x x 4 5 x 6 7 x x x 8 9 x 10 11 x x 12 x x",
+    ];
+    for (i, &s) in x.iter().enumerate() {
+        let expected = if s.is_empty() { None } else { Some(s.to_string()) };
+        let num_strings = (0..i).map(|x| x.to_string());
+        let actual = num_strings.tree_reduce(|a, b| format!("{} {} x", a, b));
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn map_ends() {
+    let tag = |it: std::ops::Range<i32>| {
+        it.map_ends(|x| (1, x), |x| (2, x), |x| (3, x)).collect::<Vec<_>>()
+    };
+    assert_eq!(tag(0..0), vec![]);
+    assert_eq!(tag(0..1), vec![(1, 0)]);
+    assert_eq!(tag(0..2), vec![(1, 0), (3, 1)]);
+    assert_eq!(tag(0..4), vec![(1, 0), (2, 1), (2, 2), (3, 3)]);
+    assert_eq!((0..4).map_ends(|x| x, |x| x, |x| x).len(), 4);
+}
+
+#[test]
+fn fold1() {
+    assert_eq!((0..0).fold1(|a, b| a + b), None);
+    assert_eq!((3..4).fold1(|_, _| panic!()), Some(3));
+    assert_eq!((1..5).fold1(|a, b| a * b), Some(24));
+    let words = vec![String::from("a"), String::from("b"), String::from("c")];
+    assert_eq!(it::free::fold1(words, |a, b| a + "-" + &b), Some(String::from("a-b-c")));
+}
+
+#[test]
+fn sum1_product1() {
+    assert_eq!((0..0).sum1::<i32>(), None);
+    assert_eq!((0..0).product1::<i32>(), None);
+    assert_eq!((0..1).sum1(), Some(0));
+    assert_eq!((0..1).product1(), Some(0));
+    assert_eq!([2u8, 3].iter().sum1::<u8>(), Some(5));
+    assert_eq!([2u8, 3].iter().product1::<u8>(), Some(6));
+}
+
+#[test]
+fn dot() {
+    assert_eq!((0..0).dot(0..5), 0);
+    assert_eq!((1..4).dot(1..), 14);
+    assert_eq!([1.5f64, -2.].iter().dot(&[2., 1.]), 1.);
+    assert_eq!((1..4).norm_squared(), (1..4).dot(1..4));
+}
+
+#[test]
+fn exactly_one() {
+    assert_eq!((0..10).filter(|&x| x == 2).exactly_one().unwrap(), 2);
+    assert!((0..10).filter(|&x| x > 1 && x < 4).exactly_one().unwrap_err().eq(2..4));
+    assert!((0..10).filter(|&x| x > 1 && x < 5).exactly_one().unwrap_err().eq(2..5));
+    assert!((0..10).filter(|&_| false).exactly_one().unwrap_err().eq(0..0));
+
+    let err = (0..0).exactly_one().unwrap_err();
+    assert_eq!(err.to_string(), "got zero elements when exactly one was expected");
+    let err = vec![1, 2, 3].into_iter().exactly_one().unwrap_err();
+    assert_eq!(err.len(), 3);
+    assert_eq!(format!("{:?}", err),
+               "ExactlyOneError[First: 1, Second: 2, RemainingIter: IntoIter([3])]");
+}
+
+#[test]
+fn at_most_one() {
+    assert_eq!((0..10).filter(|&x| x == 2).at_most_one().unwrap(), Some(2));
+    assert!((0..10).filter(|&x| x > 1 && x < 4).at_most_one().unwrap_err().eq(2..4));
+    assert!((0..10).filter(|&x| x > 1 && x < 5).at_most_one().unwrap_err().eq(2..5));
+    assert_eq!((0..10).filter(|&_| false).at_most_one().unwrap(), None);
+}
+
 #[test]
 fn intersperse() {
     let xs = ["a", "", "b", "c"];
@@ -175,6 +839,31 @@ fn intersperse() {
     assert!(it.next() == None);
 }
 
+#[test]
+fn intersperse_with() {
+    #[derive(PartialEq, Debug)]
+    struct NotClone(u8);
+    let xs = vec![NotClone(0), NotClone(1), NotClone(2)];
+    let it = xs.into_iter().intersperse_with(|| NotClone(9));
+    it::assert_equal(it, vec![NotClone(0), NotClone(9), NotClone(1), NotClone(9), NotClone(2)]);
+
+    let mut calls = 0;
+    assert_eq!((0..0).intersperse_with(|| { calls += 1; 0 }).next(), None);
+    assert_eq!(calls, 0);
+}
+
+#[test]
+fn intersperse_every() {
+    let it = (0..7).intersperse_every(3, -1);
+    assert_eq!(it.size_hint(), (9, Some(9)));
+    it::assert_equal(it, vec![0, 1, 2, -1, 3, 4, 5, -1, 6]);
+
+    // no separator after the last group
+    it::assert_equal((0..6).intersperse_every(3, -1), vec![0, 1, 2, -1, 3, 4, 5]);
+    it::assert_equal((0..4).intersperse_every(1, -1), (0..4).intersperse(-1));
+    assert_eq!((0..0).intersperse_every(2, -1).next(), None);
+}
+
 #[test]
 fn linspace() {
     let iter = it::linspace::<f32>(0., 2., 3);
@@ -231,6 +920,86 @@ fn unique() {
     it::assert_equal(ys.iter(), xs.iter().unique());
 }
 
+#[test]
+fn all_equal() {
+    assert!((0..0).all_equal());
+    assert!("AAAA".chars().all_equal());
+    assert!(!"AAAB".chars().all_equal());
+    assert!(it::free::all_equal(&[3, 3, 3]));
+
+    let mut iter = vec![1, 2, 3, 4].into_iter();
+    assert!(!iter.all_equal());
+    assert_eq!(iter.next(), Some(3));
+}
+
+#[test]
+fn all_unique() {
+    assert!((0..0).all_unique());
+    assert!("ABCD".chars().all_unique());
+    assert!(!"ABCA".chars().all_unique());
+    assert!(!it::free::all_unique(&[1, 2, 1]));
+
+    let mut iter = vec![1, 2, 1, 4].into_iter();
+    assert!(!iter.all_unique());
+    assert_eq!(iter.next(), Some(4));
+}
+
+#[test]
+fn count_occurrences() {
+    assert_eq!((0..0).count_occurrences(&0), 0);
+    assert_eq!("abcabca".chars().count_occurrences(&'a'), 3);
+    assert_eq!(["x", "y", "x"].iter().count_occurrences(&"x"), 2);
+
+    let mut iter = vec![1, 2, 1, 4, 1].into_iter();
+    assert_eq!(iter.count_occurrences_max(&1, 2), 2);
+    assert_eq!(iter.next(), Some(4));
+    assert_eq!(iter.count_occurrences_max(&1, 0), 0);
+    assert_eq!(iter.count_occurrences_max(&1, 5), 1);
+}
+
+#[test]
+fn duplicates_by() {
+    let xs = ["aaa", "bbbbb", "aa", "ccc", "bbbb", "aaaaa", "cccc"];
+    let ys = ["aa", "bbbb", "cccc"];
+    it::assert_equal(ys.iter(), xs.iter().duplicates_by(|x| x[..2].to_string()));
+}
+
+#[test]
+fn duplicates() {
+    let xs = [0, 1, 2, 3, 2, 1, 3];
+    let ys = [2, 1, 3];
+    it::assert_equal(ys.iter(), xs.iter().duplicates());
+    let xs = [0, 1, 0, 1, 0, 1];
+    let ys = [0, 1];
+    it::assert_equal(ys.iter(), xs.iter().duplicates());
+    assert_eq!((0..5).duplicates().next(), None);
+}
+
+#[test]
+fn try_min_max() {
+    use std::cmp::Ordering;
+    use std::f32::NAN;
+
+    // compare by the first field only, to check tie breaking
+    #[derive(Debug)]
+    struct Key(i32, char);
+    impl PartialEq for Key {
+        fn eq(&self, other: &Key) -> bool { self.0 == other.0 }
+    }
+    impl PartialOrd for Key {
+        fn partial_cmp(&self, other: &Key) -> Option<Ordering> { self.0.partial_cmp(&other.0) }
+    }
+
+    let data = vec![Key(1, 'a'), Key(0, 'b'), Key(2, 'c'), Key(0, 'd'), Key(2, 'e')];
+    assert_eq!(data.iter().try_min().unwrap().unwrap().1, 'b');
+    assert_eq!(data.iter().try_max().unwrap().unwrap().1, 'e');
+
+    let mut iter = vec![1., NAN, 2.].into_iter();
+    assert!(iter.try_max().is_err());
+    assert_eq!(iter.next(), Some(2.));
+    assert_eq!(vec![NAN].into_iter().try_min().map(|x| x.is_some()), Ok(true));
+}
+
 #[test]
 fn batching() {
     let xs = [0, 1, 2, 1, 3];
@@ -306,6 +1075,43 @@ fn tee() {
     it::assert_equal(t1.zip(t2), xs.iter().cloned().zip(xs.iter().cloned()));
 }
 
+#[test]
+fn tee_bounded() {
+    let xs = [0, 1, 2, 3];
+    let (mut t1, mut t2) = xs.iter().cloned().tee_bounded(2);
+    assert_eq!(t1.next(), Some(0));
+    assert_eq!(t1.next(), Some(1));
+    assert_eq!(t2.next(), Some(0));
+    assert_eq!(t2.next(), Some(1));
+    assert_eq!(t2.next(), Some(2));
+    assert_eq!(t2.next(), Some(3));
+    it::assert_equal(t1, vec![2, 3]);
+
+    let (t1, t2) = xs.iter().cloned().tee_bounded(1);
+    it::assert_equal(t1.zip(t2), xs.iter().cloned().zip(xs.iter().cloned()));
+}
+
+#[test]
+fn tee_bounded_dropped_half() {
+    let (t1, _) = (0..10).tee_bounded(2);
+    assert_eq!(t1.count(), 10);
+
+    // the backlog taken before the drop is still read
+    let (mut t1, t2) = (0..10).tee_bounded(2);
+    assert_eq!(t1.next(), Some(0));
+    drop(t1);
+    it::assert_equal(t2, 0..10);
+}
+
+#[test]
+#[should_panic]
+fn tee_bounded_overflow() {
+    let (mut t1, _t2) = (0..10).tee_bounded(3);
+    for _ in 0..4 {
+        t1.next();
+    }
+}
+
 
 #[test]
 fn rciter() {
@@ -335,11 +1141,60 @@ fn slice() {
     it::assert_equal((0..10).slice(44..), 0..0);
 }
 
+#[test]
+fn take_exact() {
+    it::assert_equal((0..5).take_exact(3), 0..3);
+    it::assert_equal((0..5).take_exact(5), 0..5);
+    assert_eq!((0..0).take_exact(0).next(), None);
+
+    // does not pull more than n elements
+    let mut iter = 0..5;
+    assert_eq!(iter.by_ref().take_exact(2).count(), 2);
+    assert_eq!(iter.next(), Some(2));
+}
+
+#[test]
+#[should_panic]
+fn take_exact_short() {
+    (0..3).take_exact(4).count();
+}
+
 #[test]
 fn step() {
     it::assert_equal((0..10).step(1), (0..10));
     it::assert_equal((0..10).step(2), (0..10).filter(|x: &i32| *x % 2 == 0));
     it::assert_equal((0..10).step(10), 0..1);
+    it::assert_equal((0..10).step(3), vec![0, 3, 6, 9]);
+    it::assert_equal((0..10).step(20), 0..1);
+    assert_eq!((0..0).step(2).next(), None);
+    assert_eq!((0..7).step(3).size_hint(), (3, Some(3)));
+}
+
+#[test]
+#[should_panic]
+fn step_zero() {
+    (0..10).step(0);
+}
+
+#[test]
+fn cycled_to_len() {
+    it::assert_equal("ab".chars().cycled_to_len(5), "ababa".chars());
+    it::assert_equal((0..5).cycled_to_len(3), 0..3);
+    assert_eq!((0..5).cycled_to_len(0).next(), None);
+
+    let it = (0..0).cycled_to_len(10);
+    assert_eq!(it.len(), 0);
+    assert_eq!(it.count(), 0);
+}
+
+#[test]
+fn inspect_every() {
+    let mut seen = Vec::new();
+    let data = "abcdefg".chars().collect_vec();
+    let it = data.iter().cloned().inspect_every(3, |i, &c| seen.push((i, c)));
+    assert_eq!(it.len(), 7);
+    it::assert_equal(it, data.iter().cloned());
+    assert_eq!(seen, vec![(0, 'a'), (3, 'd'), (6, 'g')]);
 }
 
 #[test]
@@ -388,6 +1243,14 @@ fn merge_by() {
     it::assert_equal(results, expected.iter());
 }
 
+#[test]
+fn merge_by_descending() {
+    let a = vec![9, 5, 5, 1];
+    let b = vec![8, 5, 2, 0];
+    it::assert_equal(a.iter().merge_by(&b, |x, y| x >= y), &[9, 8, 5, 5, 5, 2, 1, 0]);
+    it::assert_equal(it::free::merge_by(&a, &b, |x, y| x >= y), &[9, 8, 5, 5, 5, 2, 1, 0]);
+}
+
 #[test]
 fn merge_by_btree() {
     use std::collections::BTreeMap;
@@ -402,6 +1265,35 @@ fn merge_by_btree() {
     it::assert_equal(results, expected.into_iter());
 }
 
+#[test]
+fn merge_by_key() {
+    let a = vec!["x", "yy", "zz", "aaaa"];
+    let b = vec!["b", "cc", "ddd"];
+    it::assert_equal(a.iter().merge_by_key(&b, |s| s.len()),
+                     &["x", "b", "yy", "zz", "cc", "ddd", "aaaa"]);
+    it::assert_equal(b.iter().merge_by_key(&a, |s| s.len()),
+                     &["b", "x", "cc", "yy", "zz", "ddd", "aaaa"]);
+}
+
+#[test]
+fn merge_join_by() {
+    use it::EitherOrBoth::{Left, Right, Both};
+
+    let left = vec![(1, "a"), (2, "b"), (4, "d"), (4, "e")];
+    let right = vec![(2, 'B'), (3, 'C'), (4, 'D')];
+    let joined = left.into_iter().merge_join_by(right, |l, r| l.0.cmp(&r.0));
+    it::assert_equal(joined, vec![
+        Left((1, "a")),
+        Both((2, "b"), (2, 'B')),
+        Right((3, 'C')),
+        Both((4, "d"), (4, 'D')),
+        Left((4, "e")),
+    ]);
+
+    let empty: Vec<i32> = vec![];
+    it::assert_equal(empty.iter().merge_join_by(&[1], |l, r| l.cmp(r)), vec![Right(&1)]);
+}
+
 #[test]
 fn kmerge() {
     let its = (0..4).map(|s| (s..10).step(4));
@@ -409,6 +1301,16 @@ fn kmerge() {
     it::assert_equal(its.kmerge(), (0..10));
 }
 
+#[test]
+fn kmerge_by() {
+    let its = (0..4).map(|s| (s..10).step(4).collect_vec().into_iter().rev());
+    it::assert_equal(its.kmerge_by(|a, b| a > b), (0..10).rev());
+
+    let floats = vec![vec![0.5, 2.0, 3.5], vec![1.0], vec![], vec![0.25, 4.0]];
+    it::assert_equal(it::free::kmerge_by(floats, |a, b| a < b),
+                     vec![0.25, 0.5, 1.0, 2.0, 3.5, 4.0]);
+}
+
 #[test]
 fn kmerge_empty() {
     let its = (0..4).map(|_| (0..0));
@@ -427,6 +1329,25 @@ fn kmerge_empty_size_hint() {
     assert_eq!(its.kmerge().size_hint(), (0, Some(0)));
 }
 
+#[test]
+fn size_hint_huge_inputs() {
+    use std::usize;
+    let max = usize::MAX;
+    assert_eq!((0..max).interleave(0..max).size_hint(), (max, None));
+    assert_eq!((0..max).interleave_shortest(0..max).size_hint(), (max, None));
+    assert_eq!((0..max).interleave_shortest(0..).size_hint(), (max, None));
+    assert_eq!((0..max).cartesian_product(0..2).size_hint(), (max, None));
+    assert_eq!((0..max).merge(0..1).size_hint(), (max, None));
+    assert_eq!((0..max).intersperse(0).size_hint(), (max, None));
+    assert_eq!((0..max).combinations().size_hint(), (max, None));
+    // the count of pairs fits in usize even though n * (n - 1) does not
+    let n = 1 << (usize::BITS / 2);
+    assert_eq!((0..n).combinations().size_hint(),
+               (n / 2 * (n - 1), Some(n / 2 * (n - 1))));
+    assert_eq!((0..n + 1).combinations().size_hint(),
+               (n / 2 * (n + 1), Some(n / 2 * (n + 1))));
+}
+
 #[test]
 fn join() {
     let many = [1, 2, 3];
@@ -438,15 +1359,75 @@ fn join() {
     assert_eq!(none.iter().join(", "), "");
 }
 
+#[test]
+fn join_into() {
+    let mut s = String::from("[");
+    [1, 2, 3].iter().join_into(&mut s, ", ").unwrap();
+    s.push(']');
+    assert_eq!(s, "[1, 2, 3]");
+
+    let none: Vec<i32> = vec![];
+    none.iter().join_into(&mut s, ", ").unwrap();
+    assert_eq!(s, "[1, 2, 3]");
+
+    let mut out = Vec::new();
+    ["x", "y"].iter().write_join(&mut out, " | ").unwrap();
+    (1..3).write_join(&mut out, "").unwrap();
+    assert_eq!(out, b"x | y12");
+}
+
+#[test]
+fn format() {
+    let many = [1, 2, 3];
+    let one  = [1];
+    let none: Vec<i32> = vec![];
+
+    assert_eq!(format!("{}", many.iter().format(", ")), "1, 2, 3");
+    assert_eq!(format!("{}", one.iter().format(", ")), "1");
+    assert_eq!(format!("{}", none.iter().format(", ")), "");
+    assert_eq!(format!("{}", many.iter().format("")), "123");
+
+    // formatting options apply to each element
+    assert_eq!(format!("{:02x}", [10, 255].iter().format(":")), "0a:ff");
+
+    let t = many.iter().format_with("; ", |x, f| f(&format_args!("<{}>", x)));
+    assert_eq!(format!("{}", t), "<1>; <2>; <3>");
+}
+
+#[test]
+fn sum_by_sorted_key() {
+    let data = [1.5, 2.5, 3., 3.5, 7.];
+    let sums = data.iter().cloned().sum_by_sorted_key(|x| *x as i32);
+    assert_eq!(sums, vec![(1, 1.5), (2, 2.5), (3, 6.5), (7, 7.)]);
+
+    // unsorted input: one sum per run
+    let sums = vec![1, 1, 2, 1].into_iter().sum_by_sorted_key(|x| *x);
+    assert_eq!(sums, vec![(1, 2), (2, 2), (1, 1)]);
+    assert!((0..0).sum_by_sorted_key(|x| *x).is_empty());
+}
+
 #[test]
 fn sorted_by() {
     let sc = [3, 4, 1, 2].iter().cloned().sorted_by(|&a, &b| {
         a.cmp(&b)
     });
-    assert_eq!(sc, vec![1, 2, 3, 4]);
+    it::assert_equal(sc, vec![1, 2, 3, 4]);
 
     let v = (0..5).sorted_by(|&a, &b| a.cmp(&b).reverse());
-    assert_eq!(v, vec![4, 3, 2, 1, 0]);
+    it::assert_equal(v, vec![4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn sorted_by_key() {
+    let sc = [3, 4, 1, 2].iter().cloned().sorted_by_key(|&x| x);
+    it::assert_equal(sc, vec![1, 2, 3, 4]);
+
+    let v = (0..5).sorted_by_key(|&x| -x);
+    it::assert_equal(v, vec![4, 3, 2, 1, 0]);
+
+    // stable
+    let v = vec![(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')].into_iter().sorted_by_key(|x| x.0);
+    it::assert_equal(v, vec![(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
 }
 
 #[test]
@@ -529,6 +1510,22 @@ fn enumerate_from_overflow() {
     }
 }
 
+#[test]
+fn enumerate_as() {
+    let it = (0..256).enumerate_as::<u8>();
+    assert_eq!(it.len(), 256);
+    assert_eq!(it.last(), Some((255, 255)));
+    it::assert_equal("ab".chars().enumerate_u64(), vec![(0u64, 'a'), (1, 'b')]);
+    it::assert_equal((5..8).enumerate_as::<i8>(), vec![(0i8, 5), (1, 6), (2, 7)]);
+}
+
+#[test]
+#[should_panic]
+fn enumerate_as_overflow() {
+    for _ in (0..129).enumerate_as::<i8>() {
+    }
+}
+
 /// Like CharIndices iterator, except it yields slices instead
 #[derive(Copy, Clone, Debug)]
 struct CharSlices<'a> {
@@ -648,6 +1645,28 @@ fn pad_using() {
     let v: Vec<usize> = vec![0, 1, 2];
     let r = v.into_iter().pad_using(1, |_| panic!());
     it::assert_equal(r, vec![0, 1, 2]);
+
+    // mixing both ends
+    let mut r = vec![0, 1, 2].into_iter().pad_using(5, |n| n * 10);
+    assert_eq!(r.next(), Some(0));
+    assert_eq!(r.next_back(), Some(40));
+    assert_eq!(r.next_back(), Some(30));
+    assert_eq!(r.next_back(), Some(2));
+    assert_eq!(r.next(), Some(1));
+    assert_eq!(r.next(), None);
+    assert_eq!(r.next_back(), None);
+}
+
+#[test]
+fn pad_to_multiple() {
+    assert_eq!((0..0).pad_to_multiple(3, |_| 9).next(), None);
+    it::assert_equal((0..1).pad_to_multiple(3, |_| 9), vec![0, 9, 9]);
+    it::assert_equal((0..4).pad_to_multiple(1, |_| 9), 0..4);
+
+    let mut it = (0..4).pad_to_multiple(3, |_| 9);
+    assert_eq!(it.len(), 6);
+    it.next();
+    assert_eq!(it.len(), 5);
 }
 
 #[test]
@@ -655,6 +1674,115 @@ fn while_some() {
     let ns = (1..10).map(|x| if x % 5 != 0 { Some(x) } else { None })
                     .while_some();
     it::assert_equal(ns, vec![1, 2, 3, 4]);
+
+    // stays done after the first `None`
+    let mut ns = vec![Some(1), None, Some(2)].into_iter().while_some();
+    assert_eq!(ns.next(), Some(1));
+    assert_eq!(ns.next(), None);
+    assert_eq!(ns.next(), None);
+    assert_eq!(ns.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn scan1() {
+    it::assert_equal((0..0).scan1(|&a, b| Some(a + b)), vec![]);
+    it::assert_equal((3..4).scan1(|&a, b| Some(a + b)), vec![3]);
+    it::assert_equal("abc".chars().map(|c| c.to_string())
+                          .scan1(|a, b| Some(a.clone() + &b)),
+                     vec!["a", "ab", "abc"].into_iter().map(String::from));
+
+    // stays done after `f` returns `None`
+    let mut it = (1..10).scan1(|&a, b| if b == 3 { None } else { Some(a * b) });
+    assert_eq!(it.next(), Some(1));
+    assert_eq!(it.next(), Some(2));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn unfold_many() {
+    // each step emits `n` copies of `n`, stopping after 3
+    let it = it::unfold_many(0, |n, out| {
+        *n += 1;
+        for _ in 0..*n {
+            out.push(*n);
+        }
+        *n < 3
+    });
+    it::assert_equal(it, vec![1, 2, 2, 3, 3, 3]);
+
+    // empty bursts are skipped over
+    let it = it::unfold_many(0, |n, out| {
+        *n += 1;
+        if *n % 2 == 0 {
+            out.push(*n);
+        }
+        *n < 6
+    });
+    it::assert_equal(it, vec![2, 4, 6]);
+
+    let mut it = it::unfold_many((), |_, out: &mut Vec<i32>| {
+        out.push(1);
+        false
+    });
+    assert_eq!(it.next(), Some(1));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn indices() {
+    it::assert_equal(it::indices(&[]), vec![vec![]]);
+    assert_eq!(it::indices(&[3, 0, 2]).next(), None);
+    it::assert_equal(it::indices(&[3]), vec![vec![0], vec![1], vec![2]]);
+
+    let mut it = it::indices(&[2, 2]);
+    assert_eq!(it.next_back(), Some(vec![1, 1]));
+    assert_eq!(it.nth(1), Some(vec![0, 1]));
+    assert_eq!(it.len(), 1);
+    assert_eq!(it.nth(1), None);
+    assert_eq!(it.next_back(), None);
+}
+
+#[test]
+#[should_panic]
+fn indices_overflow() {
+    it::indices(&[!0, 2]);
+}
+
+#[test]
+fn filter_map_while() {
+    use it::WhileStep;
+    let ns = (1..20).filter_map_while(|x| {
+        if x > 10 {
+            WhileStep::Stop
+        } else if x % 3 == 0 {
+            WhileStep::Skip
+        } else {
+            WhileStep::Yield(x * 2)
+        }
+    });
+    it::assert_equal(ns, vec![2, 4, 8, 10, 14, 16, 20]);
+
+    // stays done after the first `Stop`
+    let mut ns = vec![1, 2, 3].into_iter()
+        .filter_map_while(|x| if x == 2 { WhileStep::Stop } else { WhileStep::Yield(x) });
+    assert_eq!(ns.next(), Some(1));
+    assert_eq!(ns.next(), None);
+    assert_eq!(ns.next(), None);
+    assert_eq!(ns.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn map_while_some() {
+    let ns = (1..20).map_while_some(|x| if x < 5 { Some(x * 2) } else { None });
+    it::assert_equal(ns, vec![2, 4, 6, 8]);
+
+    let mut ns = vec![1, 2, 3].into_iter().map_while_some(|x| if x == 2 { None } else { Some(x) });
+    assert_eq!(ns.next(), Some(1));
+    assert_eq!(ns.next(), None);
+    assert_eq!(ns.next(), None);
 }
 
 #[test]
@@ -819,10 +1947,38 @@ fn chunks_lazy() {
     }
 }
 
+#[test]
+fn group_by_lazy_bounded() {
+    let data = vec![0, 0, 0, 1, 1, 2, 2, 2];
+    let groups = data.iter().group_by_lazy_bounded(5, |k| *k);
+    let subs = groups.into_iter().map(|(_, sub)| sub).collect_vec();
+    assert_eq!(subs.len(), 3);
+    for (sub, &len) in subs.into_iter().rev().zip(&[3, 2, 3]) {
+        assert_eq!(sub.count(), len);
+    }
+}
+
+#[test]
+#[should_panic]
+fn group_by_lazy_bounded_overflow() {
+    let data = vec![0, 0, 0, 1, 1, 1, 2, 2, 2];
+    let groups = data.iter().group_by_lazy_bounded(3, |k| *k);
+    let subs = groups.into_iter().map(|(_, sub)| sub).collect_vec();
+    assert_eq!(subs.len(), 3);
+}
+
+#[test]
+#[should_panic]
+fn chunks_lazy_bounded_overflow() {
+    let chunks = (0..9).chunks_lazy_bounded(3, 2);
+    let subs = chunks.into_iter().collect_vec();
+    assert_eq!(subs.len(), 3);
+}
+
 #[test]
 fn flatten_iter() {
     let data = vec![vec![1,2,3], vec![4,5,6]];
-    let flattened = data.into_iter().flatten();
+    let flattened = Itertools::flatten(data.into_iter());
 
     it::assert_equal(flattened, vec![1,2,3,4,5,6]);
 }
@@ -830,7 +1986,7 @@ fn flatten_iter() {
 #[test]
 fn flatten_rev() {
     let data = vec![vec![1,2,3].into_iter(), vec![4,5,6].into_iter()];
-    let flattened = data.into_iter().flatten().rev();
+    let flattened = Itertools::flatten(data.into_iter()).rev();
     it::assert_equal(flattened, vec![6,5,4,3,2,1]);
 }
 
@@ -840,7 +1996,7 @@ fn flatten_clone() {
         &[1,2,3],
         &[4,5,6]
     ];
-    let flattened1 = data.into_iter().cloned().flatten();
+    let flattened1 = Itertools::flatten(data.into_iter().cloned());
     let flattened2 = flattened1.clone();
 
     it::assert_equal(flattened1, &[1,2,3,4,5,6]);
@@ -876,6 +2032,20 @@ fn combinations_n() {
     it::assert_equal((0..2).combinations_n(2), vec![vec![0, 1]]);
 }
 
+#[cfg(feature = "arrays")]
+#[test]
+fn array_combinations() {
+    assert!((1..3).array_combinations::<5>().next().is_none());
+    it::assert_equal((1..4).array_combinations(), vec![[1, 2], [1, 3], [2, 3]]);
+    it::assert_equal((0..0).array_combinations::<2>(), <Vec<[i32; 2]>>::new());
+    it::assert_equal((0..2).array_combinations(), vec![[0], [1]]);
+    it::assert_equal((0..3).array_combinations::<0>(), vec![[]]);
+
+    let it = (0..6).map(|x| x.to_string()).array_combinations::<3>();
+    it::assert_equal(it.map(|c| c.to_vec()),
+                     (0..6).map(|x| x.to_string()).combinations_n(3));
+}
+
 #[test]
 fn diff_mismatch() {
     let a = vec![1, 2, 3, 4];
@@ -916,3 +2086,99 @@ fn diff_shorter() {
         _ => false,
     });
 }
+
+#[test]
+fn diff_equal() {
+    let a = vec![1, 2, 3];
+    let mut calls = 0;
+    let diff = it::diff_with(&a, &a, |a, b| { calls += 1; a == b });
+    assert!(diff.is_none());
+    assert_eq!(calls, 3);
+}
+
+#[test]
+fn assert_equal_message() {
+    let result = ::std::panic::catch_unwind(|| {
+        it::assert_equal(vec![1, 2, 3, 4, 5, 6, 7], vec![1, 2, 3, 4, 0, 6]);
+    });
+    let err = result.unwrap_err();
+    let msg = err.downcast_ref::<String>().unwrap();
+    assert_eq!(*msg, "Failed assertion Some(5) == Some(0) for iteration 4\n  \
+                      preceding: [2, 3, 4]\n  \
+                      following: [6, 7] and [6]");
+}
+
+#[test]
+fn retry_ok_ends_while_retrying() {
+    let input = vec![Ok(1), Err(2), Err(3)];
+    it::assert_equal(input.into_iter().retry_ok(5, |_| true), vec![Ok(1), Err(3)]);
+}
+
+#[cfg(feature = "catch_unwind")]
+#[test]
+fn catch_unwind_map_payload() {
+    let mut calls = 0;
+    let results = (0..4).catch_unwind_map(|x| {
+        calls += 1;
+        if x == 1 {
+            panic!("bad item {}", x);
+        }
+        x * 10
+    }).collect_vec();
+    assert_eq!(calls, 4);
+    assert_eq!(results.len(), 4);
+    assert_eq!(*results[0].as_ref().unwrap(), 0);
+    assert_eq!(results[1].as_ref().unwrap_err().downcast_ref::<String>().unwrap(),
+               "bad item 1");
+    assert_eq!(*results[3].as_ref().unwrap(), 30);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_sorted_and_group_map() {
+    let data = (0..5000).map(|i| (i * 7919) % 1009).collect_vec();
+    it::assert_equal(data.iter().par_sorted(), data.iter().sorted());
+    it::assert_equal(data.iter().par_sorted_by(|a, b| b.cmp(a)),
+                     data.iter().sorted_by(|a, b| b.cmp(a)));
+    it::assert_equal(data.iter().par_sorted_by_key(|&x| x % 10),
+                     data.iter().sorted_by_key(|&x| x % 10));
+    let pairs = data.iter().map(|&x| (x % 13, x));
+    assert_eq!(pairs.clone().par_into_group_map(), pairs.into_group_map());
+}
+
+#[test]
+fn double_ended_adaptors() {
+    use it::EitherOrBoth::{Both, Left};
+
+    it::assert_equal((0..3).interleave(10..12).rev(), vec![2, 11, 1, 10, 0]);
+    it::assert_equal((0..2).cartesian_product(0..2).rev(),
+                     vec![(1, 1), (1, 0), (0, 1), (0, 0)]);
+    it::assert_equal([1, 4, 6].iter().merge(&[2, 4, 5]).rev(), vec![&6, &5, &4, &4, &2, &1]);
+    it::assert_equal((0..3).merge_by(1..2, |a, b| a <= b).rev(), vec![2, 1, 1, 0]);
+    it::assert_equal((0..3).zip_longest(0..2).rev(), vec![Left(2), Both(1, 1), Both(0, 0)]);
+    it::assert_equal((0..2).pad_using(4, |i| i * 10).rev(), vec![30, 20, 1, 0]);
+    assert_eq!([1, 2, 3, 4].iter().positions(|&x| x % 2 == 1).rfind(|_| true), Some(2));
+    it::assert_equal((0..3).update(|x| *x *= 2).rev(), vec![4, 2, 0]);
+    assert_eq!((0..3u8).map_into::<u32>().rfind(|&x| x < 2), Some(1));
+}
+
+#[test]
+fn fused_adaptors() {
+    fn is_fused<I: ::std::iter::FusedIterator>(_: I) {}
+    is_fused((0..3).interleave(0..2));
+    is_fused((0..3).intersperse(0));
+    is_fused((0..3).zip_longest(0..2));
+    is_fused((0..3).pad_using(5, |i| i));
+    is_fused((0..3).step(2));
+    is_fused((0..3).with_position());
+    is_fused((0..3).merge_join_by(0..2, |a, b| a.cmp(b)));
+    is_fused(vec![Some(1), None].into_iter().while_some());
+
+    let mut it = (0..2).delimited(9, 5, 9);
+    assert_eq!(it.by_ref().count(), 5);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+
+    let left = it::free::left_join(vec![(1, 'a'), (2, 'b')], vec![(2, 'x')]);
+    assert_eq!(left.len(), 2);
+}